@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+use crate::riscv::Opcode;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum InstructionError {
+    // Immediate doesn't fit the field of the instruction format
+    #[error("Immediate 0x{imm:X} of {opcode} does not fit in a {bits}-bit field")]
+    ImmediateOutOfRange { opcode: Opcode, imm: u32, bits: u32 },
+}
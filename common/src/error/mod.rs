@@ -1,5 +1,7 @@
+mod instruction;
 mod memory;
 mod opcode;
 
+pub use instruction::InstructionError;
 pub use memory::MemoryError;
 pub use opcode::OpcodeError;
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use crate::riscv::{encode_instruction, opcode::BuiltinOpcode};
+use crate::{
+    error::InstructionError,
+    riscv::{encode_instruction, opcode::BuiltinOpcode},
+};
 
 use super::{register::Register, Opcode};
 
@@ -85,6 +88,55 @@ impl Instruction {
         )
     }
 
+    /// Strict variant of [`Instruction::new_ir`] that rejects immediates which don't fit the field
+    /// of the instruction format instead of silently truncating them on encoding.
+    pub fn new_ir_strict(
+        opcode: Opcode,
+        op_a: u8,
+        op_b: u8,
+        op_c: u32,
+    ) -> Result<Self, InstructionError> {
+        let ins_type = opcode.ins_type();
+        let instruction = Self::new(
+            opcode,
+            Register::from(op_a),
+            Register::from(op_b),
+            op_c,
+            ins_type,
+        );
+        instruction.validate_immediate()?;
+        Ok(instruction)
+    }
+
+    /// Checks that op_c fits the field width of the instruction format.
+    ///
+    /// Signed immediates are accepted both as the raw field and sign-extended to 32 bits, as the
+    /// decoder produces the latter while IR tends to use the former.
+    pub fn validate_immediate(&self) -> Result<(), InstructionError> {
+        let (bits, signed) = match self.ins_type {
+            InstructionType::RType | InstructionType::ITypeShamt => (5, false),
+            InstructionType::IType | InstructionType::SType => (12, true),
+            InstructionType::BType => (13, true),
+            InstructionType::UType => (20, false),
+            InstructionType::JType => (21, true),
+            InstructionType::Unimpl => return Ok(()),
+        };
+        let fits_field = self.op_c < 1 << bits;
+        let fits_sign_extended = signed && {
+            let imm = self.op_c as i32;
+            (-(1 << (bits - 1))..1 << (bits - 1)).contains(&imm)
+        };
+        if fits_field || fits_sign_extended {
+            Ok(())
+        } else {
+            Err(InstructionError::ImmediateOutOfRange {
+                opcode: self.opcode.clone(),
+                imm: self.op_c,
+                bits,
+            })
+        }
+    }
+
     /// Returns true if the instruction is a branch or jump instruction.
     pub fn is_branch_or_jump_instruction(&self) -> bool {
         if let Some(opcode) = self.opcode.builtin() {
@@ -283,3 +335,36 @@ impl Display for Instruction {
         f.write_str(&output)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_immediate_bounds() {
+        let addi = Opcode::from(BuiltinOpcode::ADDI);
+
+        // 12-bit immediates are accepted as the raw field or sign-extended
+        for imm in [0, 0x7FF, 0xFFF, 0xFFFF_F800, 0xFFFF_FFFF] {
+            assert!(Instruction::new_ir_strict(addi.clone(), 1, 0, imm).is_ok());
+        }
+        // 13-bit immediate would be truncated on encoding
+        assert_eq!(
+            Instruction::new_ir_strict(addi.clone(), 1, 0, 0x1000),
+            Err(InstructionError::ImmediateOutOfRange {
+                opcode: addi.clone(),
+                imm: 0x1000,
+                bits: 12,
+            })
+        );
+        assert!(Instruction::new_ir_strict(addi, 1, 0, 0xFFFF_F7FF).is_err());
+
+        let strict = |opcode: BuiltinOpcode, imm: u32| {
+            Instruction::new_ir_strict(Opcode::from(opcode), 1, 2, imm).is_ok()
+        };
+        assert!(strict(BuiltinOpcode::LUI, 0xFFFFF));
+        assert!(!strict(BuiltinOpcode::LUI, 0x100000));
+        assert!(!strict(BuiltinOpcode::SLLI, 32));
+        assert!(strict(BuiltinOpcode::BEQ, 0x1000));
+    }
+}
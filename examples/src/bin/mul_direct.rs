@@ -0,0 +1,7 @@
+#![cfg_attr(target_arch = "riscv32", no_std, no_main)]
+
+#[nexus_rt::main]
+#[nexus_rt::public_input(a, b)]
+fn main(a: u32, b: u32) -> u32 {
+    a.wrapping_mul(b)
+}
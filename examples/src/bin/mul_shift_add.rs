@@ -0,0 +1,21 @@
+#![cfg_attr(target_arch = "riscv32", no_std, no_main)]
+
+// Multiplication without the M extension, one partial product per bit of `b`.
+#[inline(never)]
+fn shift_add_mul(mut a: u32, mut b: u32) -> u32 {
+    let mut result = 0u32;
+    while b != 0 {
+        if b & 1 == 1 {
+            result = result.wrapping_add(a);
+        }
+        a <<= 1;
+        b >>= 1;
+    }
+    result
+}
+
+#[nexus_rt::main]
+#[nexus_rt::public_input(a, b)]
+fn main(a: u32, b: u32) -> u32 {
+    shift_add_mul(a, b)
+}
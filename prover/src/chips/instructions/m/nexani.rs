@@ -754,3 +754,43 @@ pub fn abs64_limb(low: u32, high: u32) -> AbsResult64 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha12Rng;
+
+    /// Schoolbook product of byte limbs, one partial product for each of the 16 limb pairs.
+    fn schoolbook_mul(b: u32, c: u32) -> u64 {
+        let (b, c) = (b.to_le_bytes(), c.to_le_bytes());
+        let mut product = 0u64;
+        for (i, &b_i) in b.iter().enumerate() {
+            for (j, &c_j) in c.iter().enumerate() {
+                product += (b_i as u64 * c_j as u64) << (8 * (i + j));
+            }
+        }
+        product
+    }
+
+    #[test]
+    fn mull_limb_matches_schoolbook() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let edge_cases = [0, 1, 0xFF, 0xFFFF, 0x8000_0000, u32::MAX];
+        let operands = edge_cases
+            .iter()
+            .flat_map(|&b| edge_cases.map(|c| (b, c)))
+            .chain((0..1000).map(|_| (rng.gen(), rng.gen())));
+
+        for (b, c) in operands {
+            let expected = schoolbook_mul(b, c);
+            let result = mull_limb(b, c);
+            assert_eq!(
+                u32::from_le_bytes(result.a_h),
+                (expected >> 32) as u32,
+                "{b:#x} * {c:#x}"
+            );
+            assert_eq!(u32::from_le_bytes(result.a_l), expected as u32);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use nexus_vm::{
+    emulator::{InternalView, View},
+    trace::Trace,
+};
+
+use crate::{Proof, ProvingError, VerificationError};
+
+/// Proofs of two executions, together claiming that both programs, run on the same public input,
+/// exit with the same code and produce the same public output.
+///
+/// Each proof is bound to its own program and public input, so equivalence is only attested
+/// for the input the views were generated with.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EquivalenceProof {
+    pub left: Proof,
+    pub right: Proof,
+}
+
+impl EquivalenceProof {
+    /// Returns the combined size estimate of both proofs in bytes.
+    pub fn size_estimate(&self) -> usize {
+        self.left.size_estimate() + self.right.size_estimate()
+    }
+}
+
+/// Error returned by [`prove_equivalence`].
+#[derive(Debug)]
+pub enum EquivalenceError {
+    /// The executions are run on different public inputs.
+    InputMismatch,
+    /// The executions exit with different codes or produce different public outputs.
+    OutputMismatch,
+    /// One of the executions failed to prove.
+    Proving(ProvingError),
+}
+
+impl std::fmt::Display for EquivalenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InputMismatch => write!(f, "programs are run on different public inputs"),
+            Self::OutputMismatch => write!(f, "programs produce different public outputs"),
+            Self::Proving(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for EquivalenceError {}
+
+impl From<ProvingError> for EquivalenceError {
+    fn from(error: ProvingError) -> Self {
+        Self::Proving(error)
+    }
+}
+
+/// Compares public inputs by value.
+///
+/// Addresses are ignored since the input segment location depends on the layout of each program.
+fn inputs_match(left: &View, right: &View) -> bool {
+    let values = |view: &View| {
+        view.get_public_input().iter().map(|entry| entry.value).collect::<Vec<u8>>()
+    };
+    values(left) == values(right)
+}
+
+/// Compares exit codes and public outputs by value.
+///
+/// Addresses are ignored since the output segment location depends on the layout of each program.
+fn outputs_match(left: &View, right: &View) -> bool {
+    let values = |view: &View| {
+        let exit_code = view.get_exit_code().iter().map(|entry| entry.value);
+        let output = view.get_public_output().iter().map(|entry| entry.value);
+        exit_code.chain(output).collect::<Vec<u8>>()
+    };
+    values(left) == values(right)
+}
+
+/// Proves both executions and checks that they run on equal public inputs and their public
+/// outputs are equal.
+///
+/// Inputs and outputs are compared before proving, so mismatching programs are rejected without
+/// paying for the proofs.
+pub fn prove_equivalence(
+    left: (&impl Trace, &View),
+    right: (&impl Trace, &View),
+) -> Result<EquivalenceProof, EquivalenceError> {
+    let (left_trace, left_view) = left;
+    let (right_trace, right_view) = right;
+    if !inputs_match(left_view, right_view) {
+        return Err(EquivalenceError::InputMismatch);
+    }
+    if !outputs_match(left_view, right_view) {
+        return Err(EquivalenceError::OutputMismatch);
+    }
+
+    Ok(EquivalenceProof {
+        left: crate::prove(left_trace, left_view)?,
+        right: crate::prove(right_trace, right_view)?,
+    })
+}
+
+/// Verifies both proofs against their views and checks that the public inputs and outputs are
+/// equal.
+pub fn verify_equivalence(
+    proof: EquivalenceProof,
+    left_view: &View,
+    right_view: &View,
+) -> Result<(), VerificationError> {
+    if !inputs_match(left_view, right_view) {
+        return Err(VerificationError::InvalidStructure("public inputs differ".to_string()));
+    }
+    if !outputs_match(left_view, right_view) {
+        return Err(VerificationError::InvalidStructure("public outputs differ".to_string()));
+    }
+    let EquivalenceProof { left, right } = proof;
+    crate::verify(left, left_view)?;
+    crate::verify(right, right_view)
+}
@@ -11,6 +11,7 @@ pub mod column;
 pub mod traits;
 pub mod virtual_column;
 
+pub mod equivalence;
 pub mod machine;
 
 #[cfg(test)]
@@ -19,6 +20,7 @@ mod test_utils;
 use nexus_vm::emulator::InternalView;
 pub(crate) use nexus_vm::WORD_SIZE;
 
+pub use equivalence::{prove_equivalence, verify_equivalence, EquivalenceError, EquivalenceProof};
 pub use machine::Proof;
 
 pub use stwo::{core::verifier::VerificationError, prover::ProvingError};
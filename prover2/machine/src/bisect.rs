@@ -0,0 +1,140 @@
+//! Helpers for locating the first executed step that breaks the constraints.
+//!
+//! Boundary components are derived from the steps included in the trace, so a truncated trace
+//! terminates in the state reached after its last step. The view is truncated accordingly: the exit
+//! code and public output of a prefix are read from its final memory state.
+
+use std::collections::BTreeMap;
+
+use nexus_vm::{
+    emulator::{InternalView, PublicOutputEntry, View},
+    trace::{Block, UniformTrace},
+};
+
+use crate::{
+    check::{check_components, CheckError},
+    BASE_COMPONENTS,
+};
+
+/// Checks constraints of the first `num_steps` steps of the trace without proving them, see
+/// [`check`](crate::check()).
+pub fn check_prefix(
+    trace: &UniformTrace,
+    view: &View,
+    num_steps: usize,
+) -> Result<(), CheckError> {
+    assert_eq!(trace.k, 1, "k must be equal to 1");
+    assert!(num_steps > 0 && num_steps <= trace.blocks.len());
+
+    let blocks = &trace.blocks[..num_steps];
+    let prefix = UniformTrace {
+        memory_layout: trace.memory_layout,
+        k: trace.k,
+        start: trace.start,
+        blocks: blocks.to_vec(),
+    };
+    check_components(BASE_COMPONENTS, &prefix, &prefix_view(view, blocks))
+}
+
+/// Returns the view of the program that stops after the given blocks, the exit code and public
+/// output hold the values stored at their addresses by then, unwritten bytes are zero.
+fn prefix_view(view: &View, blocks: &[Block]) -> View {
+    let mut stored = BTreeMap::new();
+    for record in blocks
+        .iter()
+        .flat_map(|block| &block.steps)
+        .flat_map(|step| &step.memory_records)
+        .filter(|record| record.get_prev_value().is_some())
+    {
+        let address = record.get_address();
+        let bytes = record.get_value().to_le_bytes();
+        for (i, byte) in bytes[..record.get_size() as usize].iter().enumerate() {
+            stored.insert(address + i as u32, *byte);
+        }
+    }
+    let final_state = |entries: &[PublicOutputEntry]| -> Vec<PublicOutputEntry> {
+        entries
+            .iter()
+            .map(|entry| PublicOutputEntry {
+                address: entry.address,
+                value: stored.get(&entry.address).copied().unwrap_or_default(),
+            })
+            .collect()
+    };
+
+    View::new(
+        &view.view_memory_layout().copied(),
+        &view.view_debug_logs().unwrap_or_default(),
+        view.get_program_memory(),
+        &view.get_ro_initial_memory().to_vec(),
+        &view.get_rw_initial_memory().to_vec(),
+        &view.get_public_input().to_vec(),
+        view.view_tracked_ram_size(),
+        &final_state(view.get_exit_code()),
+        &final_state(view.get_public_output()),
+        &view.view_associated_data().unwrap_or_default(),
+    )
+}
+
+/// Returns the smallest number of steps for which the trace prefix fails the constraint check, or
+/// `None` if the whole trace passes.
+///
+/// Assumes that once a prefix fails, all longer prefixes fail as well.
+pub fn bisect_failing_prefix(trace: &UniformTrace, view: &View) -> Option<usize> {
+    let num_steps = trace.blocks.len();
+    if num_steps == 0 || check_prefix(trace, view, num_steps).is_ok() {
+        return None;
+    }
+
+    // invariant: prefix of `hi` steps fails, prefix of `lo` steps passes (or is empty)
+    let (mut lo, mut hi) = (0, num_steps);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if check_prefix(trace, view, mid).is_ok() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(hi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nexus_common::cpu::Registers;
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode, Register},
+        trace::k_trace_direct,
+    };
+
+    #[test]
+    fn bisection_locates_bad_step() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 4, 3, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 5, 4, 3),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 6, 5, 4),
+        ])];
+        let (view, mut program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        assert_eq!(bisect_failing_prefix(&program_trace, &view), None);
+        for num_steps in 1..=program_trace.blocks.len() {
+            assert_eq!(check_prefix(&program_trace, &view, num_steps), Ok(()));
+        }
+
+        // the fourth step reads x3 with a value that was never written to it
+        const BAD_STEP: usize = 3;
+        let regs = &mut program_trace.blocks[BAD_STEP].regs;
+        let x3 = regs.read(Register::X3);
+        regs.write(Register::X3, x3 + 1);
+
+        assert_eq!(
+            bisect_failing_prefix(&program_trace, &view),
+            Some(BAD_STEP + 1)
+        );
+    }
+}
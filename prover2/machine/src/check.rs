@@ -0,0 +1,153 @@
+//! Constraint checking without proving, intended for tests and development.
+//!
+//! Traces are generated the same way as by the prover and constraints are evaluated on every row
+//! of the trace domain. Commitments, the composition polynomial and FRI are skipped, which makes
+//! the check much faster than proving. The result is not a proof of anything.
+
+use num_traits::Zero;
+use stwo::core::{channel::Blake2sChannel, fields::qm31::SecureField};
+
+use nexus_vm::{emulator::View, trace::Trace};
+
+use super::BASE_COMPONENTS;
+use crate::{
+    framework::{ConstraintViolation, MachineComponent},
+    lookups::AllLookupElements,
+    side_note::SideNote,
+};
+
+/// Error returned by [`check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckError {
+    /// A constraint of the component doesn't vanish on some row of its trace.
+    ConstraintViolated {
+        component: &'static str,
+        /// First violating row, in coset order.
+        row: usize,
+    },
+    /// Logup sums of all components don't add up to zero.
+    ClaimedSumNotZero { total: SecureField },
+}
+
+impl std::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ConstraintViolated { component, row } => {
+                write!(f, "{component}: constraints are not satisfied on row {row}")
+            }
+            Self::ClaimedSumNotZero { total } => write!(f, "claimed sum is not zero: {total}"),
+        }
+    }
+}
+
+impl std::error::Error for CheckError {}
+
+/// Checks that the execution trace satisfies all constraints, without generating a proof.
+pub fn check(trace: &impl Trace, view: &View) -> Result<(), CheckError> {
+    check_components(BASE_COMPONENTS, trace, view)
+}
+
+/// Checks the trace with the given set of components.
+///
+/// Component traces are generated in the order of the slice, as in
+/// [`prove_components`](crate::prove::prove_components).
+pub(crate) fn check_components(
+    components: &[&dyn MachineComponent],
+    trace: &impl Trace,
+    view: &View,
+) -> Result<(), CheckError> {
+    let mut side_note = SideNote::new(trace, view);
+    side_note.run_step_hooks(components.iter().filter_map(|c| c.step_hook()));
+    let component_traces: Vec<_> = components
+        .iter()
+        .map(|c| c.generate_component_trace(&mut side_note))
+        .collect();
+
+    // lookup elements don't have to be bound to the traces, nothing is committed
+    let mut lookup_elements = AllLookupElements::default();
+    let channel = &mut Blake2sChannel::default();
+    for c in components {
+        c.draw_lookup_elements(&mut lookup_elements, channel);
+    }
+
+    let mut total_sum = SecureField::zero();
+    for (c, component_trace) in components.iter().zip(component_traces) {
+        let (interaction_trace, claimed_sum) =
+            c.generate_interaction_trace(component_trace.clone(), &side_note, &lookup_elements);
+        c.check_constraints(&component_trace, interaction_trace, &lookup_elements, claimed_sum)
+            .map_err(|ConstraintViolation { row }| CheckError::ConstraintViolated {
+                component: c.ordering_key(),
+                row,
+            })?;
+        total_sum += claimed_sum - c.public_logup_sum(&side_note, &lookup_elements);
+    }
+
+    if !total_sum.is_zero() {
+        return Err(CheckError::ClaimedSumNotZero { total: total_sum });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nexus_common::cpu::Registers;
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode, Register},
+        trace::k_trace_direct,
+    };
+
+    use crate::components::{
+        Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
+        RegisterMemoryBoundary, ADD, ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
+    };
+
+    fn add_program() -> Vec<BasicBlock> {
+        vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 4, 3, 2),
+        ])]
+    }
+
+    #[test]
+    fn check_add_program() {
+        let (view, program_trace) =
+            k_trace_direct(&add_program(), 1).expect("error generating trace");
+        assert_eq!(check(&program_trace, &view), Ok(()));
+
+        let components: [&dyn MachineComponent; 12] = [
+            &Cpu,
+            &CpuBoundary,
+            &RegisterMemory,
+            &RegisterMemoryBoundary,
+            &ProgramMemory,
+            &ProgramMemoryBoundary,
+            &ADD,
+            &ADDI,
+            &RANGE8,
+            &RANGE16,
+            &RANGE64,
+            &RANGE256,
+        ];
+        assert_eq!(check_components(&components, &program_trace, &view), Ok(()));
+    }
+
+    #[test]
+    fn check_detects_corrupted_trace() {
+        let (view, mut program_trace) =
+            k_trace_direct(&add_program(), 1).expect("error generating trace");
+
+        // the last ADD reads x3 with a value that was never written to it
+        let regs = &mut program_trace.blocks[3].regs;
+        let x3 = regs.read(Register::X3);
+        regs.write(Register::X3, x3 + 1);
+
+        assert!(matches!(
+            check(&program_trace, &view),
+            Err(CheckError::ClaimedSumNotZero { .. })
+        ));
+    }
+}
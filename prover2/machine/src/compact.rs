@@ -0,0 +1,137 @@
+//! Compact proofs for verifiers that have access to the program.
+//!
+//! The commitment to the preprocessed trace only depends on the program, component sizes and the
+//! public number of executed steps, a verifier holding the program recomputes it instead of reading
+//! it from the proof.
+
+use serde::{Deserialize, Serialize};
+use stwo::core::verifier::VerificationError;
+
+use nexus_vm::emulator::View;
+use nexus_vm_prover_trace::eval::PREPROCESSED_TRACE_IDX;
+
+use crate::{
+    verify::{check_log_sizes, preprocessed_commitment},
+    verify_with_config, Proof, ProvingConfig,
+};
+
+/// Header of a [`CompactProof`], flags public data omitted from the proof.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrippedData {
+    /// The commitment to the preprocessed trace was removed.
+    pub preprocessed_commitment: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CompactProof {
+    pub stripped: StrippedData,
+    pub proof: Proof,
+}
+
+impl Proof {
+    /// Removes public data that can be recomputed by a verifier with access to the program.
+    pub fn compact(mut self) -> CompactProof {
+        self.stark_proof
+            .0
+            .commitments
+            .0
+            .remove(PREPROCESSED_TRACE_IDX);
+        CompactProof {
+            stripped: StrippedData {
+                preprocessed_commitment: true,
+            },
+            proof: self,
+        }
+    }
+}
+
+impl CompactProof {
+    /// Restores omitted data using the program from the view.
+    ///
+    /// Log sizes of the proof are untrusted and checked before rebuilding the preprocessed trace
+    /// from them.
+    pub fn expand(self, view: &View, config: ProvingConfig) -> Result<Proof, VerificationError> {
+        let CompactProof {
+            stripped,
+            mut proof,
+        } = self;
+        if stripped.preprocessed_commitment {
+            check_log_sizes(&proof.log_sizes, config)?;
+            let commitment =
+                preprocessed_commitment(view, proof.num_steps, &proof.log_sizes, config);
+            proof
+                .stark_proof
+                .0
+                .commitments
+                .0
+                .insert(PREPROCESSED_TRACE_IDX, commitment);
+        }
+        Ok(proof)
+    }
+}
+
+pub fn verify_compact(proof: CompactProof, view: &View) -> Result<(), VerificationError> {
+    verify_compact_with_config(proof, view, ProvingConfig::default())
+}
+
+/// Verifies a compact proof generated with the given security parameters.
+pub fn verify_compact_with_config(
+    proof: CompactProof,
+    view: &View,
+    config: ProvingConfig,
+) -> Result<(), VerificationError> {
+    verify_with_config(proof.expand(view, config)?, view, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prove;
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    #[test]
+    fn prove_verify_compact() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let proof = prove(&program_trace, &view).unwrap();
+        let full_size = proof.stark_proof.size_estimate();
+
+        let compact = proof.compact();
+        assert!(compact.stripped.preprocessed_commitment);
+        assert!(compact.proof.stark_proof.size_estimate() < full_size);
+
+        verify_compact(compact, &view).unwrap();
+    }
+
+    #[test]
+    fn expand_rejects_invalid_log_sizes() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let compact = prove(&program_trace, &view).unwrap().compact();
+
+        let mut truncated = compact.clone();
+        truncated.proof.log_sizes.pop();
+        assert!(truncated.expand(&view, ProvingConfig::default()).is_err());
+
+        let mut oversized = compact.clone();
+        oversized.proof.log_sizes[0] = u32::MAX;
+        assert!(oversized.expand(&view, ProvingConfig::default()).is_err());
+
+        let mut undersized = compact;
+        undersized.proof.log_sizes[0] = 0;
+        assert!(verify_compact(undersized, &view).is_err());
+    }
+}
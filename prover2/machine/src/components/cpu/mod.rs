@@ -34,6 +34,8 @@ use columns::{Column, PreprocessedColumn};
 pub struct Cpu;
 
 impl BuiltInComponent for Cpu {
+    const NAME: &'static str = "Cpu";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -49,6 +51,10 @@ impl BuiltInComponent for Cpu {
         FinalizedTrace { cols, log_size }
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        trace::main_trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         trace::generate_main_trace(side_note)
     }
@@ -64,7 +70,7 @@ impl BuiltInComponent for Cpu {
     ) {
         let (rel_cont_prog_exec, range_check) = Self::LookupElements::get(lookup_elements);
         let log_size = component_trace.log_size();
-        let mut logup_trace_builder = LogupTraceBuilder::new(log_size);
+        let mut logup_trace_builder = LogupTraceBuilder::new(log_size, side_note.logup_backend);
 
         let [is_pad] = original_base_column!(component_trace, Column::IsPad);
         let [pc_aux] = original_base_column!(component_trace, Column::PcAux);
@@ -165,7 +171,10 @@ mod tests {
             CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
             RegisterMemoryBoundary, ADD, ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
         },
-        framework::test_utils::{assert_component, components_claimed_sum, AssertContext},
+        framework::{
+            test_utils::{assert_component, components_claimed_sum, AssertContext},
+            MachineComponent,
+        },
     };
 
     #[test]
@@ -203,4 +212,34 @@ mod tests {
 
         assert!(claimed_sum.is_zero());
     }
+
+    #[test]
+    fn mismatched_preprocessed_log_size() {
+        let basic_block = vec![BasicBlock::new(vec![Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADDI),
+            1,
+            0,
+            1,
+        )])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut component_trace = Cpu.generate_component_trace(&mut assert_ctx.side_note);
+        assert_eq!(Cpu.validate_trace_log_sizes(&component_trace), Ok(()));
+
+        // commit the clock over a domain twice as large as the main trace
+        let log_size = component_trace.log_size();
+        component_trace.preprocessed_trace = preprocessed_clk_trace(log_size + 1);
+        let err = Cpu.validate_trace_log_sizes(&component_trace).unwrap_err();
+        assert_eq!((err.main, err.preprocessed), (log_size, log_size + 1));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{}: preprocessed trace has log size {}, but main trace has {log_size}",
+                Cpu.ordering_key(),
+                log_size + 1,
+            )
+        );
+    }
 }
@@ -33,9 +33,14 @@ pub fn preprocessed_clk_trace(log_size: u32) -> Vec<BaseColumn> {
     vec![clk_low, clk_high]
 }
 
+pub fn main_trace_log_size(side_note: &SideNote) -> u32 {
+    let num_steps = side_note.num_program_steps();
+    num_steps.next_power_of_two().ilog2().max(LOG_N_LANES)
+}
+
 pub fn generate_main_trace(side_note: &mut SideNote) -> FinalizedTrace {
     let num_steps = side_note.num_program_steps();
-    let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
+    let log_size = main_trace_log_size(side_note);
     let mut range64_mults = RangeCheckMultiplicities::default();
     let mut range256_mults = Range256Multiplicities::default();
 
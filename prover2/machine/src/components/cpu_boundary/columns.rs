@@ -12,15 +12,19 @@ pub enum PreprocessedColumn {
     /// Initial value of the program counter.
     #[size = 2]
     InitPc,
+    /// Initial and final values of the clock. The clock starts at 1 and its final value is
+    /// determined by the public number of executed steps.
+    #[size = 2]
+    Clk,
 }
 
 #[derive(Debug, Copy, Clone, AirColumn)]
 pub enum Column {
     /// Final value of the program counter.
+    ///
+    /// Only bound to the next pc of the last executed instruction through the execution lookup, it's
+    /// not required to point into program memory: programs without an exit syscall end one word past
+    /// their last instruction.
     #[size = 2]
     FinalPc,
-    /// Initial and final values of the clock. The initial value is enforced to equal 1
-    /// in the preprocessed trace of the CPU component.
-    #[size = 2]
-    Clk,
 }
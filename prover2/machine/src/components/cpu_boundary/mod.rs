@@ -2,6 +2,11 @@
 //!
 //! This can be done manually as a part of the protocol, but wrapped into component to keep interfaces
 //! consistent.
+//!
+//! The final (clk, pc) pair is consumed from the program execution relation, which is only balanced
+//! if it equals the (clk-next, pc-next) provided by the last executed step. The final clock is
+//! fixed in the preprocessed trace by the number of executed steps, which makes the step count
+//! public.
 
 use num_traits::One;
 use stwo::{
@@ -40,6 +45,8 @@ impl CpuBoundary {
 }
 
 impl BuiltInComponent for CpuBoundary {
+    const NAME: &'static str = "CpuBoundary";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -53,9 +60,13 @@ impl BuiltInComponent for CpuBoundary {
     ) -> FinalizedTrace {
         assert_eq!(log_size, Self::LOG_SIZE);
         let init_pc = u32_to_16bit_parts_le(program.program_memory.initial_pc);
+        let init_clk_parts = [1u16, 0];
+        let final_clk_parts = u32_to_16bit_parts_le(program.num_steps + 1);
         let mut trace = TraceBuilder::new(log_size);
 
         trace.fill_columns(0, init_pc, PreprocessedColumn::InitPc);
+        trace.fill_columns(0, init_clk_parts, PreprocessedColumn::Clk);
+        trace.fill_columns(1, final_clk_parts, PreprocessedColumn::Clk);
 
         trace.fill_columns_base_field(0, &[BaseField::one()], PreprocessedColumn::InitMultiplicity);
         trace.fill_columns_base_field(
@@ -67,6 +78,10 @@ impl BuiltInComponent for CpuBoundary {
         trace.finalize()
     }
 
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::LOG_SIZE
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let log_size = Self::LOG_SIZE;
         let final_step = side_note
@@ -74,20 +89,11 @@ impl BuiltInComponent for CpuBoundary {
             .next_back()
             .expect("empty execution trace");
 
-        let init_clk_parts = [1u16, 0];
-
         let final_pc = final_step.step.next_pc;
-        let final_clk = final_step.step.timestamp + 1;
-
         let final_pc_parts = u32_to_16bit_parts_le(final_pc);
-        let final_clk_parts = u32_to_16bit_parts_le(final_clk);
 
         let mut trace = TraceBuilder::new(log_size);
-
-        trace.fill_columns(0, init_clk_parts, Column::Clk);
-
         trace.fill_columns(1, final_pc_parts, Column::FinalPc);
-        trace.fill_columns(1, final_clk_parts, Column::Clk);
 
         trace.finalize()
     }
@@ -95,7 +101,7 @@ impl BuiltInComponent for CpuBoundary {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
@@ -109,10 +115,11 @@ impl BuiltInComponent for CpuBoundary {
             preprocessed_base_column!(component_trace, PreprocessedColumn::InitMultiplicity);
         let init_pc = preprocessed_base_column!(component_trace, PreprocessedColumn::InitPc);
 
-        let clk = original_base_column!(component_trace, Column::Clk);
+        let clk = preprocessed_base_column!(component_trace, PreprocessedColumn::Clk);
         let pc = original_base_column!(component_trace, Column::FinalPc);
 
-        let mut logup_trace_builder = LogupTraceBuilder::new(Self::LOG_SIZE);
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(Self::LOG_SIZE, side_note.logup_backend);
 
         logup_trace_builder.add_to_relation(
             lookup_elements,
@@ -136,7 +143,7 @@ impl BuiltInComponent for CpuBoundary {
             preprocessed_trace_eval!(trace_eval, PreprocessedColumn::InitMultiplicity);
         let init_pc = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::InitPc);
 
-        let clk = trace_eval!(trace_eval, Column::Clk);
+        let clk = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::Clk);
         let pc = trace_eval!(trace_eval, Column::FinalPc);
 
         eval.add_to_relation(RelationEntry::new(
@@ -158,11 +165,27 @@ impl BuiltInComponent for CpuBoundary {
 mod tests {
     use super::*;
 
-    use crate::framework::test_utils::{assert_component, AssertContext};
+    use crate::{
+        components::{
+            Cpu, ProgramMemory, ProgramMemoryBoundary, RegisterMemory, RegisterMemoryBoundary, ADD,
+            ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
+        },
+        framework::{
+            test_utils::{
+                assert_component, assert_tampered_component, components_claimed_sum,
+                AssertContext,
+            },
+            MachineComponent,
+        },
+    };
     use nexus_vm::{
         riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
         trace::k_trace_direct,
+        WORD_SIZE,
     };
+    use nexus_vm_prover_air_column::AirColumn;
+    use num_traits::Zero;
+    use stwo::prover::backend::Column as _;
 
     #[test]
     fn assert_cpu_boundary_constraints() {
@@ -177,4 +200,45 @@ mod tests {
 
         assert_component(CpuBoundary, &mut AssertContext::new(&program_trace, &view));
     }
+
+    #[test]
+    fn final_pc_mismatch_rejected() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let components: &[&dyn MachineComponent] = &[
+            &Cpu,
+            &RegisterMemory,
+            &RegisterMemoryBoundary,
+            &ProgramMemory,
+            &ProgramMemoryBoundary,
+            &ADD,
+            &ADDI,
+            &RANGE8,
+            &RANGE16,
+            &RANGE64,
+            &RANGE256,
+        ];
+
+        {
+            let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+            let claimed_sum = assert_component(CpuBoundary, assert_ctx)
+                + components_claimed_sum(components, assert_ctx);
+            assert!(claimed_sum.is_zero());
+        }
+
+        // the last step provides its next pc, the boundary consumes a different final pc
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let claimed_sum = assert_tampered_component(CpuBoundary, assert_ctx, |component_trace| {
+            // only the second row is consumed, the first one has zero multiplicity
+            let final_pc_low = &mut component_trace.original_trace[Column::FinalPc.offset()];
+            for row in 0..final_pc_low.len() {
+                final_pc_low.set(row, final_pc_low.at(row) + BaseField::from(WORD_SIZE as u32));
+            }
+        }) + components_claimed_sum(components, assert_ctx);
+        assert!(!claimed_sum.is_zero());
+    }
 }
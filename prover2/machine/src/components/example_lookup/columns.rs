@@ -0,0 +1,27 @@
+use nexus_vm_prover_air_column::{AirColumn, PreprocessedAirColumn};
+
+/// Columns of [`OpcodeSteps`](super::OpcodeSteps), one row per program step.
+#[derive(Debug, Copy, Clone, AirColumn)]
+pub enum StepsColumn {
+    /// The 7-bit opcode of the executed instruction
+    #[size = 1]
+    Opcode,
+    /// Binary value to indicate if the row is a padding row
+    #[size = 1]
+    IsLocalPad,
+}
+
+#[derive(Debug, Copy, Clone, PreprocessedAirColumn)]
+#[preprocessed_prefix = "opcode_multiplicity"]
+pub enum MultiplicityPreprocessedColumn {
+    /// All 7-bit opcodes, the row index equals the opcode
+    #[size = 1]
+    Opcode,
+}
+
+#[derive(Debug, Copy, Clone, AirColumn)]
+pub enum MultiplicityColumn {
+    /// Number of executed instructions with the opcode of the row
+    #[size = 1]
+    Multiplicity,
+}
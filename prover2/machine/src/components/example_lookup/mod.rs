@@ -0,0 +1,319 @@
+//! Example of a custom lookup that counts its multiplicities with a step hook.
+//!
+//! [`OpcodeSteps`] looks up the 7-bit opcode of every executed instruction in the table of all
+//! opcodes provided by [`OpcodeMultiplicity`]. The table reads its multiplicities from
+//! [`OpcodeCounts`], a custom side note filled by [`count_opcode`] before any trace is generated,
+//! so it doesn't depend on the position of the components in the list.
+
+use std::collections::BTreeMap;
+
+use num_traits::One;
+use stwo::{
+    core::{
+        fields::{m31::BaseField, qm31::SecureField},
+        ColumnVec,
+    },
+    prover::{
+        backend::simd::{
+            column::BaseColumn,
+            m31::{PackedBaseField, LOG_N_LANES},
+            SimdBackend,
+        },
+        poly::{circle::CircleEvaluation, BitReversedOrder},
+    },
+};
+use stwo_constraint_framework::{EvalAtRow, RelationEntry};
+
+use nexus_vm_prover_air_column::empty::EmptyPreprocessedColumn;
+use nexus_vm_prover_trace::{
+    builder::{FinalizedTrace, TraceBuilder},
+    component::ComponentTrace,
+    eval::TraceEval,
+    original_base_column, preprocessed_base_column, preprocessed_trace_eval,
+    program::ProgramStep,
+    trace_eval,
+};
+
+use crate::{
+    framework::BuiltInComponent,
+    lookups::{AllLookupElements, LogupTraceBuilder},
+    side_note::{
+        custom::{CustomSideNotes, StepHook},
+        program::ProgramTraceRef,
+        SideNote,
+    },
+};
+
+mod columns;
+use columns::{MultiplicityColumn, MultiplicityPreprocessedColumn, StepsColumn};
+
+// (opcode)
+//
+// Registered in `RelationVariant` under `#[cfg(test)]`, like the components of this example.
+const REL_OPCODE_LOOKUP_SIZE: usize = 1;
+stwo_constraint_framework::relation!(OpcodeLookupElements, REL_OPCODE_LOOKUP_SIZE);
+
+/// Number of executed instructions per 7-bit opcode.
+#[derive(Debug, Default)]
+pub struct OpcodeCounts(BTreeMap<u8, u32>);
+
+/// Step hook of [`OpcodeMultiplicity`].
+fn count_opcode(program_step: &ProgramStep, side_notes: &mut CustomSideNotes) {
+    let opcode = program_step.step.instruction.opcode.raw();
+    *side_notes
+        .get_or_default::<OpcodeCounts>()
+        .0
+        .entry(opcode)
+        .or_default() += 1;
+}
+
+/// Looks up the opcode of every program step.
+pub struct OpcodeSteps;
+
+impl BuiltInComponent for OpcodeSteps {
+    const NAME: &'static str = "OpcodeSteps";
+
+    type PreprocessedColumn = EmptyPreprocessedColumn;
+
+    type MainColumn = StepsColumn;
+
+    type LookupElements = OpcodeLookupElements;
+
+    fn generate_preprocessed_trace(
+        &self,
+        _log_size: u32,
+        _program: &ProgramTraceRef,
+    ) -> FinalizedTrace {
+        FinalizedTrace::empty()
+    }
+
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        let num_steps = side_note.num_program_steps();
+        num_steps.next_power_of_two().ilog2().max(LOG_N_LANES)
+    }
+
+    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
+        let num_steps = side_note.num_program_steps();
+        let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
+
+        let mut trace = TraceBuilder::new(log_size);
+        for (row_idx, program_step) in side_note.iter_program_steps().enumerate() {
+            let opcode = program_step.step.instruction.opcode.raw();
+            trace.fill_columns(row_idx, opcode, StepsColumn::Opcode);
+        }
+        for row_idx in num_steps..1 << log_size {
+            trace.fill_columns(row_idx, true, StepsColumn::IsLocalPad);
+        }
+        trace.finalize()
+    }
+
+    fn generate_interaction_trace(
+        &self,
+        component_trace: ComponentTrace,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> (
+        ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        SecureField,
+    ) {
+        let rel_opcode: &Self::LookupElements = lookup_elements.as_ref();
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
+
+        let [is_local_pad] = original_base_column!(component_trace, StepsColumn::IsLocalPad);
+        let opcode = original_base_column!(component_trace, StepsColumn::Opcode);
+
+        // consume(rel-opcode, 1 − is-local-pad, opcode)
+        logup_trace_builder.add_to_relation_with(
+            rel_opcode,
+            [is_local_pad],
+            |[is_local_pad]| (PackedBaseField::one() - is_local_pad).into(),
+            &opcode,
+        );
+        logup_trace_builder.finalize()
+    }
+
+    fn add_constraints<E: EvalAtRow>(
+        &self,
+        eval: &mut E,
+        trace_eval: TraceEval<Self::PreprocessedColumn, Self::MainColumn, E>,
+        lookup_elements: &Self::LookupElements,
+    ) {
+        let [is_local_pad] = trace_eval!(trace_eval, StepsColumn::IsLocalPad);
+        let opcode = trace_eval!(trace_eval, StepsColumn::Opcode);
+
+        // (is-local-pad) · (1 − is-local-pad) = 0
+        eval.add_constraint(is_local_pad.clone() * (E::F::one() - is_local_pad.clone()));
+
+        // consume(rel-opcode, 1 − is-local-pad, opcode)
+        eval.add_to_relation(RelationEntry::new(
+            lookup_elements,
+            (E::F::one() - is_local_pad).into(),
+            &opcode,
+        ));
+
+        eval.finalize_logup_in_pairs();
+    }
+}
+
+/// Table of all 7-bit opcodes with multiplicities counted by [`count_opcode`].
+pub struct OpcodeMultiplicity;
+
+impl OpcodeMultiplicity {
+    const LOG_SIZE: u32 = 7;
+}
+
+impl BuiltInComponent for OpcodeMultiplicity {
+    const NAME: &'static str = "OpcodeMultiplicity";
+
+    type PreprocessedColumn = MultiplicityPreprocessedColumn;
+
+    type MainColumn = MultiplicityColumn;
+
+    type LookupElements = OpcodeLookupElements;
+
+    const STEP_HOOK: Option<StepHook> = Some(count_opcode);
+
+    fn generate_preprocessed_trace(
+        &self,
+        _log_size: u32,
+        _program: &ProgramTraceRef,
+    ) -> FinalizedTrace {
+        let opcode = BaseColumn::from_iter((0..1u32 << Self::LOG_SIZE).map(BaseField::from));
+        FinalizedTrace {
+            cols: vec![opcode],
+            log_size: Self::LOG_SIZE,
+        }
+    }
+
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::LOG_SIZE
+    }
+
+    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
+        let counts = side_note.custom.get::<OpcodeCounts>();
+        let multiplicity = BaseColumn::from_iter((0..1u8 << Self::LOG_SIZE).map(|opcode| {
+            let count = counts.and_then(|counts| counts.0.get(&opcode));
+            BaseField::from(count.copied().unwrap_or_default())
+        }));
+        FinalizedTrace {
+            cols: vec![multiplicity],
+            log_size: Self::LOG_SIZE,
+        }
+    }
+
+    fn generate_interaction_trace(
+        &self,
+        component_trace: ComponentTrace,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> (
+        ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        SecureField,
+    ) {
+        let rel_opcode: &Self::LookupElements = lookup_elements.as_ref();
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
+
+        let opcode =
+            preprocessed_base_column!(component_trace, MultiplicityPreprocessedColumn::Opcode);
+        let [mult] = original_base_column!(component_trace, MultiplicityColumn::Multiplicity);
+
+        // provide(rel-opcode, mult, opcode)
+        logup_trace_builder.add_to_relation_with(
+            rel_opcode,
+            [mult],
+            |[mult]| (-mult).into(),
+            &opcode,
+        );
+        logup_trace_builder.finalize()
+    }
+
+    fn add_constraints<E: EvalAtRow>(
+        &self,
+        eval: &mut E,
+        trace_eval: TraceEval<Self::PreprocessedColumn, Self::MainColumn, E>,
+        lookup_elements: &Self::LookupElements,
+    ) {
+        let opcode = preprocessed_trace_eval!(trace_eval, MultiplicityPreprocessedColumn::Opcode);
+        let [mult] = trace_eval!(trace_eval, MultiplicityColumn::Multiplicity);
+
+        // provide(rel-opcode, mult, opcode)
+        eval.add_to_relation(RelationEntry::new(
+            lookup_elements,
+            (-mult).into(),
+            &opcode,
+        ));
+
+        eval.finalize_logup_in_pairs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    use crate::{
+        check::check_components,
+        components::{
+            Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
+            RegisterMemoryBoundary, ADD, ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
+        },
+        framework::MachineComponent,
+    };
+
+    fn add_program() -> Vec<BasicBlock> {
+        vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+        ])]
+    }
+
+    #[test]
+    fn step_hook_counts_opcodes() {
+        let (view, program_trace) =
+            k_trace_direct(&add_program(), 1).expect("error generating trace");
+        let mut side_note = SideNote::new(&program_trace, &view);
+        side_note.run_step_hooks(OpcodeMultiplicity.step_hook());
+
+        let counts = side_note
+            .custom
+            .get::<OpcodeCounts>()
+            .expect("hook must register the side note");
+        assert_eq!(
+            counts.0,
+            BTreeMap::from([(BuiltinOpcode::ADDI.raw(), 1), (BuiltinOpcode::ADD.raw(), 2)])
+        );
+    }
+
+    #[test]
+    fn opcode_lookup_balances() {
+        let (view, program_trace) =
+            k_trace_direct(&add_program(), 1).expect("error generating trace");
+
+        // the table is listed before the steps, counts don't depend on the order
+        let components: [&dyn MachineComponent; 14] = [
+            &OpcodeMultiplicity,
+            &OpcodeSteps,
+            &Cpu,
+            &CpuBoundary,
+            &RegisterMemory,
+            &RegisterMemoryBoundary,
+            &ProgramMemory,
+            &ProgramMemoryBoundary,
+            &ADD,
+            &ADDI,
+            &RANGE8,
+            &RANGE16,
+            &RANGE64,
+            &RANGE256,
+        ];
+        assert_eq!(check_components(&components, &program_trace, &view), Ok(()));
+    }
+}
@@ -28,4 +28,10 @@ pub enum Column {
     /// Helper variables to implement the ADD functionality
     #[size = 2]
     HCarry,
+    /// Carry out of the 32-bit sum, equals the second h-carry
+    ///
+    /// Placed after all other columns so that their offsets are unchanged, the decoding columns
+    /// that follow the main columns are shifted by one.
+    #[size = 1]
+    CarryOut,
 }
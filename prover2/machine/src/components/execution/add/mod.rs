@@ -7,11 +7,11 @@ use stwo::{
         ColumnVec,
     },
     prover::{
-        backend::simd::{m31::LOG_N_LANES, SimdBackend},
+        backend::simd::{m31::PackedBaseField, SimdBackend},
         poly::{circle::CircleEvaluation, BitReversedOrder},
     },
 };
-use stwo_constraint_framework::EvalAtRow;
+use stwo_constraint_framework::{EvalAtRow, RelationEntry};
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
 use nexus_vm_prover_air_column::AirColumn;
@@ -19,30 +19,28 @@ use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
     eval::TraceEval,
-    program::{ProgramStep, Word},
+    original_base_column,
+    program::{BoolWord, ProgramStep, Word},
     trace_eval,
-    utils::zero_array,
 };
 
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{
+                opcode_component_name, ExecutionColumns, ExecutionComponent, ExecutionEval,
+            },
             decoding::InstructionDecoding,
         },
-        utils::{
-            add_16bit_with_carry, add_with_carries,
-            constraints::{ClkIncrement, PcIncrement},
-            u32_to_16bit_parts_le,
-        },
+        utils::{add_with_carries, constraints::StepCounters},
     },
     framework::BuiltInComponent,
     lookups::{
-        AllLookupElements, ComponentLookupElements, InstToProgMemoryLookupElements,
-        InstToRegisterMemoryLookupElements, LogupTraceBuilder, ProgramExecutionLookupElements,
-        RangeCheckLookupElements,
+        AddCarryOutLookupElements, AllLookupElements, ComponentLookupElements,
+        InstToProgMemoryLookupElements, InstToRegisterMemoryLookupElements,
+        ProgramExecutionLookupElements, RangeCheckLookupElements,
     },
-    side_note::{program::ProgramTraceRef, range_check::RangeCheckAccumulator, SideNote},
+    side_note::{program::ProgramTraceRef, SideNote},
 };
 
 mod add;
@@ -51,6 +49,18 @@ mod columns;
 
 use columns::{Column, PreprocessedColumn};
 
+const COLUMNS: ExecutionColumns<Column> = ExecutionColumns {
+    is_local_pad: Column::IsLocalPad,
+    a_val: Column::AVal,
+    b_val: Column::BVal,
+    step_counters: StepCounters {
+        clk: Column::Clk,
+        clk_carry: Column::ClkCarry,
+        pc: Column::Pc,
+        pc_carry: Column::PcCarry,
+    },
+};
+
 pub const ADD: Add<add::Add> = Add::new();
 pub const ADDI: Add<addi::Addi> = Add::new();
 
@@ -59,11 +69,48 @@ pub trait AddOp:
 {
 }
 
-pub struct Add<A> {
+/// Addition component.
+///
+/// With `EXPOSE_CARRY_OUT` set, every executed step also provides `(clk, carry-out)` to
+/// [`AddCarryOutLookupElements`], which lets a separate component consume the carry out of the
+/// 32-bit sum, e.g. to trap on overflow. The consumer must then look up every step exactly once.
+/// [`ADD`] and [`ADDI`] don't expose the carry, its column is constrained but otherwise unused,
+/// and the relation is not a part of their lookup elements.
+pub struct Add<A, const EXPOSE_CARRY_OUT: bool = false> {
     _phantom: PhantomData<A>,
 }
 
-impl<T: AddOp> ExecutionComponent for Add<T> {
+/// Selects the carry-out lookup elements of [`Add`] by the value of `EXPOSE_CARRY_OUT`.
+pub struct CarryOut<const EXPOSE: bool>;
+
+/// Lookup elements required by [`Add`] for the carry-out relation.
+pub trait CarryOutLookup {
+    /// [`AddCarryOutLookupElements`] if the carry out is exposed, no elements otherwise.
+    type LookupElements: ComponentLookupElements + Sync + 'static;
+
+    /// Returns the carry-out lookup elements if the carry out is exposed.
+    fn carry_out(lookup_elements: &Self::LookupElements) -> Option<&AddCarryOutLookupElements>;
+}
+
+impl CarryOutLookup for CarryOut<false> {
+    type LookupElements = ();
+
+    fn carry_out(_lookup_elements: &()) -> Option<&AddCarryOutLookupElements> {
+        None
+    }
+}
+
+impl CarryOutLookup for CarryOut<true> {
+    type LookupElements = AddCarryOutLookupElements;
+
+    fn carry_out(
+        lookup_elements: &AddCarryOutLookupElements,
+    ) -> Option<&AddCarryOutLookupElements> {
+        Some(lookup_elements)
+    }
+}
+
+impl<T: AddOp, const EXPOSE_CARRY_OUT: bool> ExecutionComponent for Add<T, EXPOSE_CARRY_OUT> {
     const OPCODE: BuiltinOpcode = <T as InstructionDecoding>::OPCODE;
 
     const REG1_ACCESSED: bool = true;
@@ -73,11 +120,21 @@ impl<T: AddOp> ExecutionComponent for Add<T> {
 }
 
 struct ExecutionResult {
-    carry_bits: [bool; 2], // carry bits for 16-bit boundaries
+    /// Carry out of each byte of the sum.
+    byte_carry_bits: BoolWord,
     sum_bytes: Word,
+    /// Carry out of the 32-bit sum, set when it wraps past `u32::MAX`.
+    carry_out: bool,
+}
+
+impl ExecutionResult {
+    /// Carry bits for 16-bit boundaries, the only ones committed to the trace.
+    fn carry_bits(&self) -> [bool; 2] {
+        [self.byte_carry_bits[1], self.byte_carry_bits[3]]
+    }
 }
 
-impl<T: AddOp> Add<T> {
+impl<T: AddOp, const EXPOSE_CARRY_OUT: bool> Add<T, EXPOSE_CARRY_OUT> {
     const fn new() -> Self {
         assert!(matches!(
             T::OPCODE,
@@ -90,12 +147,12 @@ impl<T: AddOp> Add<T> {
 
     fn execute_step(value_b: Word, value_c: Word) -> ExecutionResult {
         // Recompute 32-bit result from 8-bit limbs.
-        let (sum_bytes, carry_bits) = add_with_carries(value_b, value_c);
-        let carry_bits = [carry_bits[1], carry_bits[3]];
+        let (sum_bytes, byte_carry_bits) = add_with_carries(value_b, value_c);
 
         ExecutionResult {
-            carry_bits,
+            byte_carry_bits,
             sum_bytes,
+            carry_out: byte_carry_bits[WORD_SIZE - 1],
         }
     }
 
@@ -105,36 +162,79 @@ impl<T: AddOp> Add<T> {
         row_idx: usize,
         program_step: ProgramStep,
     ) {
-        let step = &program_step.step;
-
-        let pc = step.pc;
-        let pc_parts = u32_to_16bit_parts_le(pc);
-        let (_pc_next, pc_carry) = add_16bit_with_carry(pc_parts, WORD_SIZE as u16);
-
-        let clk = step.timestamp;
-        let clk_parts = u32_to_16bit_parts_le(clk);
-        let (_clk_next, clk_carry) = add_16bit_with_carry(clk_parts, 1u16);
-
         let value_b = program_step.get_value_b();
-        let (value_c, _) = program_step.get_value_c();
-        let ExecutionResult {
-            carry_bits,
-            sum_bytes,
-        } = Self::execute_step(value_b, value_c);
+        let (value_c, c_bits) = program_step.get_value_c();
+        // only register-sourced c spans all 32 bits, immediates are already sign-extended
+        assert_eq!(c_bits == 32, T::REG2_ACCESSED, "{:?} has {c_bits}-bit c", T::OPCODE);
+        let result = Self::execute_step(value_b, value_c);
+
+        COLUMNS.fill(trace, row_idx, &program_step, &result.sum_bytes, &value_b);
+        trace.fill_columns(row_idx, result.carry_bits(), Column::HCarry);
+        trace.fill_columns(row_idx, result.carry_out, Column::CarryOut);
+    }
 
-        trace.fill_columns(row_idx, pc_parts, Column::Pc);
-        trace.fill_columns(row_idx, pc_carry, Column::PcCarry);
+    /// Constrains a-val to the sum of b-val and c-val, and carry-out to the carry out of the
+    /// 32-bit sum.
+    fn constrain_sum<E: EvalAtRow>(
+        eval: &mut E,
+        execution_eval: &ExecutionEval<E::F>,
+        [h_carry_1, h_carry_2]: [E::F; 2],
+        carry_out: E::F,
+    ) {
+        let ExecutionEval {
+            is_local_pad,
+            a_val,
+            b_val,
+            c_val,
+            ..
+        } = execution_eval;
+        let modulus = E::F::from(256u32.into());
 
-        trace.fill_columns(row_idx, clk_parts, Column::Clk);
-        trace.fill_columns(row_idx, clk_carry, Column::ClkCarry);
+        // add two bytes at a time
+        //
+        // (1 − is-local-pad) · (a-val(1) + h-carry(1) · 2^8 − b-val(1) − c-val(1) ) = 0
+        // (1 − is-local-pad) · (a-val(2) + h-carry(2) · 2^8 − b-val(2) − c-val(2) − h-carry(1)) = 0
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (a_val[0].clone()
+                    + a_val[1].clone() * modulus.clone()
+                    + h_carry_1.clone() * modulus.clone().pow(2)
+                    - (b_val[0].clone()
+                        + b_val[1].clone() * modulus.clone()
+                        + c_val[0].clone()
+                        + c_val[1].clone() * modulus.clone())),
+        );
+        // (1 − is-local-pad) · (a-val(3) + h-carry(3) · 2^8 − b-val(3) − c-val(3) − h-carry(2)) = 0
+        // (1 − is-local-pad) · (a-val(4) + h-carry(4) · 2^8 − b-val(4) − c-val(4) − h-carry(3)) = 0
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (a_val[2].clone()
+                    + a_val[3].clone() * modulus.clone()
+                    + h_carry_2.clone() * modulus.clone().pow(2)
+                    - (b_val[2].clone()
+                        + b_val[3].clone() * modulus.clone()
+                        + c_val[2].clone()
+                        + c_val[3].clone() * modulus.clone()
+                        + h_carry_1)),
+        );
 
-        trace.fill_columns_bytes(row_idx, &value_b, Column::BVal);
-        trace.fill_columns_bytes(row_idx, &sum_bytes, Column::AVal);
-        trace.fill_columns(row_idx, carry_bits, Column::HCarry);
+        // (carry-out) · (1 − carry-out) = 0
+        // (1 − is-local-pad) · (carry-out − h-carry(2)) = 0
+        eval.add_constraint(carry_out.clone() * (E::F::one() - carry_out.clone()));
+        eval.add_constraint((E::F::one() - is_local_pad.clone()) * (carry_out - h_carry_2));
     }
 }
 
-impl<T: AddOp> BuiltInComponent for Add<T> {
+impl<T: AddOp, const EXPOSE_CARRY_OUT: bool> BuiltInComponent for Add<T, EXPOSE_CARRY_OUT>
+where
+    CarryOut<EXPOSE_CARRY_OUT>: CarryOutLookup,
+{
+    const NAME: &'static str = match (Self::OPCODE, EXPOSE_CARRY_OUT) {
+        (BuiltinOpcode::ADD, true) => "ADD_CARRY_OUT",
+        (BuiltinOpcode::ADDI, true) => "ADDI_CARRY_OUT",
+        (opcode, _) => opcode_component_name(opcode),
+    };
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -144,6 +244,7 @@ impl<T: AddOp> BuiltInComponent for Add<T> {
         ProgramExecutionLookupElements,
         InstToRegisterMemoryLookupElements,
         RangeCheckLookupElements,
+        <CarryOut<EXPOSE_CARRY_OUT> as CarryOutLookup>::LookupElements,
     );
 
     fn generate_preprocessed_trace(
@@ -154,32 +255,19 @@ impl<T: AddOp> BuiltInComponent for Add<T> {
         FinalizedTrace::empty()
     }
 
-    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
-        let num_add_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
-        let log_size = num_add_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
-
-        let mut common_trace = TraceBuilder::new(log_size);
-        let mut local_trace = TraceBuilder::new(log_size);
-        let mut range_check_accum = RangeCheckAccumulator::default();
-
-        for (row_idx, program_step) in
-            <Self as ExecutionComponent>::iter_program_steps(side_note).enumerate()
-        {
-            self.generate_trace_row(&mut common_trace, row_idx, program_step);
-            T::generate_trace_row(
-                row_idx,
-                &mut local_trace,
-                program_step,
-                &mut range_check_accum,
-            );
-        }
-        side_note.range_check.append(range_check_accum);
-        // fill padding
-        for row_idx in num_add_steps..1 << log_size {
-            common_trace.fill_columns(row_idx, true, Column::IsLocalPad);
-        }
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
 
-        common_trace.finalize().concat(local_trace.finalize())
+    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
+        <Self as ExecutionComponent>::generate_main_trace_with(
+            side_note,
+            Column::IsLocalPad,
+            |common_trace, local_trace, row_idx, program_step, range_check_accum| {
+                self.generate_trace_row(common_trace, row_idx, program_step);
+                T::generate_trace_row(row_idx, local_trace, program_step, range_check_accum);
+            },
+        )
     }
 
     fn generate_interaction_trace(
@@ -191,33 +279,26 @@ impl<T: AddOp> BuiltInComponent for Add<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + T::DecodingColumn::COLUMNS_NUM
-        );
-        let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
-            Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
-
-        let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
-        <T as InstructionDecoding>::generate_interaction_trace(
-            &mut logup_trace_builder,
-            &component_trace,
-            &range_check,
-        );
-        <Self as ExecutionComponent>::generate_interaction_trace(
-            &mut logup_trace_builder,
+        <Self as ExecutionComponent>::generate_execution_interaction_trace::<T>(
             &component_trace,
             side_note,
-            &(
-                rel_inst_to_prog_memory,
-                rel_cont_prog_exec,
-                rel_inst_to_reg_memory,
-            ),
-            is_local_pad,
-        );
-
-        logup_trace_builder.finalize()
+            lookup_elements,
+            Column::IsLocalPad,
+            |logup_trace_builder, is_local_pad| {
+                if EXPOSE_CARRY_OUT {
+                    let rel_add_carry_out: &AddCarryOutLookupElements = lookup_elements.as_ref();
+                    let [clk_low, clk_high] = original_base_column!(component_trace, Column::Clk);
+                    let [carry_out] = original_base_column!(component_trace, Column::CarryOut);
+                    // provide(rel-add-carry-out, 1 − is-local-pad, (clk, carry-out))
+                    logup_trace_builder.add_to_relation_with(
+                        rel_add_carry_out,
+                        [is_local_pad],
+                        |[is_local_pad]| (PackedBaseField::one() - is_local_pad).into(),
+                        &[clk_low, clk_high, carry_out],
+                    );
+                }
+            },
+        )
     }
 
     fn add_constraints<E: EvalAtRow>(
@@ -226,92 +307,42 @@ impl<T: AddOp> BuiltInComponent for Add<T> {
         trace_eval: TraceEval<Self::PreprocessedColumn, Self::MainColumn, E>,
         lookup_elements: &Self::LookupElements,
     ) {
-        let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
-            lookup_elements;
-        let local_trace_eval = TraceEval::new(eval);
-
-        let [is_local_pad] = trace_eval!(trace_eval, Column::IsLocalPad);
-        let [h_carry_1, h_carry_2] = trace_eval!(trace_eval, Column::HCarry);
-
-        let a_val = trace_eval!(trace_eval, Column::AVal);
-        let b_val = trace_eval!(trace_eval, Column::BVal);
-        let c_val = T::combine_c_val(&local_trace_eval);
-
-        let clk = trace_eval!(trace_eval, Column::Clk);
-        let clk_next = ClkIncrement {
-            clk: Column::Clk,
-            clk_carry: Column::ClkCarry,
-        }
-        .eval(eval, &trace_eval);
-
-        let pc = trace_eval!(trace_eval, Column::Pc);
-        let pc_next = PcIncrement {
-            pc: Column::Pc,
-            pc_carry: Column::PcCarry,
-        }
-        .eval(eval, &trace_eval);
-
-        let modulus = E::F::from(256u32.into());
-
-        // add two bytes at a time
-        //
-        // (1 − is-local-pad) · (a-val(1) + h-carry(1) · 2^8 − b-val(1) − c-val(1) ) = 0
-        // (1 − is-local-pad) · (a-val(2) + h-carry(2) · 2^8 − b-val(2) − c-val(2) − h-carry(1)) = 0
-        eval.add_constraint(
-            (E::F::one() - is_local_pad.clone())
-                * (a_val[0].clone()
-                    + a_val[1].clone() * modulus.clone()
-                    + h_carry_1.clone() * modulus.clone().pow(2)
-                    - (b_val[0].clone()
-                        + b_val[1].clone() * modulus.clone()
-                        + c_val[0].clone()
-                        + c_val[1].clone() * modulus.clone())),
-        );
-        // (1 − is-local-pad) · (a-val(3) + h-carry(3) · 2^8 − b-val(3) − c-val(3) − h-carry(2)) = 0
-        // (1 − is-local-pad) · (a-val(4) + h-carry(4) · 2^8 − b-val(4) − c-val(4) − h-carry(3)) = 0
-        eval.add_constraint(
-            (E::F::one() - is_local_pad.clone())
-                * (a_val[2].clone()
-                    + a_val[3].clone() * modulus.clone()
-                    + h_carry_2.clone() * modulus.clone().pow(2)
-                    - (b_val[2].clone()
-                        + b_val[3].clone() * modulus.clone()
-                        + c_val[2].clone()
-                        + c_val[3].clone() * modulus.clone()
-                        + h_carry_1.clone())),
-        );
-        T::constrain_decoding(eval, &trace_eval, &local_trace_eval, range_check);
-
-        // Logup Interactions
-        let instr_val = T::combine_instr_val(&local_trace_eval);
-        let reg_addrs = T::combine_reg_addresses(&local_trace_eval);
-
-        let c_val = if Self::REG2_ACCESSED {
-            c_val
-        } else {
-            zero_array::<WORD_SIZE, E>()
-        };
-
-        <Self as ExecutionComponent>::constrain_logups(
+        let (
+            rel_inst_to_prog_memory,
+            rel_cont_prog_exec,
+            rel_inst_to_reg_memory,
+            range_check,
+            rel_add_carry_out,
+        ) = lookup_elements;
+        let rel_add_carry_out = CarryOut::<EXPOSE_CARRY_OUT>::carry_out(rel_add_carry_out);
+        let h_carry = trace_eval!(trace_eval, Column::HCarry);
+        let [carry_out] = trace_eval!(trace_eval, Column::CarryOut);
+
+        <Self as ExecutionComponent>::constrain_execution::<E, T>(
             eval,
+            &trace_eval,
+            &COLUMNS,
             (
                 rel_inst_to_prog_memory,
                 rel_cont_prog_exec,
                 rel_inst_to_reg_memory,
+                range_check,
             ),
-            ExecutionLookupEval {
-                is_local_pad,
-                reg_addrs,
-                reg_values: [a_val, b_val, c_val],
-                instr_val,
-                clk,
-                clk_next,
-                pc,
-                pc_next,
+            |eval, execution_eval| {
+                Self::constrain_sum(eval, execution_eval, h_carry, carry_out.clone());
+
+                if let Some(rel_add_carry_out) = rel_add_carry_out {
+                    let is_local_pad = execution_eval.is_local_pad.clone();
+                    let clk = &execution_eval.step_counters.clk;
+                    // provide(rel-add-carry-out, 1 − is-local-pad, (clk, carry-out))
+                    eval.add_to_relation(RelationEntry::new(
+                        rel_add_carry_out,
+                        (E::F::one() - is_local_pad).into(),
+                        &[clk[0].clone(), clk[1].clone(), carry_out],
+                    ));
+                }
             },
         );
-
-        eval.finalize_logup_in_pairs();
     }
 }
 
@@ -324,13 +355,23 @@ mod tests {
             Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
             RegisterMemoryBoundary, RANGE16, RANGE256, RANGE64, RANGE8,
         },
-        framework::test_utils::{assert_component, components_claimed_sum, AssertContext},
+        framework::{
+            test_utils::{
+                assert_claimed_sum_zero, assert_component, assert_reference_table,
+                components_claimed_sum, stub_claimed_sum, AssertContext,
+            },
+            MachineComponent,
+        },
+        lookups::record_relations,
+        prove_with_report, ProvingConfig,
     };
+    use nexus_common::constants::WORD_SIZE_HALVED;
     use nexus_vm::{
         riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
         trace::k_trace_direct,
     };
     use num_traits::Zero;
+    use stwo::prover::backend::Column as _;
 
     #[test]
     fn assert_add_constraints() {
@@ -351,13 +392,14 @@ mod tests {
             k_trace_direct(&basic_block, 1).expect("error generating trace");
 
         let assert_ctx = &mut AssertContext::new(&program_trace, &view);
-        let mut claimed_sum = SecureField::zero();
-
-        claimed_sum += assert_component(ADD, assert_ctx);
-        claimed_sum += assert_component(ADDI, assert_ctx);
+        assert_component(ADD, assert_ctx);
+        assert_component(ADDI, assert_ctx);
 
-        claimed_sum += components_claimed_sum(
+        // traces are regenerated, the side note of the first context already counts their lookups
+        assert_claimed_sum_zero(
             &[
+                &ADD,
+                &ADDI,
                 &Cpu,
                 &CpuBoundary,
                 &RegisterMemory,
@@ -369,9 +411,281 @@ mod tests {
                 &RANGE64,
                 &RANGE256,
             ],
-            assert_ctx,
+            &mut AssertContext::new(&program_trace, &view),
+        );
+    }
+
+    #[test]
+    fn assert_add_constraints_with_stub_memory() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 127),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 4, 3, 3),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        // only the add component and range checks, registers and program memory are stubbed
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let (claimed_sum, log) = record_relations(|| {
+            assert_component(ADD, assert_ctx)
+                + components_claimed_sum(&[&RANGE8, &RANGE16, &RANGE64, &RANGE256], assert_ctx)
+        });
+        assert!(!claimed_sum.is_zero());
+
+        let range_checks = RangeCheckLookupElements::relation_names();
+        let stub_sum = stub_claimed_sum(&log, &assert_ctx.lookup_elements, &range_checks);
+        assert!((claimed_sum + stub_sum).is_zero());
+    }
+
+    #[test]
+    fn add_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::ADD,
+            ADD,
+            Column::AVal,
+            &[
+                (1, 2, 3),
+                (0x0000_FFFF, 1, 0x0001_0000),
+                (0x00FF_00FF, 0x0001_FF01, 0x0101_0000),
+                (0x7FFF_FFFF, 1, 0x8000_0000),
+                (0xFFFF_FFFF, 1, 0),
+                (0x8000_0000, 0x8000_0000, 0),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFE),
+            ],
+            &[],
         );
+    }
+
+    #[test]
+    #[should_panic(expected = "Range64LookupElements")]
+    fn claimed_sum_reports_unbalanced_relation() {
+        let basic_block = vec![BasicBlock::new(vec![Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADD),
+            1,
+            0,
+            0,
+        )])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        // the pc range checks of the cpu are never provided
+        assert_claimed_sum_zero(
+            &[
+                &ADD,
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &RANGE8,
+                &RANGE16,
+                &RANGE256,
+            ],
+            &mut AssertContext::new(&program_trace, &view),
+        );
+    }
+
+    #[test]
+    fn add_read_back_result() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1000),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 2, 0, 0xFFFFF),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 4, 3, 3),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let trace = ADD.generate_main_trace(&mut assert_ctx.side_note);
+
+        let b = 1000u32;
+        let c = 0xFFFFF000u32;
+        assert_eq!(trace.read_word(0, Column::AVal), b.wrapping_add(c));
+        assert_eq!(trace.read_word(1, Column::AVal), b.wrapping_add(c).wrapping_mul(2));
+    }
+
+    fn wrapping_add_program() -> Vec<BasicBlock> {
+        vec![BasicBlock::new(vec![
+            // x1 = u32::MAX
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 0xFFF),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 4, 2, 2),
+        ])]
+    }
+
+    #[test]
+    fn carry_out_set_on_wrap() {
+        let (view, program_trace) =
+            k_trace_direct(&wrapping_add_program(), 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let trace = ADD.generate_main_trace(&mut assert_ctx.side_note);
+        let carry_out = &trace.cols[Column::CarryOut.offset()];
+        // u32::MAX + 1 wraps to zero, 1 + 1 doesn't
+        assert_eq!(carry_out.at(0), BaseField::one());
+        assert_eq!(carry_out.at(1), BaseField::zero());
+    }
+
+    #[test]
+    fn exposed_carry_out_lookup() {
+        const ADD_WITH_CARRY_OUT: Add<add::Add, true> = Add::new();
+
+        let (view, program_trace) =
+            k_trace_direct(&wrapping_add_program(), 1).expect("error generating trace");
+        let relation = AddCarryOutLookupElements::relation_names()[0];
+
+        // the relation is only required when the carry is exposed
+        let relations = <<Add<add::Add> as BuiltInComponent>::LookupElements>::relation_names();
+        assert!(!relations.contains(&relation));
+        let relations =
+            <<Add<add::Add, true> as BuiltInComponent>::LookupElements>::relation_names();
+        assert!(relations.contains(&relation));
+
+        // the carry is only provided when exposed
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let (_, log) = record_relations(|| assert_component(ADD, assert_ctx));
+        assert!(log.iter().all(|entry| entry.relation != relation));
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let (_, log) = record_relations(|| assert_component(ADD_WITH_CARRY_OUT, assert_ctx));
+        let carry_outs: Vec<(SecureField, BaseField)> = log
+            .iter()
+            .filter(|entry| entry.relation == relation)
+            .map(|entry| (entry.multiplicity, entry.values[WORD_SIZE_HALVED]))
+            .collect();
+        assert_eq!(
+            carry_outs,
+            [
+                (SecureField::one(), BaseField::one()),
+                (SecureField::one(), BaseField::zero()),
+            ]
+        );
+    }
+
+    #[test]
+    fn value_c_effective_bits() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 0xFFF),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 0, 1),
+        ])];
+        let (_, program_trace) = k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let [addi_step, add_step]: [ProgramStep; 2] = program_trace
+            .blocks
+            .iter()
+            .map(ProgramStep::from)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        // immediate: sign-extended value with the width of the encoded field
+        assert_eq!(addi_step.get_value_c(), (0xFFFF_FFFFu32.to_le_bytes(), 12));
+        // register: value of x1 read from the register file
+        assert_eq!(add_step.get_value_c(), (0xFFFF_FFFFu32.to_le_bytes(), 32));
+    }
+
+    #[test]
+    fn execute_step_byte_carries() {
+        const VALUES: [u32; 7] = [0, 1, 0xFF, 0xFFFF, 0x00FF_00FF, 0x8000_0000, u32::MAX];
+        for b in VALUES {
+            for c in VALUES {
+                let result = Add::<add::Add>::execute_step(b.to_le_bytes(), c.to_le_bytes());
+                assert_eq!(result.sum_bytes, b.wrapping_add(c).to_le_bytes());
+
+                // carry out of byte i is the overflow of the sum truncated to the lowest i + 1 bytes
+                let expected: BoolWord = std::array::from_fn(|i| {
+                    let mask = (1u64 << (8 * (i + 1))) - 1;
+                    (b as u64 & mask) + (c as u64 & mask) > mask
+                });
+                assert_eq!(result.byte_carry_bits, expected, "{b:#x} + {c:#x}");
+                assert_eq!(result.carry_bits(), [expected[1], expected[3]]);
+                assert_eq!(result.carry_out, expected[WORD_SIZE - 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn miscounted_trace_width() {
+        let basic_block = vec![BasicBlock::new(vec![Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADD),
+            1,
+            0,
+            0,
+        )])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut component_trace = ADD.generate_component_trace(&mut assert_ctx.side_note);
+        assert_eq!(ADD.validate_trace_width(&component_trace), Ok(()));
+
+        // drop the last decoding column
+        let num_columns = component_trace.original_trace.len();
+        component_trace.original_trace.pop();
+        let err = ADD.validate_trace_width(&component_trace).unwrap_err();
+        assert_eq!((err.expected, err.actual), (num_columns, num_columns - 1));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{}: main trace has {} columns, but constraints read {num_columns}",
+                ADD.ordering_key(),
+                num_columns - 1,
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "main trace has")]
+    fn miscounted_trace_rejected_by_interaction() {
+        let basic_block = vec![BasicBlock::new(vec![Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADD),
+            1,
+            0,
+            0,
+        )])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut component_trace = ADD.generate_component_trace(&mut assert_ctx.side_note);
+        component_trace.original_trace.pop();
+        MachineComponent::generate_interaction_trace(
+            &ADD,
+            component_trace,
+            &assert_ctx.side_note,
+            &assert_ctx.lookup_elements,
+        );
+    }
+
+    #[test]
+    fn add_sum_reconstruction_bounds() {
+        // x1 = 0xFFFFFFFF, x2 = x1 + x1, x3 = x2 + x1
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 0xFFF),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
 
-        assert!(claimed_sum.is_zero());
+        let config = ProvingConfig::dev().with_expression_bounds();
+        let (_, report) = prove_with_report(&program_trace, &view, config).unwrap();
+        let bounds = report
+            .components
+            .iter()
+            .find(|c| c.name == ADD.ordering_key())
+            .and_then(|c| c.expression_bounds.as_ref())
+            .expect("bounds of ADD are reported");
+        assert!(bounds.iter().all(|bound| !bound.near_modulus()));
+
+        let magnitudes: Vec<u64> = bounds.iter().map(|bound| bound.max_magnitude).collect();
+        // a-val(1) + a-val(2) · 2^8 + h-carry(1) · 2^16 = 0xFFFF + 0xFFFF
+        assert!(magnitudes.contains(&0x1FFFE), "{magnitudes:x?}");
+        // a-val(3) + a-val(4) · 2^8 + h-carry(2) · 2^16 = 0xFFFF + 0xFFFF + h-carry(1)
+        assert!(magnitudes.contains(&0x1FFFF), "{magnitudes:x?}");
     }
 }
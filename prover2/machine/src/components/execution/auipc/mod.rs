@@ -12,7 +12,7 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::{empty::EmptyPreprocessedColumn, AirColumn};
+use nexus_vm_prover_air_column::empty::EmptyPreprocessedColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -25,7 +25,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::{
                 type_u::{self, TypeUDecoding},
                 InstructionDecoding,
@@ -125,6 +125,8 @@ impl Auipc {
 }
 
 impl BuiltInComponent for Auipc {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -144,6 +146,10 @@ impl BuiltInComponent for Auipc {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -181,13 +187,10 @@ impl BuiltInComponent for Auipc {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + type_u::DecodingColumn::COLUMNS_NUM
-        );
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
         let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
 
         Decoding::generate_interaction_trace(
@@ -313,6 +316,7 @@ mod tests {
         },
         framework::test_utils::{assert_component, components_claimed_sum, AssertContext},
     };
+    use nexus_common::constants::ELF_TEXT_START;
     use nexus_vm::{
         riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
         trace::k_trace_direct,
@@ -363,4 +367,47 @@ mod tests {
 
         assert!(claimed_sum.is_zero());
     }
+
+    #[test]
+    fn auipc_carries_across_halves() {
+        // pad the program so that the tested instructions start at pc = 0x1000
+        let num_padding = (0x1000 - ELF_TEXT_START) as usize / WORD_SIZE;
+        let mut instructions =
+            vec![Instruction::new_ir(Opcode::from(BuiltinOpcode::AUIPC), 0, 0, 0); num_padding];
+        instructions.extend([
+            // AUIPC x1, 0xF (x1 = 0x1000 + 0xF000, carry into the upper half)
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::AUIPC), 1, 0, 0xF),
+            // AUIPC x2, 0xFFFFF (x2 = 0x1004 + 0xFFFFF000, carry out of both halves)
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::AUIPC), 2, 0, 0xFFFFF),
+        ]);
+        let basic_block = vec![BasicBlock::new(instructions)];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let results: Vec<_> = program_trace.blocks[num_padding..]
+            .iter()
+            .map(|block| (block.steps[0].pc, block.steps[0].result))
+            .collect();
+        assert_eq!(results, [(0x1000, Some(0x10000)), (0x1004, Some(0x4))]);
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = assert_component(Auipc, assert_ctx);
+
+        claimed_sum += components_claimed_sum(
+            &[
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &RANGE16,
+                &RANGE64,
+                &RANGE256,
+            ],
+            assert_ctx,
+        );
+
+        assert!(claimed_sum.is_zero());
+    }
 }
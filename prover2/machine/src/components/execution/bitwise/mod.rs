@@ -31,7 +31,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::InstructionDecoding,
         },
         utils::constraints::{ClkIncrement, PcIncrement},
@@ -62,6 +62,11 @@ pub const AND_LOOKUP_IDX: u32 = 1;
 pub const OR_LOOKUP_IDX: u32 = 2;
 pub const XOR_LOOKUP_IDX: u32 = 3;
 
+/// Returns whether the index refers to one of the bitwise lookup tables.
+const fn is_valid_lookup_idx(idx: u32) -> bool {
+    matches!(idx, AND_LOOKUP_IDX | OR_LOOKUP_IDX | XOR_LOOKUP_IDX)
+}
+
 pub trait BitwiseOp:
     InstructionDecoding<PreprocessedColumn = PreprocessedColumn, MainColumn = Column>
 {
@@ -109,7 +114,14 @@ struct ExecutionResult {
 }
 
 impl<T: BitwiseOp> Bitwise<T> {
+    /// Evaluated when the component is constructed, rejects misconfigured ops at build time.
+    const VALID_LOOKUP_IDX: () = assert!(
+        is_valid_lookup_idx(T::BITWISE_LOOKUP_IDX),
+        "invalid bitwise lookup idx"
+    );
+
     const fn new() -> Self {
+        let _ = Self::VALID_LOOKUP_IDX;
         Self {
             _phantom: PhantomData,
         }
@@ -117,6 +129,8 @@ impl<T: BitwiseOp> Bitwise<T> {
 }
 
 impl<T: BitwiseOp> BuiltInComponent for Bitwise<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -137,6 +151,10 @@ impl<T: BitwiseOp> BuiltInComponent for Bitwise<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -167,12 +185,7 @@ impl<T: BitwiseOp> BuiltInComponent for Bitwise<T> {
         }
 
         // store computed multiplicities
-        let accum_mut = match T::BITWISE_LOOKUP_IDX {
-            idx if idx == AND_LOOKUP_IDX => &mut side_note.bitwise.bitwise_mults_and,
-            idx if idx == OR_LOOKUP_IDX => &mut side_note.bitwise.bitwise_mults_or,
-            idx if idx == XOR_LOOKUP_IDX => &mut side_note.bitwise.bitwise_mults_xor,
-            _ => panic!("invalid lookup idx"),
-        };
+        let accum_mut = side_note.bitwise.get_mut(T::BITWISE_LOOKUP_IDX);
         for (row, mult) in accum.accum.iter() {
             *accum_mut.accum.entry(*row).or_default() += mult;
         }
@@ -199,7 +212,8 @@ impl<T: BitwiseOp> BuiltInComponent for Bitwise<T> {
             rel_bitwise_instr,
             range_check,
         ) = Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
 
@@ -377,7 +391,9 @@ mod tests {
             RegisterMemory, RegisterMemoryBoundary, ADD, ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
         },
         framework::{
-            test_utils::{assert_component, components_claimed_sum, AssertContext},
+            test_utils::{
+                assert_claimed_sum_zero, assert_component, assert_reference_table, AssertContext,
+            },
             MachineComponent,
         },
     };
@@ -385,7 +401,6 @@ mod tests {
         riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
         trace::k_trace_direct,
     };
-    use num_traits::Zero;
 
     const BASE_TEST_COMPONENTS: &[&dyn MachineComponent] = &[
         &Cpu,
@@ -413,16 +428,71 @@ mod tests {
         let (view, program_trace) =
             k_trace_direct(&basic_block, 1).expect("error generating trace");
 
+        let components: Vec<&dyn MachineComponent> = [&c1 as &dyn MachineComponent, &c2]
+            .into_iter()
+            .chain(BASE_TEST_COMPONENTS.iter().copied())
+            .chain([&BitwiseMultiplicity as &dyn MachineComponent])
+            .collect();
+        assert_claimed_sum_zero(&components, &mut AssertContext::new(&program_trace, &view));
+
         let assert_ctx = &mut AssertContext::new(&program_trace, &view);
-        let mut claimed_sum = SecureField::zero();
+        assert_component(c1, assert_ctx);
+        assert_component(c2, assert_ctx);
+        assert_component(BitwiseMultiplicity, assert_ctx);
+    }
 
-        claimed_sum += assert_component(c1, assert_ctx);
-        claimed_sum += assert_component(c2, assert_ctx);
+    #[test]
+    fn and_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::AND,
+            AND,
+            Column::AVal,
+            &[
+                (0xF0F0_1234, 0xFF00_00FF, 0xF000_0034),
+                (0xFFFF_FFFF, 0, 0),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF),
+            ],
+            &[&BitwiseMultiplicity],
+        );
+    }
 
-        claimed_sum += components_claimed_sum(BASE_TEST_COMPONENTS, assert_ctx);
-        claimed_sum += assert_component(BitwiseMultiplicity, assert_ctx);
+    #[test]
+    fn or_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::OR,
+            OR,
+            Column::AVal,
+            &[
+                (0xF0F0_1234, 0xFF00_00FF, 0xFFF0_12FF),
+                (0, 0, 0),
+                (0, 0xFFFF_FFFF, 0xFFFF_FFFF),
+            ],
+            &[&BitwiseMultiplicity],
+        );
+    }
 
-        assert!(claimed_sum.is_zero());
+    #[test]
+    fn xor_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::XOR,
+            XOR,
+            Column::AVal,
+            &[
+                (0xF0F0_1234, 0xFF00_00FF, 0x0FF0_12CB),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0),
+                (0x5555_5555, 0xAAAA_AAAA, 0xFFFF_FFFF),
+            ],
+            &[&BitwiseMultiplicity],
+        );
+    }
+
+    #[test]
+    fn lookup_idx_validation() {
+        for idx in [AND_LOOKUP_IDX, OR_LOOKUP_IDX, XOR_LOOKUP_IDX] {
+            assert!(is_valid_lookup_idx(idx));
+        }
+        assert!(!is_valid_lookup_idx(0));
+        assert!(!is_valid_lookup_idx(XOR_LOOKUP_IDX + 1));
     }
 
     #[test]
@@ -455,6 +525,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn assert_shared_table_constraints() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 27), // x1 = 0b11011
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 21), // x2 = 0b10101
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::AND), 3, 1, 2),   // x3 = x1 & x2
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::OR), 4, 1, 2),    // x4 = x1 | x2
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::XOR), 5, 1, 2),   // x5 = x1 ^ x2
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::XORI), 6, 1, 21), // x6 = x1 ^ 0b10101
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        assert_component(AND, assert_ctx);
+        assert_component(OR, assert_ctx);
+        assert_component(XOR, assert_ctx);
+        assert_component(XORI, assert_ctx);
+        assert_component(BitwiseMultiplicity, assert_ctx);
+
+        // all ops are looked up from the single multiplicity table
+        let components: Vec<&dyn MachineComponent> =
+            [&AND as &dyn MachineComponent, &OR, &XOR, &XORI]
+                .into_iter()
+                .chain(BASE_TEST_COMPONENTS.iter().copied())
+                .chain([&BitwiseMultiplicity as &dyn MachineComponent])
+                .collect();
+        assert_claimed_sum_zero(&components, &mut AssertContext::new(&program_trace, &view));
+    }
+
     #[test]
     fn assert_xor_constraints() {
         assert_components(
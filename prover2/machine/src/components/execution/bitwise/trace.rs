@@ -33,7 +33,7 @@ impl<B: BitwiseOp> Bitwise<B> {
                 idx if idx == AND_LOOKUP_IDX => b & c,
                 idx if idx == OR_LOOKUP_IDX => b | c,
                 idx if idx == XOR_LOOKUP_IDX => b ^ c,
-                _ => panic!("invalid lookup idx"),
+                _ => unreachable!("lookup idx is checked on construction"),
             }
         });
 
@@ -35,6 +35,8 @@ impl BitwiseMultiplicity {
 }
 
 impl BuiltInComponent for BitwiseMultiplicity {
+    const NAME: &'static str = "BitwiseMultiplicity";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -66,10 +68,14 @@ impl BuiltInComponent for BitwiseMultiplicity {
         }
     }
 
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::LOG_SIZE
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
-        let multiplicity_and = side_note.bitwise.bitwise_mults_and.multiplicities();
-        let multiplicity_or = side_note.bitwise.bitwise_mults_or.multiplicities();
-        let multiplicity_xor = side_note.bitwise.bitwise_mults_xor.multiplicities();
+        let multiplicity_and = side_note.bitwise.get(AND_LOOKUP_IDX).multiplicities();
+        let multiplicity_or = side_note.bitwise.get(OR_LOOKUP_IDX).multiplicities();
+        let multiplicity_xor = side_note.bitwise.get(XOR_LOOKUP_IDX).multiplicities();
 
         let range = 0..=255;
         let multiplicity_and = BaseColumn::from_iter(
@@ -94,14 +100,15 @@ impl BuiltInComponent for BitwiseMultiplicity {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
         let rel_bitwise_instr: &Self::LookupElements = lookup_elements.as_ref();
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [b_val] = preprocessed_base_column!(component_trace, PreprocessedColumn::BVal);
         let [c_val] = preprocessed_base_column!(component_trace, PreprocessedColumn::CVal);
@@ -14,7 +14,7 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::{empty::EmptyPreprocessedColumn, AirColumn};
+use nexus_vm_prover_air_column::empty::EmptyPreprocessedColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -27,11 +27,12 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::{type_b, InstructionDecoding},
         },
         utils::{
-            add_16bit_with_carry, add_with_carries, constraints::ClkIncrement,
+            add_16bit_with_carry, add_with_carries,
+            constraints::{select_word, ClkIncrement},
             u32_to_16bit_parts_le,
         },
     },
@@ -228,6 +229,8 @@ impl<T: BranchOp> BranchEq<T> {
 }
 
 impl<T: BranchOp> BuiltInComponent for BranchEq<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -247,6 +250,10 @@ impl<T: BranchOp> BuiltInComponent for BranchEq<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -292,13 +299,10 @@ impl<T: BranchOp> BuiltInComponent for BranchEq<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + T::DecodingColumn::COLUMNS_NUM
-        );
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
         <T as InstructionDecoding>::generate_interaction_trace(
@@ -406,6 +410,12 @@ impl<T: BranchOp> BuiltInComponent for BranchEq<T> {
         let enforce_branch_flag = T::enforce_branch_flag_eval(&trace_eval);
 
         let c_val = type_b::CVal.eval(&decoding_trace_eval);
+        // pc-offset = enforce-flag · c-val + (1 − enforce-flag) · 4
+        let pc_offset = select_word(
+            &enforce_branch_flag,
+            c_val,
+            [4, 0, 0, 0].map(|byte: u32| E::F::from(BaseField::from(byte))),
+        );
         let [h_carry_1, h_carry_2] = trace_eval!(trace_eval, Column::HCarry);
         // add two bytes at a time
         //
@@ -414,16 +424,15 @@ impl<T: BranchOp> BuiltInComponent for BranchEq<T> {
         // (1 − is-local-pad) · (enforce-flag · c-val(3) + pc(3) + h-carry(2) − pc-next(3) − h-carry(3) · 2^8 ) = 0
         // (1 − is-local-pad) · (enforce-flag · c-val(4) + pc(4) + h-carry(3) − pc-next(4) − h-carry(4) · 2^8 ) = 0
         eval.add_constraint(
-            enforce_branch_flag.clone()
-                * (c_val[0].clone() + c_val[1].clone() * BaseField::from(1 << 8))
-                + (E::F::one() - enforce_branch_flag.clone()) * E::F::from(4u32.into())
+            pc_offset[0].clone()
+                + pc_offset[1].clone() * BaseField::from(1 << 8)
                 + pc[0].clone()
                 - h_carry_1.clone() * BaseField::from(1 << 8).pow(2)
                 - pc_next[0].clone(),
         );
         eval.add_constraint(
-            enforce_branch_flag.clone()
-                * (c_val[2].clone() + c_val[3].clone() * BaseField::from(1 << 8))
+            pc_offset[2].clone()
+                + pc_offset[3].clone() * BaseField::from(1 << 8)
                 + pc[1].clone()
                 + h_carry_1.clone()
                 - h_carry_2.clone() * BaseField::from(1 << 8).pow(2)
@@ -1,25 +1,103 @@
 use num_traits::{One, Zero};
-use stwo::{core::fields::m31::BaseField, prover::backend::simd::m31::PackedBaseField};
+use stwo::{
+    core::{
+        fields::{m31::BaseField, qm31::SecureField},
+        ColumnVec,
+    },
+    prover::{
+        backend::simd::{
+            m31::{PackedBaseField, LOG_N_LANES},
+            SimdBackend,
+        },
+        poly::{circle::CircleEvaluation, BitReversedOrder},
+    },
+};
 use stwo_constraint_framework::{EvalAtRow, RelationEntry};
 
 use nexus_common::constants::WORD_SIZE_HALVED;
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
+use nexus_vm_prover_air_column::{AirColumn, PreprocessedAirColumn};
 use nexus_vm_prover_trace::{
+    builder::{FinalizedTrace, TraceBuilder},
     component::{ComponentTrace, FinalizedColumn},
-    program::ProgramStep,
+    eval::TraceEval,
+    program::{ProgramStep, Word},
+    utils::zero_array,
 };
 
 use crate::{
+    components::{
+        execution::decoding::InstructionDecoding,
+        utils::constraints::{StepCounters, StepCountersEval},
+    },
     lookups::{
-        InstToProgMemoryLookupElements, InstToRegisterMemoryLookupElements, LogupTraceBuilder,
-        ProgramExecutionLookupElements,
+        AllLookupElements, ComponentLookupElements, InstToProgMemoryLookupElements,
+        InstToRegisterMemoryLookupElements, LogupTraceBuilder, ProgramExecutionLookupElements,
+        RangeCheckLookupElements,
     },
-    side_note::SideNote,
+    side_note::{range_check::RangeCheckAccumulator, SideNote},
 };
 
 mod logup_gen;
 pub use logup_gen::{ComponentTraceRef, ExecutionComponentColumn, ExecutionComponentTrace};
 
+/// Returns the name of the component executing the opcode, see [`BuiltInComponent::NAME`].
+///
+/// [`BuiltInComponent::NAME`]: crate::framework::BuiltInComponent::NAME
+pub const fn opcode_component_name(opcode: BuiltinOpcode) -> &'static str {
+    use BuiltinOpcode::*;
+
+    match opcode {
+        ADD => "ADD",
+        SUB => "SUB",
+        SLL => "SLL",
+        SLT => "SLT",
+        SLTU => "SLTU",
+        XOR => "XOR",
+        SRL => "SRL",
+        SRA => "SRA",
+        OR => "OR",
+        AND => "AND",
+        MUL => "MUL",
+        MULH => "MULH",
+        MULHSU => "MULHSU",
+        MULHU => "MULHU",
+        DIV => "DIV",
+        DIVU => "DIVU",
+        REM => "REM",
+        REMU => "REMU",
+        ADDI => "ADDI",
+        SLLI => "SLLI",
+        SLTI => "SLTI",
+        SLTIU => "SLTIU",
+        XORI => "XORI",
+        SRLI => "SRLI",
+        SRAI => "SRAI",
+        ORI => "ORI",
+        ANDI => "ANDI",
+        LB => "LB",
+        LH => "LH",
+        LW => "LW",
+        LBU => "LBU",
+        LHU => "LHU",
+        JALR => "JALR",
+        ECALL => "ECALL",
+        SB => "SB",
+        SH => "SH",
+        SW => "SW",
+        BEQ => "BEQ",
+        BNE => "BNE",
+        BLT => "BLT",
+        BGE => "BGE",
+        BLTU => "BLTU",
+        BGEU => "BGEU",
+        LUI => "LUI",
+        AUIPC => "AUIPC",
+        JAL => "JAL",
+        EBREAK | FENCE | UNIMPL => panic!("opcode isn't executed by a component"),
+    }
+}
+
 pub trait ExecutionComponent {
     const OPCODE: BuiltinOpcode;
 
@@ -38,6 +116,178 @@ pub trait ExecutionComponent {
         })
     }
 
+    /// Returns the log size of the main trace, which has a row for each program step with
+    /// [`Self::OPCODE`].
+    fn trace_log_size(side_note: &SideNote) -> u32 {
+        let num_steps = Self::iter_program_steps(side_note).count();
+        num_steps.next_power_of_two().ilog2().max(LOG_N_LANES)
+    }
+
+    /// Generates the main trace from the rows filled by `fill_row`, one per program step with
+    /// [`Self::OPCODE`], and pads it with rows that only have `is_local_pad` set.
+    ///
+    /// The trace is split into columns shared by the component and columns local to its
+    /// instruction decoding, the latter are placed last.
+    fn generate_main_trace_with<C: AirColumn, L: AirColumn>(
+        side_note: &mut SideNote,
+        is_local_pad: C,
+        mut fill_row: impl FnMut(
+            &mut TraceBuilder<C>,
+            &mut TraceBuilder<L>,
+            usize,
+            ProgramStep,
+            &mut RangeCheckAccumulator,
+        ),
+    ) -> FinalizedTrace {
+        let num_steps = Self::iter_program_steps(side_note).count();
+        let log_size = Self::trace_log_size(side_note);
+
+        let mut common_trace = TraceBuilder::new(log_size);
+        let mut local_trace = TraceBuilder::new(log_size);
+        let mut range_check_accum = RangeCheckAccumulator::default();
+
+        for (row_idx, program_step) in Self::iter_program_steps(side_note).enumerate() {
+            fill_row(
+                &mut common_trace,
+                &mut local_trace,
+                row_idx,
+                program_step,
+                &mut range_check_accum,
+            );
+        }
+        side_note.range_check.append(range_check_accum);
+        // fill padding
+        for row_idx in num_steps..1 << log_size {
+            common_trace.fill_columns(row_idx, true, is_local_pad);
+        }
+
+        common_trace.finalize().concat(local_trace.finalize())
+    }
+
+    /// Generates the interaction trace of a component decoding its instruction with `D`.
+    ///
+    /// Lookups of the instruction decoding come first, followed by the lookups specific to the
+    /// component added by `add_lookups`, and the program and register memory lookups of the step.
+    /// The order matches the one of [`Self::constrain_execution`].
+    fn generate_execution_interaction_trace<D>(
+        component_trace: &ComponentTrace,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+        is_local_pad: D::MainColumn,
+        add_lookups: impl FnOnce(&mut LogupTraceBuilder, FinalizedColumn),
+    ) -> (
+        ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        SecureField,
+    )
+    where
+        D: InstructionDecoding,
+        D::MainColumn: AirColumn,
+    {
+        let range_check = RangeCheckLookupElements::get(lookup_elements);
+        let execution_lookup_elements = <(
+            InstToProgMemoryLookupElements,
+            ProgramExecutionLookupElements,
+            InstToRegisterMemoryLookupElements,
+        )>::get(lookup_elements);
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
+
+        let [is_local_pad] = component_trace.original_base_column(is_local_pad);
+        D::generate_interaction_trace(&mut logup_trace_builder, component_trace, &range_check);
+        add_lookups(&mut logup_trace_builder, is_local_pad.clone());
+        Self::generate_interaction_trace(
+            &mut logup_trace_builder,
+            component_trace,
+            side_note,
+            &execution_lookup_elements,
+            is_local_pad,
+        );
+
+        logup_trace_builder.finalize()
+    }
+
+    /// Adds constraints of a component decoding its instruction with `D`: the decoding, clock and
+    /// pc increments, and the program and register memory lookups of the step, then finalizes the
+    /// logup.
+    ///
+    /// `add_constraints` adds the constraints and lookups specific to the component, its lookups
+    /// are placed as in [`Self::generate_execution_interaction_trace`].
+    fn constrain_execution<E, D>(
+        eval: &mut E,
+        trace_eval: &TraceEval<D::PreprocessedColumn, D::MainColumn, E>,
+        columns: &ExecutionColumns<D::MainColumn>,
+        (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check): (
+            &InstToProgMemoryLookupElements,
+            &ProgramExecutionLookupElements,
+            &InstToRegisterMemoryLookupElements,
+            &RangeCheckLookupElements,
+        ),
+        add_constraints: impl FnOnce(&mut E, &ExecutionEval<E::F>),
+    ) where
+        E: EvalAtRow,
+        D: InstructionDecoding,
+        D::PreprocessedColumn: PreprocessedAirColumn,
+        D::MainColumn: AirColumn,
+    {
+        let decoding_trace_eval = TraceEval::new(eval);
+
+        let [is_local_pad] = trace_eval.column_eval(columns.is_local_pad);
+        let a_val = trace_eval.column_eval(columns.a_val);
+        let b_val = trace_eval.column_eval(columns.b_val);
+        let c_val = D::combine_c_val(&decoding_trace_eval);
+        let step_counters = columns.step_counters.constrain(eval, trace_eval);
+
+        D::constrain_decoding(eval, trace_eval, &decoding_trace_eval, range_check);
+
+        let execution_eval = ExecutionEval {
+            is_local_pad,
+            a_val,
+            b_val,
+            c_val,
+            step_counters,
+        };
+        add_constraints(eval, &execution_eval);
+
+        let ExecutionEval {
+            is_local_pad,
+            a_val,
+            b_val,
+            c_val,
+            step_counters:
+                StepCountersEval {
+                    clk,
+                    clk_next,
+                    pc,
+                    pc_next,
+                },
+        } = execution_eval;
+        let c_val = if Self::REG2_ACCESSED {
+            c_val
+        } else {
+            zero_array::<WORD_SIZE, E>()
+        };
+        Self::constrain_logups(
+            eval,
+            (
+                rel_inst_to_prog_memory,
+                rel_cont_prog_exec,
+                rel_inst_to_reg_memory,
+            ),
+            ExecutionLookupEval {
+                is_local_pad,
+                reg_addrs: D::combine_reg_addresses(&decoding_trace_eval),
+                reg_values: [a_val, b_val, c_val],
+                instr_val: D::combine_instr_val(&decoding_trace_eval),
+                clk,
+                clk_next,
+                pc,
+                pc_next,
+            },
+        );
+
+        eval.finalize_logup_in_pairs();
+    }
+
     fn generate_interaction_trace(
         logup_trace_builder: &mut LogupTraceBuilder,
         component_trace: &ComponentTrace,
@@ -196,6 +446,41 @@ pub trait ExecutionComponent {
     }
 }
 
+/// Main trace columns shared by execution components that increment pc by the word size, see
+/// [`ExecutionComponent::constrain_execution`].
+pub struct ExecutionColumns<C> {
+    pub is_local_pad: C,
+    pub a_val: C,
+    pub b_val: C,
+    pub step_counters: StepCounters<C>,
+}
+
+impl<C: AirColumn> ExecutionColumns<C> {
+    /// Fills clock and program counter columns along with values of registers a and b.
+    pub fn fill(
+        &self,
+        trace: &mut TraceBuilder<C>,
+        row_idx: usize,
+        program_step: &ProgramStep,
+        a_val: &Word,
+        b_val: &Word,
+    ) {
+        self.step_counters.fill(trace, row_idx, program_step.step);
+        trace.fill_columns_bytes(row_idx, a_val, self.a_val);
+        trace.fill_columns_bytes(row_idx, b_val, self.b_val);
+    }
+}
+
+/// Evaluations of [`ExecutionColumns`] passed to the constraints specific to a component.
+pub struct ExecutionEval<F> {
+    pub is_local_pad: F,
+    pub a_val: [F; WORD_SIZE],
+    pub b_val: [F; WORD_SIZE],
+    /// Either an immediate or the value of the second source register.
+    pub c_val: [F; WORD_SIZE],
+    pub step_counters: StepCountersEval<F>,
+}
+
 /// Evaluations of columns used in execution components lookups
 pub struct ExecutionLookupEval<F> {
     pub is_local_pad: F,
@@ -9,13 +9,14 @@ use nexus_vm_prover_air_column::{
     empty::EmptyPreprocessedColumn, AirColumn, PreprocessedAirColumn,
 };
 use nexus_vm_prover_trace::{
-    builder::TraceBuilder, eval::TraceEval, program::ProgramStep, trace_eval,
+    builder::TraceBuilder, component::FinalizedColumn, eval::TraceEval, program::ProgramStep,
+    trace_eval,
 };
 
 use super::{InstructionDecoding, RegSplitAt0};
 use crate::{
     components::execution::common::ComponentTraceRef,
-    lookups::{RangeCheckLookupElements, RangeLookupBound},
+    lookups::{LogupTraceBuilder, RangeCheckLookupElements, RangeLookupBound},
     side_note::range_check::RangeCheckAccumulator,
 };
 
@@ -59,14 +60,93 @@ pub const OP_B: RegSplitAt0<DecodingColumn> = RegSplitAt0 {
     bits_1_4: DecodingColumn::OpB1_4,
 };
 
-pub struct CVal<C> {
+/// Sign-extended 12-bit immediate of type I instructions, split into op-c parts.
+pub struct ITypeImmediate<C> {
     pub op_c0_3: C,
     pub op_c4_7: C,
     pub op_c8_10: C,
     pub op_c11: C,
 }
 
-impl<C: AirColumn> CVal<C> {
+/// Immediate encoded as a linear combination of helper columns.
+pub const IMMEDIATE: ITypeImmediate<DecodingColumn> = ITypeImmediate {
+    op_c0_3: DecodingColumn::OpC0_3,
+    op_c4_7: DecodingColumn::OpC4_7,
+    op_c8_10: DecodingColumn::OpC8_10,
+    op_c11: DecodingColumn::OpC11,
+};
+
+impl<C: AirColumn> ITypeImmediate<C> {
+    /// Splits the raw immediate into parts and fills decoding columns.
+    pub fn fill_trace_row(
+        &self,
+        trace: &mut TraceBuilder<C>,
+        row_idx: usize,
+        op_c_raw: u32,
+        range_check_accum: &mut RangeCheckAccumulator,
+    ) {
+        let op_c0_3 = (op_c_raw & 0xF) as u8;
+        let op_c4_7 = ((op_c_raw >> 4) & 0xF) as u8;
+        let op_c8_10 = ((op_c_raw >> 8) & 0x7) as u8;
+        let op_c11 = ((op_c_raw >> 11) & 0x1) as u8;
+        trace.fill_columns(row_idx, op_c0_3, self.op_c0_3);
+        trace.fill_columns(row_idx, op_c4_7, self.op_c4_7);
+        trace.fill_columns(row_idx, op_c8_10, self.op_c8_10);
+        trace.fill_columns(row_idx, op_c11, self.op_c11);
+
+        range_check_accum
+            .range16
+            .add_values_from_slice(&[op_c0_3, op_c4_7]);
+        range_check_accum.range8.add_value(op_c8_10);
+    }
+
+    /// Constrains op-c11 to be a bit and range checks the remaining parts.
+    pub fn constrain<E: EvalAtRow, P: PreprocessedAirColumn>(
+        &self,
+        eval: &mut E,
+        trace_eval: &TraceEval<P, C, E>,
+        is_local_pad: E::F,
+        range_check: &RangeCheckLookupElements,
+    ) {
+        let [op_c0_3] = trace_eval.column_eval(self.op_c0_3);
+        let [op_c4_7] = trace_eval.column_eval(self.op_c4_7);
+        let [op_c8_10] = trace_eval.column_eval(self.op_c8_10);
+        let [op_c11] = trace_eval.column_eval(self.op_c11);
+
+        // constrain op_c11 ∈ {0, 1}
+        eval.add_constraint(op_c11.clone() * (E::F::one() - op_c11));
+
+        for col in [op_c0_3, op_c4_7] {
+            range_check
+                .range16
+                .constrain(eval, is_local_pad.clone(), col);
+        }
+        range_check.range8.constrain(eval, is_local_pad, op_c8_10);
+    }
+
+    /// Adds range checks of the immediate parts to the interaction trace, in the same order as [`Self::constrain`].
+    pub fn generate_interaction_trace<'a>(
+        &self,
+        logup_trace_builder: &mut LogupTraceBuilder,
+        is_local_pad: FinalizedColumn<'a>,
+        base_column: impl Fn(C) -> FinalizedColumn<'a>,
+        range_check: &RangeCheckLookupElements,
+    ) {
+        for col in [self.op_c0_3, self.op_c4_7] {
+            range_check.range16.generate_logup_col(
+                logup_trace_builder,
+                is_local_pad.clone(),
+                base_column(col),
+            );
+        }
+        range_check.range8.generate_logup_col(
+            logup_trace_builder,
+            is_local_pad,
+            base_column(self.op_c8_10),
+        );
+    }
+
+    /// Returns sign-extended immediate bytes.
     pub fn eval<E: EvalAtRow, P: PreprocessedAirColumn>(
         &self,
         trace_eval: &TraceEval<P, C, E>,
@@ -170,20 +250,16 @@ pub fn generate_trace_row(
     trace.fill_columns(row_idx, op_b0, DecodingColumn::OpB0);
     trace.fill_columns(row_idx, op_b1_4, DecodingColumn::OpB1_4);
 
-    let op_c_raw = program_step.step.instruction.op_c;
-    let op_c0_3 = (op_c_raw & 0xF) as u8;
-    let op_c4_7 = ((op_c_raw >> 4) & 0xF) as u8;
-    let op_c8_10 = ((op_c_raw >> 8) & 0x7) as u8;
-    let op_c11 = ((op_c_raw >> 11) & 0x1) as u8;
-    trace.fill_columns(row_idx, op_c0_3, DecodingColumn::OpC0_3);
-    trace.fill_columns(row_idx, op_c4_7, DecodingColumn::OpC4_7);
-    trace.fill_columns(row_idx, op_c8_10, DecodingColumn::OpC8_10);
-    trace.fill_columns(row_idx, op_c11, DecodingColumn::OpC11);
-
     range_check_accum
         .range16
-        .add_values_from_slice(&[op_a1_4, op_b1_4, op_c0_3, op_c4_7]);
-    range_check_accum.range8.add_value(op_c8_10);
+        .add_values_from_slice(&[op_a1_4, op_b1_4]);
+
+    IMMEDIATE.fill_trace_row(
+        trace,
+        row_idx,
+        program_step.step.instruction.op_c,
+        range_check_accum,
+    );
 }
 
 /// Zero-sized struct that implements type-I instruction decoding.
@@ -224,30 +300,25 @@ impl<T: TypeIDecoding> InstructionDecoding for TypeI<T> {
 
         let [op_a0] = trace_eval!(decoding_trace_eval, DecodingColumn::OpA0);
         let [op_b0] = trace_eval!(decoding_trace_eval, DecodingColumn::OpB0);
-        let [op_c11] = trace_eval!(decoding_trace_eval, DecodingColumn::OpC11);
 
-        // constrain op_a0, op_b0, op_c11 ∈ {0, 1}
-        for bit in [op_a0, op_b0, op_c11.clone()] {
+        // constrain op_a0, op_b0 ∈ {0, 1}
+        for bit in [op_a0, op_b0] {
             eval.add_constraint(bit.clone() * (E::F::one() - bit));
         }
 
         let [op_a1_4] = trace_eval!(decoding_trace_eval, DecodingColumn::OpA1_4);
         let [op_b1_4] = trace_eval!(decoding_trace_eval, DecodingColumn::OpB1_4);
-        let [op_c0_3] = trace_eval!(decoding_trace_eval, DecodingColumn::OpC0_3);
-        let [op_c4_7] = trace_eval!(decoding_trace_eval, DecodingColumn::OpC4_7);
 
-        let [op_c8_10] = trace_eval!(decoding_trace_eval, DecodingColumn::OpC8_10);
-
-        for col in [op_a1_4, op_b1_4, op_c0_3, op_c4_7] {
+        for col in [op_a1_4, op_b1_4] {
             range_check
                 .range16
                 .constrain(eval, is_local_pad.clone(), col);
         }
-        range_check.range8.constrain(eval, is_local_pad, op_c8_10);
+        IMMEDIATE.constrain(eval, decoding_trace_eval, is_local_pad, range_check);
     }
 
     fn generate_interaction_trace(
-        logup_trace_builder: &mut crate::lookups::LogupTraceBuilder,
+        logup_trace_builder: &mut LogupTraceBuilder,
         component_trace: &nexus_vm_prover_trace::component::ComponentTrace,
         range_check: &RangeCheckLookupElements,
     ) {
@@ -257,19 +328,21 @@ impl<T: TypeIDecoding> InstructionDecoding for TypeI<T> {
 
         let [op_a1_4] = decoding_trace_ref.base_column(DecodingColumn::OpA1_4);
         let [op_b1_4] = decoding_trace_ref.base_column(DecodingColumn::OpB1_4);
-        let [op_c0_3] = decoding_trace_ref.base_column(DecodingColumn::OpC0_3);
-        let [op_c4_7] = decoding_trace_ref.base_column(DecodingColumn::OpC4_7);
-
-        let [op_c8_10] = decoding_trace_ref.base_column(DecodingColumn::OpC8_10);
 
-        for col in [op_a1_4, op_b1_4, op_c0_3, op_c4_7] {
+        for col in [op_a1_4, op_b1_4] {
             range_check
                 .range16
                 .generate_logup_col(logup_trace_builder, is_local_pad.clone(), col);
         }
-        range_check
-            .range8
-            .generate_logup_col(logup_trace_builder, is_local_pad, op_c8_10);
+        IMMEDIATE.generate_interaction_trace(
+            logup_trace_builder,
+            is_local_pad,
+            |col| {
+                let [col] = decoding_trace_ref.base_column(col);
+                col
+            },
+            range_check,
+        );
     }
 
     fn combine_reg_addresses<E: EvalAtRow>(
@@ -289,12 +362,76 @@ impl<T: TypeIDecoding> InstructionDecoding for TypeI<T> {
     fn combine_c_val<E: EvalAtRow>(
         decoding_trace_eval: &TraceEval<EmptyPreprocessedColumn, Self::DecodingColumn, E>,
     ) -> [E::F; WORD_SIZE] {
-        CVal {
-            op_c0_3: DecodingColumn::OpC0_3,
-            op_c4_7: DecodingColumn::OpC4_7,
-            op_c8_10: DecodingColumn::OpC8_10,
-            op_c11: DecodingColumn::OpC11,
+        IMMEDIATE.eval(decoding_trace_eval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::Zero;
+    use stwo::core::fields::qm31::SecureField;
+
+    use crate::{
+        components::{
+            BitwiseMultiplicity, Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary,
+            RegisterMemory, RegisterMemoryBoundary, ADDI, ANDI, RANGE16, RANGE256, RANGE64, RANGE8,
+        },
+        framework::test_utils::{assert_component, components_claimed_sum, AssertContext},
+    };
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    #[test]
+    fn immediate_shared_across_components() {
+        let mut instr = vec![Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADDI),
+            1,
+            0,
+            0x5A5,
+        )];
+        // positive, negative and zero immediates
+        for imm in [0x123, 0xFFB, 0] {
+            instr.push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                2,
+                1,
+                imm,
+            ));
+            instr.push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ANDI),
+                3,
+                1,
+                imm,
+            ));
         }
-        .eval(decoding_trace_eval)
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = SecureField::zero();
+
+        claimed_sum += assert_component(ADDI, assert_ctx);
+        claimed_sum += assert_component(ANDI, assert_ctx);
+
+        claimed_sum += components_claimed_sum(
+            &[
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &RANGE8,
+                &RANGE16,
+                &RANGE64,
+                &RANGE256,
+            ],
+            assert_ctx,
+        );
+        claimed_sum += assert_component(BitwiseMultiplicity, assert_ctx);
+
+        assert!(claimed_sum.is_zero());
     }
 }
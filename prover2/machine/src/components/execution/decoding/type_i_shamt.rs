@@ -89,6 +89,9 @@ pub struct InstrVal<C> {
     pub op_c0_3: C,
 
     /// Byte 3: op_c4 + imm[11:5] * 2
+    ///
+    /// The reserved bits imm[11:5] are not committed and are fixed to `funct7`, instructions with
+    /// non-zero reserved bits (or a different funct7 for SRAI) don't match the program memory word.
     pub op_c4: C,
     pub funct7: u8,
 }
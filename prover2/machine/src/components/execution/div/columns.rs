@@ -0,0 +1,78 @@
+use nexus_vm_prover_air_column::{empty::EmptyPreprocessedColumn, AirColumn};
+
+pub type PreprocessedColumn = EmptyPreprocessedColumn;
+
+#[derive(Debug, Copy, Clone, AirColumn)]
+pub enum Column {
+    /// The current execution time represented by two 16-bit limbs
+    #[size = 2]
+    Clk,
+    /// The helper bit to compute the next clock value
+    #[size = 1]
+    ClkCarry,
+    /// A 32-bit word specifying the value of operand op-a represented by four 8-bit limbs
+    #[size = 4]
+    AVal,
+    /// A 32-bit word specifying the value of operand op-b represented by four 8-bit limbs
+    #[size = 4]
+    BVal,
+    /// The current value of the program counter register
+    #[size = 2]
+    Pc,
+    /// The helper bits to compute the program counter update
+    #[size = 1]
+    PcCarry,
+    /// Binary value to indicate if the row is a padding row
+    #[size = 1]
+    IsLocalPad,
+
+    // helper variables used for constraining division
+    /// Quotient of b-val and c-val represented by four 8-bit limbs
+    #[size = 4]
+    Quotient,
+    /// Remainder of b-val and c-val represented by four 8-bit limbs
+    #[size = 4]
+    Remainder,
+    /// Binary value to indicate division by zero
+    #[size = 1]
+    IsDivByZero,
+    /// Binary value to indicate signed division of the minimal integer by -1
+    #[size = 1]
+    IsOverflow,
+    /// Sign bit of b-val
+    #[size = 1]
+    BSign,
+    /// Lower 7 bits of the most significant byte of b-val
+    #[size = 1]
+    BLow,
+    /// Sign bit of c-val
+    #[size = 1]
+    CSign,
+    /// Lower 7 bits of the most significant byte of c-val
+    #[size = 1]
+    CLow,
+    /// Sign bit of the quotient
+    #[size = 1]
+    QSign,
+    /// Lower 7 bits of the most significant byte of the quotient
+    #[size = 1]
+    QLow,
+    /// Sign bit of the remainder
+    #[size = 1]
+    RSign,
+    /// Lower 7 bits of the most significant byte of the remainder
+    #[size = 1]
+    RLow,
+    /// Lower 8 bits of the carries of quotient · c-val + remainder − b-val
+    #[size = 8]
+    ProdCarry,
+    /// Upper bits of the carries of quotient · c-val + remainder − b-val
+    #[size = 8]
+    ProdCarryHigh,
+    /// |c-val| − |remainder| − 1 represented by four 8-bit limbs
+    #[size = 4]
+    Diff,
+    /// Carry of the lower half of the difference, shifted to be non-negative
+    #[size = 1]
+    DiffCarry,
+}
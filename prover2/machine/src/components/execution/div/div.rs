@@ -0,0 +1,19 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, DivOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct DivDecoding;
+impl TypeRDecoding for DivDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::DIV;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Div = TypeR<DivDecoding>;
+impl DivOp for Div {
+    const SIGNED: bool = true;
+    const REM: bool = false;
+}
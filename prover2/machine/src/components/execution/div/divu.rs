@@ -0,0 +1,19 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, DivOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct DivuDecoding;
+impl TypeRDecoding for DivuDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::DIVU;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Divu = TypeR<DivuDecoding>;
+impl DivOp for Divu {
+    const SIGNED: bool = false;
+    const REM: bool = false;
+}
@@ -0,0 +1,786 @@
+use std::marker::PhantomData;
+
+use num_traits::{One, Zero};
+use stwo::{
+    core::{
+        fields::{m31::BaseField, qm31::SecureField},
+        ColumnVec,
+    },
+    prover::{
+        backend::simd::SimdBackend,
+        poly::{circle::CircleEvaluation, BitReversedOrder},
+    },
+};
+use stwo_constraint_framework::EvalAtRow;
+
+use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
+use nexus_vm_prover_trace::{
+    builder::{FinalizedTrace, TraceBuilder},
+    component::ComponentTrace,
+    eval::TraceEval,
+    original_base_column,
+    program::{ProgramStep, Word},
+    trace_eval,
+};
+
+use crate::{
+    components::{
+        execution::{
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
+            decoding::InstructionDecoding,
+        },
+        utils::constraints::{StepCounters, StepCountersEval},
+    },
+    framework::BuiltInComponent,
+    lookups::{
+        AllLookupElements, ComponentLookupElements, InstToProgMemoryLookupElements,
+        InstToRegisterMemoryLookupElements, LogupTraceBuilder, ProgramExecutionLookupElements,
+        RangeCheckLookupElements, RangeLookupBound,
+    },
+    side_note::{program::ProgramTraceRef, range_check::RangeCheckAccumulator, SideNote},
+};
+
+mod columns;
+mod div;
+mod divu;
+mod rem;
+mod remu;
+
+use columns::{Column, PreprocessedColumn};
+
+const STEP_COUNTERS: StepCounters<Column> = StepCounters {
+    clk: Column::Clk,
+    clk_carry: Column::ClkCarry,
+    pc: Column::Pc,
+    pc_carry: Column::PcCarry,
+};
+
+pub const DIV: Div<div::Div> = Div::new();
+pub const DIVU: Div<divu::Divu> = Div::new();
+pub const REM: Div<rem::Rem> = Div::new();
+pub const REMU: Div<remu::Remu> = Div::new();
+
+/// Number of limbs in the sign-extended 64-bit operands.
+const PROD_SIZE: usize = 2 * WORD_SIZE;
+/// Offset keeping the carry of the lower half of the remainder bound non-negative.
+const DIFF_CARRY_OFFSET: i64 = 3;
+
+pub trait DivOp:
+    InstructionDecoding<PreprocessedColumn = PreprocessedColumn, MainColumn = Column>
+{
+    /// Whether operands are interpreted as signed integers.
+    const SIGNED: bool;
+    /// Whether the result is the remainder rather than the quotient.
+    const REM: bool;
+}
+
+/// Division component, proves `b-val = quotient · c-val + remainder` with `|remainder| < |c-val|`.
+///
+/// Operands are sign-extended to 64 bits and the identity is checked modulo 2^64 byte by byte,
+/// the sign of the remainder follows the sign of b-val for signed variants.
+///
+/// Division by zero and signed overflow (`i32::MIN / -1`) are flagged with boolean columns: the
+/// former skips the remainder bound and forces an all-ones quotient, the latter treats the
+/// quotient `2^31` as unsigned so that the identity holds.
+pub struct Div<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: DivOp> ExecutionComponent for Div<T> {
+    const OPCODE: BuiltinOpcode = <T as InstructionDecoding>::OPCODE;
+
+    const REG1_ACCESSED: bool = true;
+    const REG2_ACCESSED: bool = true;
+    const REG3_ACCESSED: bool = true;
+    const REG3_WRITE: bool = true;
+}
+
+struct ExecutionResult {
+    quotient: Word,
+    remainder: Word,
+    is_div_by_zero: bool,
+    is_overflow: bool,
+    /// Carry out of each limb of quotient · c-val + remainder − b-val, fits into 11 bits.
+    prod_carry: [u16; PROD_SIZE],
+    /// |c-val| − |remainder| − 1, zero on division by zero.
+    diff: Word,
+    diff_carry: u8,
+}
+
+impl<T: DivOp> Div<T> {
+    const fn new() -> Self {
+        assert!(matches!(
+            T::OPCODE,
+            BuiltinOpcode::DIV | BuiltinOpcode::DIVU | BuiltinOpcode::REM | BuiltinOpcode::REMU
+        ));
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+
+    fn execute_step(value_b: Word, value_c: Word) -> ExecutionResult {
+        let b = u32::from_le_bytes(value_b);
+        let c = u32::from_le_bytes(value_c);
+
+        let (quotient, remainder, is_div_by_zero, is_overflow) = if c == 0 {
+            (u32::MAX, b, true, false)
+        } else if T::SIGNED && b == i32::MIN as u32 && c == u32::MAX {
+            (b, 0, false, true)
+        } else if T::SIGNED {
+            let (b, c) = (b as i32, c as i32);
+            ((b / c) as u32, (b % c) as u32, false, false)
+        } else {
+            (b / c, b % c, false, false)
+        };
+
+        // signs are only taken into account by signed variants
+        let sign = |x: u32| i64::from(T::SIGNED && x >> 31 == 1);
+        let sb = sign(b);
+        let sc = sign(c);
+        let sq = sign(quotient) - i64::from(is_overflow);
+        let sr = sign(remainder);
+
+        let extend = |x: u32, s: i64| -> [i64; PROD_SIZE] {
+            let bytes = x.to_le_bytes();
+            std::array::from_fn(|k| {
+                if k < WORD_SIZE {
+                    i64::from(bytes[k])
+                } else {
+                    255 * s
+                }
+            })
+        };
+        let b_ext = extend(b, sb);
+        let c_ext = extend(c, sc);
+        let q_ext = extend(quotient, sq);
+        let r_ext = extend(remainder, sr);
+
+        let mut prod_carry = [0u16; PROD_SIZE];
+        let mut carry = 0i64;
+        for k in 0..PROD_SIZE {
+            let mut sum = carry + r_ext[k] - b_ext[k];
+            for i in 0..=k {
+                sum += q_ext[i] * c_ext[k - i];
+            }
+            assert!(sum >= 0 && sum % 256 == 0, "invalid division result");
+            carry = sum >> 8;
+            prod_carry[k] = carry as u16;
+        }
+
+        let (diff, diff_carry) = if is_div_by_zero {
+            (0, 0)
+        } else {
+            let abs = |x: u32, s: i64| i64::from(if s == 1 { x.wrapping_neg() } else { x });
+            let diff = abs(c, sc) - abs(remainder, sr) - 1;
+            assert!(diff >= 0, "remainder must be smaller than divisor");
+
+            let lo = |x: u32| i64::from(x & 0xFFFF);
+            let low = (1 - 2 * sc) * lo(c) - (1 - 2 * sr) * lo(remainder) - 1 - lo(diff as u32);
+            (diff as u32, ((low >> 16) + DIFF_CARRY_OFFSET) as u8)
+        };
+
+        ExecutionResult {
+            quotient: quotient.to_le_bytes(),
+            remainder: remainder.to_le_bytes(),
+            is_div_by_zero,
+            is_overflow,
+            prod_carry,
+            diff: diff.to_le_bytes(),
+            diff_carry,
+        }
+    }
+
+    fn generate_trace_row(
+        &self,
+        trace: &mut TraceBuilder<Column>,
+        row_idx: usize,
+        program_step: ProgramStep,
+        range_check_accum: &mut RangeCheckAccumulator,
+    ) {
+        let step = &program_step.step;
+
+        let value_b = program_step.get_value_b();
+        let (value_c, _) = program_step.get_value_c();
+        let ExecutionResult {
+            quotient,
+            remainder,
+            is_div_by_zero,
+            is_overflow,
+            prod_carry,
+            diff,
+            diff_carry,
+        } = Self::execute_step(value_b, value_c);
+        let value_a = if T::REM { remainder } else { quotient };
+        assert_eq!(program_step.get_result(), Some(value_a), "{:?}", T::OPCODE);
+
+        let prod_carry_low = prod_carry.map(|carry| (carry & 0xFF) as u8);
+        let prod_carry_high = prod_carry.map(|carry| (carry >> 8) as u8);
+        let [b_sign, c_sign, q_sign, r_sign] =
+            [value_b, value_c, quotient, remainder].map(|word| word[WORD_SIZE - 1] >> 7);
+        let [b_low, c_low, q_low, r_low] =
+            [value_b, value_c, quotient, remainder].map(|word| word[WORD_SIZE - 1] & 0x7F);
+
+        STEP_COUNTERS.fill(trace, row_idx, step);
+
+        trace.fill_columns_bytes(row_idx, &value_b, Column::BVal);
+        trace.fill_columns_bytes(row_idx, &value_a, Column::AVal);
+
+        trace.fill_columns(row_idx, quotient, Column::Quotient);
+        trace.fill_columns(row_idx, remainder, Column::Remainder);
+        trace.fill_columns(row_idx, is_div_by_zero, Column::IsDivByZero);
+        trace.fill_columns(row_idx, is_overflow, Column::IsOverflow);
+        trace.fill_columns(row_idx, b_sign, Column::BSign);
+        trace.fill_columns(row_idx, b_low, Column::BLow);
+        trace.fill_columns(row_idx, c_sign, Column::CSign);
+        trace.fill_columns(row_idx, c_low, Column::CLow);
+        trace.fill_columns(row_idx, q_sign, Column::QSign);
+        trace.fill_columns(row_idx, q_low, Column::QLow);
+        trace.fill_columns(row_idx, r_sign, Column::RSign);
+        trace.fill_columns(row_idx, r_low, Column::RLow);
+        trace.fill_columns(row_idx, prod_carry_low, Column::ProdCarry);
+        trace.fill_columns(row_idx, prod_carry_high, Column::ProdCarryHigh);
+        trace.fill_columns(row_idx, diff, Column::Diff);
+        trace.fill_columns(row_idx, diff_carry, Column::DiffCarry);
+
+        range_check_accum.range256.add_values(&quotient);
+        range_check_accum.range256.add_values(&remainder);
+        range_check_accum.range256.add_values(&prod_carry_low);
+        range_check_accum.range256.add_values(&diff);
+        for value in prod_carry_high.into_iter().chain([diff_carry]) {
+            range_check_accum.range8.add_value(value);
+        }
+        for value in [b_low, c_low, q_low, r_low] {
+            range_check_accum.range128.add_value(value);
+        }
+    }
+}
+
+impl<T: DivOp> BuiltInComponent for Div<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
+    const LOG_CONSTRAINT_DEGREE_BOUND: u32 = 2;
+
+    type PreprocessedColumn = PreprocessedColumn;
+
+    type MainColumn = Column;
+
+    type LookupElements = (
+        InstToProgMemoryLookupElements,
+        ProgramExecutionLookupElements,
+        InstToRegisterMemoryLookupElements,
+        RangeCheckLookupElements,
+    );
+
+    fn generate_preprocessed_trace(
+        &self,
+        _log_size: u32,
+        _program: &ProgramTraceRef,
+    ) -> FinalizedTrace {
+        FinalizedTrace::empty()
+    }
+
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
+    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
+        <Self as ExecutionComponent>::generate_main_trace_with(
+            side_note,
+            Column::IsLocalPad,
+            |common_trace, local_trace, row_idx, program_step, range_check_accum| {
+                self.generate_trace_row(common_trace, row_idx, program_step, range_check_accum);
+                T::generate_trace_row(row_idx, local_trace, program_step, range_check_accum);
+            },
+        )
+    }
+
+    fn generate_interaction_trace(
+        &self,
+        component_trace: ComponentTrace,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> (
+        ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        SecureField,
+    ) {
+        let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
+            Self::LookupElements::get(lookup_elements);
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
+
+        let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
+        let quotient = original_base_column!(component_trace, Column::Quotient);
+        let remainder = original_base_column!(component_trace, Column::Remainder);
+        let prod_carry = original_base_column!(component_trace, Column::ProdCarry);
+        let prod_carry_high = original_base_column!(component_trace, Column::ProdCarryHigh);
+        let diff = original_base_column!(component_trace, Column::Diff);
+        let [diff_carry] = original_base_column!(component_trace, Column::DiffCarry);
+        let [b_low] = original_base_column!(component_trace, Column::BLow);
+        let [c_low] = original_base_column!(component_trace, Column::CLow);
+        let [q_low] = original_base_column!(component_trace, Column::QLow);
+        let [r_low] = original_base_column!(component_trace, Column::RLow);
+
+        for bytes in [&quotient[..], &remainder, &prod_carry, &diff] {
+            range_check.range256.generate_logup_col(
+                &mut logup_trace_builder,
+                is_local_pad.clone(),
+                bytes,
+            );
+        }
+        for value in prod_carry_high.into_iter().chain([diff_carry]) {
+            range_check.range8.generate_logup_col(
+                &mut logup_trace_builder,
+                is_local_pad.clone(),
+                value,
+            );
+        }
+        for low in [b_low, c_low, q_low, r_low] {
+            range_check.range128.generate_logup_col(
+                &mut logup_trace_builder,
+                is_local_pad.clone(),
+                low,
+            );
+        }
+
+        let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
+        <T as InstructionDecoding>::generate_interaction_trace(
+            &mut logup_trace_builder,
+            &component_trace,
+            &range_check,
+        );
+        <Self as ExecutionComponent>::generate_interaction_trace(
+            &mut logup_trace_builder,
+            &component_trace,
+            side_note,
+            &(
+                rel_inst_to_prog_memory,
+                rel_cont_prog_exec,
+                rel_inst_to_reg_memory,
+            ),
+            is_local_pad,
+        );
+        logup_trace_builder.finalize()
+    }
+
+    fn add_constraints<E: EvalAtRow>(
+        &self,
+        eval: &mut E,
+        trace_eval: TraceEval<Self::PreprocessedColumn, Self::MainColumn, E>,
+        lookup_elements: &Self::LookupElements,
+    ) {
+        let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
+            lookup_elements;
+        let local_trace_eval = TraceEval::new(eval);
+
+        let [is_local_pad] = trace_eval!(trace_eval, Column::IsLocalPad);
+
+        let a_val = trace_eval!(trace_eval, Column::AVal);
+        let b_val = trace_eval!(trace_eval, Column::BVal);
+        let c_val = T::combine_c_val(&local_trace_eval);
+
+        let StepCountersEval {
+            clk,
+            clk_next,
+            pc,
+            pc_next,
+        } = STEP_COUNTERS.constrain(eval, &trace_eval);
+
+        let quotient = trace_eval!(trace_eval, Column::Quotient);
+        let remainder = trace_eval!(trace_eval, Column::Remainder);
+        let [is_div_by_zero] = trace_eval!(trace_eval, Column::IsDivByZero);
+        let [is_overflow] = trace_eval!(trace_eval, Column::IsOverflow);
+        let [b_sign] = trace_eval!(trace_eval, Column::BSign);
+        let [b_low] = trace_eval!(trace_eval, Column::BLow);
+        let [c_sign] = trace_eval!(trace_eval, Column::CSign);
+        let [c_low] = trace_eval!(trace_eval, Column::CLow);
+        let [q_sign] = trace_eval!(trace_eval, Column::QSign);
+        let [q_low] = trace_eval!(trace_eval, Column::QLow);
+        let [r_sign] = trace_eval!(trace_eval, Column::RSign);
+        let [r_low] = trace_eval!(trace_eval, Column::RLow);
+        let prod_carry_low = trace_eval!(trace_eval, Column::ProdCarry);
+        let prod_carry_high = trace_eval!(trace_eval, Column::ProdCarryHigh);
+        let diff = trace_eval!(trace_eval, Column::Diff);
+        let [diff_carry] = trace_eval!(trace_eval, Column::DiffCarry);
+
+        // (1 − is-local-pad) · (low + sgn · 2^7 − word(4)) = 0
+        // (sgn) · (1 − sgn) = 0
+        //
+        // for each of b-val, c-val, quotient and remainder
+        for (low, sign, byte) in [
+            (&b_low, &b_sign, &b_val[WORD_SIZE - 1]),
+            (&c_low, &c_sign, &c_val[WORD_SIZE - 1]),
+            (&q_low, &q_sign, &quotient[WORD_SIZE - 1]),
+            (&r_low, &r_sign, &remainder[WORD_SIZE - 1]),
+        ] {
+            eval.add_constraint(
+                (E::F::one() - is_local_pad.clone())
+                    * (low.clone() + sign.clone() * BaseField::from(1 << 7) - byte.clone()),
+            );
+            eval.add_constraint(sign.clone() * (E::F::one() - sign.clone()));
+        }
+
+        // (is-div-by-zero) · (1 − is-div-by-zero) = 0
+        // (is-div-by-zero) · (c-val(1) + c-val(2) + c-val(3) + c-val(4)) = 0
+        // (is-div-by-zero) · (quotient(i) − 0xFF) = 0              for i = 1, 2, 3, 4
+        //
+        // A zero divisor can't be left unflagged, the remainder bound has no solution for it.
+        eval.add_constraint(is_div_by_zero.clone() * (E::F::one() - is_div_by_zero.clone()));
+        eval.add_constraint(
+            is_div_by_zero.clone()
+                * c_val
+                    .iter()
+                    .cloned()
+                    .fold(E::F::zero(), |acc, byte| acc + byte),
+        );
+        for byte in &quotient {
+            eval.add_constraint(
+                is_div_by_zero.clone() * (byte.clone() - E::F::from(BaseField::from(0xFF))),
+            );
+        }
+
+        let (sb, sc, sq, sr) = if T::SIGNED {
+            // (is-overflow) · (1 − is-overflow) = 0
+            // (is-overflow) · (b-val(i) − [0, 0, 0, 0x80](i)) = 0   for i = 1, 2, 3, 4
+            // (is-overflow) · (c-val(i) − 0xFF) = 0                  for i = 1, 2, 3, 4
+            //
+            // The overflow pattern can't be left unflagged either: the quotient 2^31 isn't
+            // representable as a signed word, which leaves the product identity without solutions.
+            eval.add_constraint(is_overflow.clone() * (E::F::one() - is_overflow.clone()));
+            for (i, (b, c)) in b_val.iter().zip(&c_val).enumerate() {
+                let min_byte = if i == WORD_SIZE - 1 { 0x80 } else { 0 };
+                eval.add_constraint(
+                    is_overflow.clone() * (b.clone() - E::F::from(BaseField::from(min_byte))),
+                );
+                eval.add_constraint(
+                    is_overflow.clone() * (c.clone() - E::F::from(BaseField::from(0xFF))),
+                );
+            }
+
+            // the remainder is either zero or has the sign of b-val
+            //
+            // (r-sgn) · (1 − b-sgn) = 0
+            // (b-sgn) · (1 − r-sgn) · (rem(1) + rem(2) + rem(3) + rem(4)) = 0
+            eval.add_constraint(r_sign.clone() * (E::F::one() - b_sign.clone()));
+            eval.add_constraint(
+                b_sign.clone()
+                    * (E::F::one() - r_sign.clone())
+                    * remainder
+                        .iter()
+                        .cloned()
+                        .fold(E::F::zero(), |acc, byte| acc + byte),
+            );
+
+            // on overflow the quotient 2^31 is extended with zeros
+            (b_sign, c_sign, q_sign - is_overflow, r_sign)
+        } else {
+            // (is-overflow) = 0
+            eval.add_constraint(is_overflow);
+            (E::F::zero(), E::F::zero(), E::F::zero(), E::F::zero())
+        };
+
+        let extend = |word: &[E::F], sign: &E::F| -> [E::F; PROD_SIZE] {
+            std::array::from_fn(|k| {
+                if k < WORD_SIZE {
+                    word[k].clone()
+                } else {
+                    sign.clone() * BaseField::from(0xFF)
+                }
+            })
+        };
+        let b_ext = extend(&b_val, &sb);
+        let c_ext = extend(&c_val, &sc);
+        let q_ext = extend(&quotient, &sq);
+        let r_ext = extend(&remainder, &sr);
+        let prod_carry: [E::F; PROD_SIZE] = std::array::from_fn(|k| {
+            prod_carry_low[k].clone() + prod_carry_high[k].clone() * BaseField::from(1 << 8)
+        });
+
+        // quotient · c-val + remainder ≡ b-val (mod 2^64), one sign-extended limb at a time
+        //
+        // (1 − is-local-pad) · (
+        //     Σ_{i + j = k} q-ext(i) · c-ext(j) + r-ext(k) + prod-carry(k − 1)
+        //     − b-ext(k) − prod-carry(k) · 2^8
+        // ) = 0                                              for k = 1, …, 8, prod-carry(0) = 0
+        for k in 0..PROD_SIZE {
+            let mut sum = r_ext[k].clone() - b_ext[k].clone();
+            for i in 0..=k {
+                sum += q_ext[i].clone() * c_ext[k - i].clone();
+            }
+            if k > 0 {
+                sum += prod_carry[k - 1].clone();
+            }
+            eval.add_constraint(
+                (E::F::one() - is_local_pad.clone())
+                    * (sum - prod_carry[k].clone() * BaseField::from(1 << 8)),
+            );
+        }
+
+        // |c-val| − |remainder| − 1 = diff, checked in 16-bit halves, where |x| = (1 − 2 · sgn) · x
+        // modulo 2^32 and the signed carry of the lower half is offset by 3
+        //
+        // (1 − is-local-pad) · (1 − is-div-by-zero) · (
+        //     (1 − 2 · sc) · (c-val(1) + c-val(2) · 2^8) − (1 − 2 · sr) · (rem(1) + rem(2) · 2^8)
+        //     − 1 − diff(1) − diff(2) · 2^8 − (diff-carry − 3) · 2^16
+        // ) = 0
+        // (1 − is-local-pad) · (1 − is-div-by-zero) · (
+        //     (1 − 2 · sc) · (c-val(3) + c-val(4) · 2^8) − (1 − 2 · sr) · (rem(3) + rem(4) · 2^8)
+        //     + (sc − sr) · 2^16 + diff-carry − 3 − diff(3) − diff(4) · 2^8
+        // ) = 0
+        let half = |word: &[E::F], i: usize| {
+            word[2 * i].clone() + word[2 * i + 1].clone() * BaseField::from(1 << 8)
+        };
+        let c_factor = E::F::one() - sc.clone() * BaseField::from(2);
+        let r_factor = E::F::one() - sr.clone() * BaseField::from(2);
+        let signed_diff_carry =
+            diff_carry.clone() - E::F::from(BaseField::from(DIFF_CARRY_OFFSET as u32));
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (E::F::one() - is_div_by_zero.clone())
+                * (c_factor.clone() * half(&c_val, 0)
+                    - r_factor.clone() * half(&remainder, 0)
+                    - E::F::one()
+                    - half(&diff, 0)
+                    - signed_diff_carry.clone() * BaseField::from(1 << 16)),
+        );
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (E::F::one() - is_div_by_zero.clone())
+                * (c_factor * half(&c_val, 1) - r_factor * half(&remainder, 1)
+                    + (sc - sr) * BaseField::from(1 << 16)
+                    + signed_diff_carry
+                    - half(&diff, 1)),
+        );
+
+        // (1 − is-local-pad) · (a-val(i) − result(i)) = 0          for i = 1, 2, 3, 4
+        let result = if T::REM { &remainder } else { &quotient };
+        for (a, res) in a_val.iter().zip(result) {
+            eval.add_constraint((E::F::one() - is_local_pad.clone()) * (a.clone() - res.clone()));
+        }
+
+        // range checks
+        for bytes in [&quotient[..], &remainder, &prod_carry_low, &diff] {
+            range_check
+                .range256
+                .constrain(eval, is_local_pad.clone(), bytes);
+        }
+        for value in prod_carry_high.into_iter().chain([diff_carry]) {
+            range_check
+                .range8
+                .constrain(eval, is_local_pad.clone(), value);
+        }
+        for low in [b_low, c_low, q_low, r_low] {
+            range_check
+                .range128
+                .constrain(eval, is_local_pad.clone(), low);
+        }
+
+        T::constrain_decoding(eval, &trace_eval, &local_trace_eval, range_check);
+
+        // Logup Interactions
+        let instr_val = T::combine_instr_val(&local_trace_eval);
+        let reg_addrs = T::combine_reg_addresses(&local_trace_eval);
+
+        <Self as ExecutionComponent>::constrain_logups(
+            eval,
+            (
+                rel_inst_to_prog_memory,
+                rel_cont_prog_exec,
+                rel_inst_to_reg_memory,
+            ),
+            ExecutionLookupEval {
+                is_local_pad,
+                reg_addrs,
+                reg_values: [a_val, b_val, c_val],
+                instr_val,
+                clk,
+                clk_next,
+                pc,
+                pc_next,
+            },
+        );
+
+        eval.finalize_logup_in_pairs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        components::{
+            Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
+            RegisterMemoryBoundary, ADDI, LUI, RANGE128, RANGE16, RANGE256, RANGE32, RANGE64,
+            RANGE8,
+        },
+        framework::test_utils::{
+            assert_claimed_sum_zero, assert_component, assert_reference_table, AssertContext,
+        },
+    };
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    #[test]
+    fn assert_div_constraints() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x1 = 0x7FF
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 0x7FF),
+            // x2 = -3
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 0xFFD),
+            // x3 = 0x80000000
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 3, 0, 0x80000),
+            // x4 = -1
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 4, 0, 0xFFF),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIV), 5, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIV), 6, 2, 1),
+            // division by zero
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIV), 7, 2, 0),
+            // overflow
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIV), 8, 3, 4),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIVU), 9, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIVU), 10, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::DIVU), 11, 3, 4),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REM), 12, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REM), 13, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REM), 14, 2, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REM), 15, 3, 4),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REMU), 16, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REMU), 17, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::REMU), 18, 3, 4),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        assert_component(DIV, assert_ctx);
+        assert_component(DIVU, assert_ctx);
+        assert_component(REM, assert_ctx);
+        assert_component(REMU, assert_ctx);
+
+        assert_claimed_sum_zero(
+            &[
+                &DIV,
+                &DIVU,
+                &REM,
+                &REMU,
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &ADDI,
+                &LUI,
+                &RANGE8,
+                &RANGE16,
+                &RANGE32,
+                &RANGE64,
+                &RANGE128,
+                &RANGE256,
+            ],
+            &mut AssertContext::new(&program_trace, &view),
+        );
+    }
+
+    #[test]
+    fn div_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::DIV,
+            DIV,
+            Column::AVal,
+            &[
+                (7, 2, 3),
+                (0xFFFF_FFF9, 2, 0xFFFF_FFFD),
+                (7, 0xFFFF_FFFE, 0xFFFF_FFFD),
+                (0xFFFF_FFF9, 0xFFFF_FFFE, 3),
+                (5, 0, 0xFFFF_FFFF),
+                (0xFFFF_FFFB, 0, 0xFFFF_FFFF),
+                (0x8000_0000, 0xFFFF_FFFF, 0x8000_0000),
+                (0x8000_0000, 1, 0x8000_0000),
+                (0x8000_0000, 2, 0xC000_0000),
+                (0xFFFF_FFFF, 0x8000_0000, 0),
+                (0x1234_5678, 0x1234, 0x0001_0004),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 1),
+                (0xFFFF_FFFF, 1, 0xFFFF_FFFF),
+            ],
+            &[],
+        );
+    }
+
+    #[test]
+    fn divu_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::DIVU,
+            DIVU,
+            Column::AVal,
+            &[
+                (7, 2, 3),
+                (0xFFFF_FFF9, 2, 0x7FFF_FFFC),
+                (7, 0xFFFF_FFFE, 0),
+                (0xFFFF_FFF9, 0xFFFF_FFFE, 0),
+                (5, 0, 0xFFFF_FFFF),
+                (0xFFFF_FFFB, 0, 0xFFFF_FFFF),
+                (0x8000_0000, 0xFFFF_FFFF, 0),
+                (0x8000_0000, 1, 0x8000_0000),
+                (0x8000_0000, 2, 0x4000_0000),
+                (0xFFFF_FFFF, 0x8000_0000, 1),
+                (0x1234_5678, 0x1234, 0x0001_0004),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 1),
+                (0xFFFF_FFFF, 1, 0xFFFF_FFFF),
+            ],
+            &[],
+        );
+    }
+
+    #[test]
+    fn rem_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::REM,
+            REM,
+            Column::AVal,
+            &[
+                (7, 2, 1),
+                (0xFFFF_FFF9, 2, 0xFFFF_FFFF),
+                (7, 0xFFFF_FFFE, 1),
+                (0xFFFF_FFF9, 0xFFFF_FFFE, 0xFFFF_FFFF),
+                (5, 0, 5),
+                (0xFFFF_FFFB, 0, 0xFFFF_FFFB),
+                (0x8000_0000, 0xFFFF_FFFF, 0),
+                (0x8000_0000, 1, 0),
+                (0x8000_0000, 2, 0),
+                (0xFFFF_FFFF, 0x8000_0000, 0xFFFF_FFFF),
+                (0x1234_5678, 0x1234, 0x0DA8),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0),
+                (0xFFFF_FFFF, 1, 0),
+            ],
+            &[],
+        );
+    }
+
+    #[test]
+    fn remu_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::REMU,
+            REMU,
+            Column::AVal,
+            &[
+                (7, 2, 1),
+                (0xFFFF_FFF9, 2, 1),
+                (7, 0xFFFF_FFFE, 7),
+                (0xFFFF_FFF9, 0xFFFF_FFFE, 0xFFFF_FFF9),
+                (5, 0, 5),
+                (0xFFFF_FFFB, 0, 0xFFFF_FFFB),
+                (0x8000_0000, 0xFFFF_FFFF, 0x8000_0000),
+                (0x8000_0000, 1, 0),
+                (0x8000_0000, 2, 0),
+                (0xFFFF_FFFF, 0x8000_0000, 0x7FFF_FFFF),
+                (0x1234_5678, 0x1234, 0x0DA8),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0),
+                (0xFFFF_FFFF, 1, 0),
+            ],
+            &[],
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, DivOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct RemDecoding;
+impl TypeRDecoding for RemDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::REM;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Rem = TypeR<RemDecoding>;
+impl DivOp for Rem {
+    const SIGNED: bool = true;
+    const REM: bool = true;
+}
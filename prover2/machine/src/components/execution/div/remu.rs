@@ -0,0 +1,19 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, DivOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct RemuDecoding;
+impl TypeRDecoding for RemuDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::REMU;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Remu = TypeR<RemuDecoding>;
+impl DivOp for Remu {
+    const SIGNED: bool = false;
+    const REM: bool = true;
+}
@@ -12,6 +12,9 @@
 //! │            │ R[x10]                                                        │                │
 //! │ 0x405      │ System call for heap allocation                               │ pc ← pc + 4    │
 //! └────────────┴───────────────────────────────────────────────────────────────┴────────────────┘
+//!
+//! Values read from private input are witness-only: they are bound to R[x10] through register memory
+//! and nothing else, so the program is responsible for validating prover-supplied hints.
 
 use num_traits::{One, Zero};
 use stwo::{
@@ -31,7 +34,6 @@ use stwo_constraint_framework::{EvalAtRow, RelationEntry};
 
 use nexus_common::constants::WORD_SIZE_HALVED;
 use nexus_vm::{riscv::BuiltinOpcode, SyscallCode, WORD_SIZE};
-use nexus_vm_prover_air_column::AirColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -148,6 +150,8 @@ impl Ecall {
 }
 
 impl BuiltInComponent for Ecall {
+    const NAME: &'static str = "ECALL";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -166,6 +170,11 @@ impl BuiltInComponent for Ecall {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        let num_steps = Self::iter_program_steps(side_note).count();
+        num_steps.next_power_of_two().ilog2().max(LOG_N_LANES)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = Self::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -192,10 +201,10 @@ impl BuiltInComponent for Ecall {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(component_trace.original_trace.len(), Column::COLUMNS_NUM);
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         // reg3-accessed is not a constant for ecall
         //
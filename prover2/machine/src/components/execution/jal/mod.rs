@@ -12,7 +12,6 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::AirColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -24,7 +23,7 @@ use nexus_vm_prover_trace::{
 
 use crate::{
     components::{
-        execution::common::{ExecutionComponent, ExecutionLookupEval},
+        execution::common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
         utils::{
             add_16bit_with_carry, add_with_carries, constraints::ClkIncrement,
             u32_to_16bit_parts_le,
@@ -123,6 +122,8 @@ impl Jal {
 }
 
 impl BuiltInComponent for Jal {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -142,6 +143,10 @@ impl BuiltInComponent for Jal {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -177,10 +182,10 @@ impl BuiltInComponent for Jal {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(component_trace.original_trace.len(), Column::COLUMNS_NUM);
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
         Decoding::generate_interaction_trace(
@@ -85,7 +85,7 @@ pub const fn load_instr_val(opcode: u8, funct3: u8) -> type_i::InstrVal<Column>
     }
 }
 
-pub const C_VAL: type_i::CVal<Column> = type_i::CVal {
+pub const IMMEDIATE: type_i::ITypeImmediate<Column> = type_i::ITypeImmediate {
     op_c0_3: Column::OpC0_3,
     op_c4_7: Column::OpC4_7,
     op_c8_10: Column::OpC8_10,
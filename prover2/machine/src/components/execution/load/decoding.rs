@@ -6,7 +6,7 @@ use nexus_vm_prover_trace::{
     program::ProgramStep, trace_eval,
 };
 
-use super::columns::{Column, PreprocessedColumn};
+use super::columns::{Column, PreprocessedColumn, IMMEDIATE};
 use crate::{
     lookups::{LogupTraceBuilder, RangeCheckLookupElements, RangeLookupBound},
     side_note::range_check::RangeCheckAccumulator,
@@ -32,20 +32,16 @@ impl Decoding {
         trace.fill_columns(row_idx, op_b0, Column::OpB0);
         trace.fill_columns(row_idx, op_b1_4, Column::OpB1_4);
 
-        let op_c_raw = program_step.step.instruction.op_c;
-        let op_c0_3 = (op_c_raw & 0xF) as u8;
-        let op_c4_7 = ((op_c_raw >> 4) & 0xF) as u8;
-        let op_c8_10 = ((op_c_raw >> 8) & 0x7) as u8;
-        let op_c11 = ((op_c_raw >> 11) & 0x1) as u8;
-        trace.fill_columns(row_idx, op_c0_3, Column::OpC0_3);
-        trace.fill_columns(row_idx, op_c4_7, Column::OpC4_7);
-        trace.fill_columns(row_idx, op_c8_10, Column::OpC8_10);
-        trace.fill_columns(row_idx, op_c11, Column::OpC11);
-
         range_check_accum
             .range16
-            .add_values_from_slice(&[op_a1_4, op_b1_4, op_c0_3, op_c4_7]);
-        range_check_accum.range8.add_value(op_c8_10);
+            .add_values_from_slice(&[op_a1_4, op_b1_4]);
+
+        IMMEDIATE.fill_trace_row(
+            trace,
+            row_idx,
+            program_step.step.instruction.op_c,
+            range_check_accum,
+        );
     }
 
     pub(super) fn constrain_decoding<E: EvalAtRow>(
@@ -55,27 +51,23 @@ impl Decoding {
     ) {
         let [op_a0] = trace_eval!(trace_eval, Column::OpA0);
         let [op_b0] = trace_eval!(trace_eval, Column::OpB0);
-        let [op_c11] = trace_eval!(trace_eval, Column::OpC11);
 
-        // constrain op_a0, op_b0, op_c11 ∈ {0, 1}
-        for bit in [op_a0, op_b0, op_c11.clone()] {
+        // constrain op_a0, op_b0 ∈ {0, 1}
+        for bit in [op_a0, op_b0] {
             eval.add_constraint(bit.clone() * (E::F::one() - bit));
         }
 
         let [op_a1_4] = trace_eval!(trace_eval, Column::OpA1_4);
         let [op_b1_4] = trace_eval!(trace_eval, Column::OpB1_4);
-        let [op_c0_3] = trace_eval!(trace_eval, Column::OpC0_3);
-        let [op_c4_7] = trace_eval!(trace_eval, Column::OpC4_7);
-        let [op_c8_10] = trace_eval!(trace_eval, Column::OpC8_10);
 
         let [is_local_pad] = trace_eval!(trace_eval, Column::IsLocalPad);
 
-        for col in [op_a1_4, op_b1_4, op_c0_3, op_c4_7] {
+        for col in [op_a1_4, op_b1_4] {
             range_check
                 .range16
                 .constrain(eval, is_local_pad.clone(), col);
         }
-        range_check.range8.constrain(eval, is_local_pad, op_c8_10);
+        IMMEDIATE.constrain(eval, trace_eval, is_local_pad, range_check);
     }
 
     pub(super) fn generate_interaction_trace(
@@ -87,17 +79,20 @@ impl Decoding {
 
         let [op_a1_4] = original_base_column!(component_trace, Column::OpA1_4);
         let [op_b1_4] = original_base_column!(component_trace, Column::OpB1_4);
-        let [op_c0_3] = original_base_column!(component_trace, Column::OpC0_3);
-        let [op_c4_7] = original_base_column!(component_trace, Column::OpC4_7);
-        let [op_c8_10] = original_base_column!(component_trace, Column::OpC8_10);
 
-        for col in [op_a1_4, op_b1_4, op_c0_3, op_c4_7] {
+        for col in [op_a1_4, op_b1_4] {
             range_check
                 .range16
                 .generate_logup_col(logup_trace_builder, is_local_pad.clone(), col);
         }
-        range_check
-            .range8
-            .generate_logup_col(logup_trace_builder, is_local_pad, op_c8_10);
+        IMMEDIATE.generate_interaction_trace(
+            logup_trace_builder,
+            is_local_pad,
+            |col| {
+                let [col] = component_trace.original_base_column(col);
+                col
+            },
+            range_check,
+        );
     }
 }
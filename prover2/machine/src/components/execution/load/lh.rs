@@ -10,12 +10,11 @@ use nexus_vm_prover_trace::{
     eval::TraceEval,
     program::ProgramStep,
     trace_eval,
-    virtual_column::VirtualColumn,
 };
 
 use super::{
     columns::{Column, PreprocessedColumn},
-    lb::SignExtByte,
+    sign_bit::SignBit,
     LoadOp,
 };
 use crate::{
@@ -57,12 +56,9 @@ impl LoadOp for Lh {
         range_check_accum: &mut RangeCheckAccumulator,
     ) {
         let value_a = program_step.get_result().expect("LH must have a result");
-        let h_ram_val_rem = value_a[1] & 0x7F;
-        let h_ram_val_sign = (value_a[1] >> 7) & 1;
 
         trace.fill_columns(row_idx, [value_a[0], value_a[1]], LhColumn::AVal);
-        trace.fill_columns(row_idx, h_ram_val_rem, LhColumn::HRamValRem);
-        trace.fill_columns(row_idx, h_ram_val_sign, LhColumn::HRamValSign);
+        SIGN_BIT.fill(trace, row_idx, value_a[1], range_check_accum);
 
         let value_b = program_step.get_value_b();
         let (value_c, _) = program_step.get_value_c();
@@ -71,9 +67,7 @@ impl LoadOp for Lh {
         let h_ram_base_addr_aux = h_ram_base_addr[0] >> 1;
         trace.fill_columns(row_idx, h_ram_base_addr_aux, LhColumn::HRamBaseAddrAux);
 
-        range_check_accum
-            .range128
-            .add_values_from_slice(&[h_ram_val_rem, h_ram_base_addr_aux]);
+        range_check_accum.range128.add_value(h_ram_base_addr_aux);
     }
 
     fn add_constraints<E: EvalAtRow>(
@@ -85,8 +79,6 @@ impl LoadOp for Lh {
         let [is_local_pad] = trace_eval!(trace_eval, Column::IsLocalPad);
 
         let [ram1_val, ram2_val] = trace_eval!(local_trace_eval, LhColumn::AVal);
-        let [h_ram_val_rem] = trace_eval!(local_trace_eval, LhColumn::HRamValRem);
-        let [h_ram_val_sign] = trace_eval!(local_trace_eval, LhColumn::HRamValSign);
 
         let h_ram_base_addr = trace_eval!(trace_eval, Column::HRamBaseAddr);
         let [h_ram_base_addr_aux] = trace_eval!(local_trace_eval, LhColumn::HRamBaseAddrAux);
@@ -96,23 +88,18 @@ impl LoadOp for Lh {
             (E::F::one() - is_local_pad.clone())
                 * (h_ram_base_addr_aux.clone() * BaseField::from(2) - h_ram_base_addr[0].clone()),
         );
-        // (1 − is-local-pad) · (h-ram-val-rem + h-ram-val-sgn · 2^7 − ram2-val) = 0
-        eval.add_constraint(
-            (E::F::one() - is_local_pad.clone())
-                * (h_ram_val_rem.clone() + h_ram_val_sign.clone() * BaseField::from(1 << 7)
-                    - ram2_val.clone()),
-        );
-        // (h-ram-val-sgn) · (1 − h-ram-val-sgn) = 0
-        eval.add_constraint(h_ram_val_sign.clone() * (E::F::one() - h_ram_val_sign));
 
-        range_check
-            .range128
-            .constrain(eval, is_local_pad.clone(), h_ram_val_rem);
+        let sign_ext_byte = SIGN_BIT.constrain(
+            eval,
+            is_local_pad.clone(),
+            ram2_val.clone(),
+            local_trace_eval,
+            range_check,
+        );
         range_check
             .range128
             .constrain(eval, is_local_pad.clone(), h_ram_base_addr_aux);
 
-        let sign_ext_byte = SIGN_EXT_BYTE.eval(local_trace_eval);
         let ram_values = [
             ram1_val.clone(),
             ram2_val.clone(),
@@ -156,4 +143,7 @@ impl LoadOp for Lh {
     }
 }
 
-const SIGN_EXT_BYTE: SignExtByte<LhColumn> = SignExtByte(LhColumn::HRamValSign);
+const SIGN_BIT: SignBit<LhColumn> = SignBit {
+    rem: LhColumn::HRamValRem,
+    sign: LhColumn::HRamValSign,
+};
@@ -32,7 +32,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             load::columns::load_instr_val,
         },
         read_write_memory::ShiftedBaseAddr,
@@ -59,6 +59,7 @@ mod lbu;
 mod lhu;
 
 mod decoding;
+mod sign_bit;
 
 mod columns;
 use columns::{Column, PreprocessedColumn};
@@ -158,6 +159,8 @@ impl<T: LoadOp> Load<T> {
 }
 
 impl<T: LoadOp> BuiltInComponent for Load<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -179,6 +182,10 @@ impl<T: LoadOp> BuiltInComponent for Load<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_load_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_load_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -221,10 +228,6 @@ impl<T: LoadOp> BuiltInComponent for Load<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + T::LocalColumn::COLUMNS_NUM
-        );
         let (
             rel_inst_to_ram,
             rel_inst_to_prog_memory,
@@ -233,7 +236,8 @@ impl<T: LoadOp> BuiltInComponent for Load<T> {
             rel_ram_read_addr,
             range_check,
         ) = Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
         let clk = original_base_column!(component_trace, Column::Clk);
@@ -316,7 +320,7 @@ impl<T: LoadOp> BuiltInComponent for Load<T> {
         let [is_local_pad] = trace_eval!(trace_eval, Column::IsLocalPad);
 
         let b_val = trace_eval!(trace_eval, Column::BVal);
-        let c_val = columns::C_VAL.eval(&trace_eval);
+        let c_val = columns::IMMEDIATE.eval(&trace_eval);
 
         let h_ram_base_addr = trace_eval!(trace_eval, Column::HRamBaseAddr);
         let h_carry = trace_eval!(trace_eval, Column::HCarry);
@@ -528,11 +532,11 @@ pub mod tests {
     use crate::{
         components::{
             Cpu, CpuBoundary, PrivateMemoryBoundary, ProgramMemory, ProgramMemoryBoundary,
-            ReadWriteMemory, RegisterMemory, RegisterMemoryBoundary, ADD, ADDI, RANGE128, RANGE16,
-            RANGE256, RANGE64, RANGE8,
+            ReadWriteMemory, RegisterMemory, RegisterMemoryBoundary, ADD, ADDI, LUI, RANGE128,
+            RANGE16, RANGE256, RANGE64, RANGE8, SB, SW,
         },
         framework::{
-            test_utils::{assert_component, components_claimed_sum, AssertContext},
+            test_utils::{assert_component, components_claimed_sum, load_word, AssertContext},
             MachineComponent,
         },
         verify::verify_logup_sum,
@@ -638,4 +642,90 @@ pub mod tests {
     fn assert_lhu_constraints() {
         assert_load_constraints(LHU, BuiltinOpcode::LHU);
     }
+
+    /// Stores 0xFF into memory, loads it back with `opcode` and returns the loaded register value.
+    fn load_byte_ff<C>(component: C, opcode: BuiltinOpcode) -> u32
+    where
+        C: BuiltInComponent + 'static + Sync,
+        C::LookupElements: 'static + Sync,
+    {
+        let mut instr = setup_ir();
+        instr.extend(load_word(3, 0xFF));
+        instr.extend([
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SB), 2, 3, 0),
+            Instruction::new_ir(Opcode::from(opcode), 5, 2, 0),
+        ]);
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = assert_component(component, assert_ctx);
+        claimed_sum += components_claimed_sum(&[&LUI, &SB], assert_ctx);
+        claimed_sum += components_claimed_sum(BASE_TEST_COMPONENTS, assert_ctx);
+        verify_logup_sum(&[claimed_sum], &view, &assert_ctx.lookup_elements).unwrap();
+
+        let block = program_trace.blocks.last().unwrap();
+        block.steps[0].result.expect("load must have a result")
+    }
+
+    #[test]
+    fn lb_sign_extends_byte() {
+        assert_eq!(load_byte_ff(LB, BuiltinOpcode::LB), 0xFFFFFFFF);
+    }
+
+    #[test]
+    fn lbu_zero_extends_byte() {
+        assert_eq!(load_byte_ff(LBU, BuiltinOpcode::LBU), 0x000000FF);
+    }
+
+    /// Stores `word` with SW, loads it back with `opcode` at `offset` and returns the loaded
+    /// register value.
+    fn load_stored_word<C>(component: C, opcode: BuiltinOpcode, word: u32, offset: u32) -> u32
+    where
+        C: BuiltInComponent + 'static + Sync,
+        C::LookupElements: 'static + Sync,
+    {
+        let mut instr = setup_ir();
+        instr.extend(load_word(3, word));
+        instr.extend([
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), 2, 3, 0),
+            Instruction::new_ir(Opcode::from(opcode), 5, 2, offset),
+        ]);
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = assert_component(component, assert_ctx);
+        claimed_sum += components_claimed_sum(&[&LUI, &SW], assert_ctx);
+        claimed_sum += components_claimed_sum(BASE_TEST_COMPONENTS, assert_ctx);
+        verify_logup_sum(&[claimed_sum], &view, &assert_ctx.lookup_elements).unwrap();
+
+        let block = program_trace.blocks.last().unwrap();
+        block.steps[0].result.expect("load must have a result")
+    }
+
+    #[test]
+    fn lbu_zero_extends_stored_word() {
+        assert_eq!(load_stored_word(LBU, BuiltinOpcode::LBU, 0xF7F8F9FA, 0), 0xFA);
+        assert_eq!(load_stored_word(LBU, BuiltinOpcode::LBU, 0xF7F8F9FA, 1), 0xF9);
+        assert_eq!(load_stored_word(LBU, BuiltinOpcode::LBU, 0xF7F8F9FA, 3), 0xF7);
+    }
+
+    #[test]
+    fn lhu_zero_extends_stored_word() {
+        assert_eq!(load_stored_word(LHU, BuiltinOpcode::LHU, 0xF7F8F9FA, 0), 0xF9FA);
+        assert_eq!(load_stored_word(LHU, BuiltinOpcode::LHU, 0xF7F8F9FA, 2), 0xF7F8);
+    }
+
+    #[test]
+    fn lb_sign_extends_stored_word() {
+        // 0x7F and 0x80 are the largest positive and the smallest negative byte
+        assert_eq!(load_stored_word(LB, BuiltinOpcode::LB, 0x01807F02, 1), 0x0000007F);
+        assert_eq!(load_stored_word(LB, BuiltinOpcode::LB, 0x01807F02, 2), 0xFFFFFF80);
+        assert_eq!(load_stored_word(LB, BuiltinOpcode::LB, 0x01807F02, 3), 0x00000001);
+    }
+
+    #[test]
+    fn lh_sign_extends_stored_word() {
+        assert_eq!(load_stored_word(LH, BuiltinOpcode::LH, 0x80017FFF, 0), 0x00007FFF);
+        assert_eq!(load_stored_word(LH, BuiltinOpcode::LH, 0x80017FFF, 2), 0xFFFF8001);
+    }
 }
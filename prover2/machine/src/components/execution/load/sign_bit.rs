@@ -0,0 +1,97 @@
+use num_traits::One;
+use stwo::{core::fields::m31::BaseField, prover::backend::simd::m31::PackedBaseField};
+use stwo_constraint_framework::EvalAtRow;
+
+use nexus_vm_prover_air_column::{
+    empty::EmptyPreprocessedColumn, AirColumn, PreprocessedAirColumn,
+};
+use nexus_vm_prover_trace::{
+    builder::TraceBuilder, component::ComponentTrace, eval::TraceEval,
+    virtual_column::VirtualColumn,
+};
+
+use super::columns::Column;
+use crate::{
+    lookups::{RangeCheckLookupElements, RangeLookupBound},
+    side_note::range_check::RangeCheckAccumulator,
+};
+
+/// Sign bit of the most significant loaded byte, shared by signed loads.
+///
+/// The byte is decomposed as `rem + sign · 2^7`, with `rem` range checked to 7 bits.
+pub(super) struct SignBit<C> {
+    /// Lower 7 bits of the byte.
+    pub rem: C,
+    /// Sign bit of the byte.
+    pub sign: C,
+}
+
+impl<C: AirColumn> SignBit<C> {
+    pub fn fill(
+        &self,
+        trace: &mut TraceBuilder<C>,
+        row_idx: usize,
+        byte: u8,
+        range_check_accum: &mut RangeCheckAccumulator,
+    ) {
+        let rem = byte & 0x7F;
+        let sign = byte >> 7;
+
+        trace.fill_columns(row_idx, rem, self.rem);
+        trace.fill_columns(row_idx, sign, self.sign);
+
+        range_check_accum.range128.add_value(rem);
+    }
+
+    /// Constrains the decomposition of `byte` and returns its sign extension.
+    pub fn constrain<E: EvalAtRow>(
+        &self,
+        eval: &mut E,
+        is_local_pad: E::F,
+        byte: E::F,
+        local_trace_eval: &TraceEval<EmptyPreprocessedColumn, C, E>,
+        range_check: &RangeCheckLookupElements,
+    ) -> E::F {
+        let [rem] = local_trace_eval.column_eval(self.rem);
+        let [sign] = local_trace_eval.column_eval(self.sign);
+
+        // (1 − is-local-pad) · (h-ram-val-rem + h-ram-val-sgn · 2^7 − byte) = 0
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (rem.clone() + sign.clone() * BaseField::from(1 << 7) - byte),
+        );
+        // (h-ram-val-sgn) · (1 − h-ram-val-sgn) = 0
+        eval.add_constraint(sign.clone() * (E::F::one() - sign));
+
+        range_check.range128.constrain(eval, is_local_pad, rem);
+
+        SignExtByte(self.sign).eval(local_trace_eval)
+    }
+}
+
+/// A sign-extended byte: 0xFF if sign bit is set, 0x00 otherwise.
+struct SignExtByte<C>(C);
+
+impl<C: AirColumn> VirtualColumn for SignExtByte<C> {
+    type Column = C;
+
+    fn eval<E: EvalAtRow, P: PreprocessedAirColumn>(
+        &self,
+        trace_eval: &TraceEval<P, Self::Column, E>,
+    ) -> E::F {
+        let [h_ram_val_sign] = trace_eval.column_eval(self.0);
+        h_ram_val_sign * BaseField::from((1 << 8) - 1)
+    }
+
+    fn combine_at_row(&self, component_trace: &ComponentTrace, vec_idx: usize) -> PackedBaseField {
+        assert_eq!(
+            component_trace.original_trace.len() - Column::COLUMNS_NUM,
+            self.0.offset() + 1
+        );
+        let h_ram_val_sign = component_trace
+            .original_trace
+            .last()
+            .expect("trace is not empty");
+        h_ram_val_sign.data[vec_idx] * BaseField::from((1 << 8) - 1)
+    }
+}
@@ -5,11 +5,14 @@ pub mod bitwise_multiplicity;
 pub mod branch_cmp_signed;
 pub mod branch_cmp_unsigned;
 pub mod branch_eq;
+pub mod div;
 pub mod ecall;
 pub mod jal;
 pub mod jalr;
 pub mod load;
 pub mod lui;
+pub mod mul;
+pub mod product_multiplicity;
 pub mod sll;
 pub mod slt;
 pub mod sltu;
@@ -0,0 +1,60 @@
+use nexus_vm_prover_air_column::{empty::EmptyPreprocessedColumn, AirColumn};
+
+pub type PreprocessedColumn = EmptyPreprocessedColumn;
+
+#[derive(Debug, Copy, Clone, AirColumn)]
+pub enum Column {
+    /// The current execution time represented by two 16-bit limbs
+    #[size = 2]
+    Clk,
+    /// The helper bit to compute the next clock value
+    #[size = 1]
+    ClkCarry,
+    /// A 32-bit word specifying the value of operand op-a represented by four 8-bit limbs
+    #[size = 4]
+    AVal,
+    /// A 32-bit word specifying the value of operand op-b represented by four 8-bit limbs
+    #[size = 4]
+    BVal,
+    /// The current value of the program counter register
+    #[size = 2]
+    Pc,
+    /// The helper bits to compute the program counter update
+    #[size = 1]
+    PcCarry,
+    /// Binary value to indicate if the row is a padding row
+    #[size = 1]
+    IsLocalPad,
+
+    // helper variables used for constraining multiplication
+    /// 64-bit unsigned product of b-val and c-val represented by eight 8-bit limbs
+    #[size = 8]
+    Prod,
+    /// Lower 8 bits of the carries between product limbs
+    #[size = 6]
+    ProdCarry,
+    /// Upper bits of the carries between product limbs
+    #[size = 6]
+    ProdCarryHigh,
+    /// Lower 8 bits of the partial products b-val(i) · c-val(j), stored at index i · 4 + j
+    #[size = 16]
+    PartialProdLow,
+    /// Upper 8 bits of the partial products b-val(i) · c-val(j), stored at index i · 4 + j
+    #[size = 16]
+    PartialProdHigh,
+    /// Sign bit of b-val
+    #[size = 1]
+    BSign,
+    /// Lower 7 bits of the most significant byte of b-val
+    #[size = 1]
+    BRem,
+    /// Sign bit of c-val
+    #[size = 1]
+    CSign,
+    /// Lower 7 bits of the most significant byte of c-val
+    #[size = 1]
+    CRem,
+    /// Carries of adding the sign correction to a-val at 16-bit boundaries
+    #[size = 2]
+    HCarry,
+}
@@ -0,0 +1,732 @@
+use std::marker::PhantomData;
+
+use num_traits::{One, Zero};
+use stwo::{
+    core::{
+        fields::{m31::BaseField, qm31::SecureField},
+        ColumnVec,
+    },
+    prover::{
+        backend::simd::{m31::PackedBaseField, SimdBackend},
+        poly::{circle::CircleEvaluation, BitReversedOrder},
+    },
+};
+use stwo_constraint_framework::{EvalAtRow, RelationEntry};
+
+use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
+use nexus_vm_prover_trace::{
+    builder::{FinalizedTrace, TraceBuilder},
+    component::{ComponentTrace, FinalizedColumn},
+    eval::TraceEval,
+    original_base_column,
+    program::{ProgramStep, Word},
+    trace_eval,
+};
+
+use crate::{
+    components::{
+        execution::{
+            common::{
+                opcode_component_name, ComponentTraceRef, ExecutionComponent, ExecutionLookupEval,
+            },
+            decoding::{type_r, InstructionDecoding},
+        },
+        utils::constraints::{StepCounters, StepCountersEval},
+    },
+    framework::BuiltInComponent,
+    lookups::{
+        AllLookupElements, ComponentLookupElements, InstToProgMemoryLookupElements,
+        InstToRegisterMemoryLookupElements, LogupTraceBuilder, ProductLookupElements,
+        ProgramExecutionLookupElements, RangeCheckLookupElements, RangeLookupBound,
+    },
+    side_note::{
+        product::ProductMultiplicities, program::ProgramTraceRef,
+        range_check::RangeCheckAccumulator, SideNote,
+    },
+};
+
+mod columns;
+mod mul;
+mod mulh;
+mod mulhsu;
+mod mulhu;
+
+use columns::{Column, PreprocessedColumn};
+
+const STEP_COUNTERS: StepCounters<Column> = StepCounters {
+    clk: Column::Clk,
+    clk_carry: Column::ClkCarry,
+    pc: Column::Pc,
+    pc_carry: Column::PcCarry,
+};
+
+pub const MUL: Mul<mul::Mul> = Mul::new();
+pub const MULH: Mul<mulh::Mulh> = Mul::new();
+pub const MULHU: Mul<mulhu::Mulhu> = Mul::new();
+pub const MULHSU: Mul<mulhsu::Mulhsu> = Mul::new();
+
+/// Number of limbs in the 64-bit product.
+const PROD_SIZE: usize = 2 * WORD_SIZE;
+/// Number of carries between product limbs, the last two limbs don't need one.
+const PROD_CARRY_SIZE: usize = PROD_SIZE - 2;
+/// Number of byte products b-val(i) · c-val(j), each one is looked up in the multiplication table.
+const PARTIAL_PROD_SIZE: usize = WORD_SIZE * WORD_SIZE;
+
+pub trait MulOp:
+    InstructionDecoding<PreprocessedColumn = PreprocessedColumn, MainColumn = Column>
+{
+    /// Whether the result is the upper word of the product.
+    const HIGH: bool;
+    /// Whether b-val is interpreted as a signed integer.
+    const SIGNED_B: bool;
+    /// Whether c-val is interpreted as a signed integer.
+    const SIGNED_C: bool;
+}
+
+/// Multiplication component, proves the 64-bit unsigned product of b-val and c-val byte by byte.
+///
+/// Byte products b-val(i) · c-val(j) are committed as pairs of bytes and looked up in the
+/// multiplication table of [`ProductMultiplicity`](crate::components::ProductMultiplicity), the
+/// schoolbook constraints sum the looked up products.
+///
+/// Signed variants take the upper word of the unsigned product and subtract the sign corrections
+/// `sgn(b) · c-val + sgn(c) · b-val` from it, which yields the upper word of the signed product
+/// modulo 2^32.
+pub struct Mul<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<T: MulOp> ExecutionComponent for Mul<T> {
+    const OPCODE: BuiltinOpcode = <T as InstructionDecoding>::OPCODE;
+
+    const REG1_ACCESSED: bool = true;
+    const REG2_ACCESSED: bool = true;
+    const REG3_ACCESSED: bool = true;
+    const REG3_WRITE: bool = true;
+}
+
+struct ExecutionResult {
+    /// Byte products b-val(i) · c-val(j) at index i · 4 + j.
+    partial_prod: [u16; PARTIAL_PROD_SIZE],
+    prod: [u8; PROD_SIZE],
+    /// Carry out of each product limb, fits into 11 bits.
+    prod_carry: [u16; PROD_CARRY_SIZE],
+    b_sign: u8,
+    c_sign: u8,
+    value_a: Word,
+    h_carry: [u8; 2],
+}
+
+impl<T: MulOp> Mul<T> {
+    const fn new() -> Self {
+        assert!(matches!(
+            T::OPCODE,
+            BuiltinOpcode::MUL | BuiltinOpcode::MULH | BuiltinOpcode::MULHU | BuiltinOpcode::MULHSU
+        ));
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+
+    fn execute_step(value_b: Word, value_c: Word) -> ExecutionResult {
+        let partial_prod: [u16; PARTIAL_PROD_SIZE] = std::array::from_fn(|idx| {
+            u16::from(value_b[idx / WORD_SIZE]) * u16::from(value_c[idx % WORD_SIZE])
+        });
+        let mut prod = [0u8; PROD_SIZE];
+        let mut prod_carry = [0u16; PROD_CARRY_SIZE];
+
+        let mut carry = 0u32;
+        for k in 0..PROD_SIZE {
+            let mut sum = carry;
+            for i in k.saturating_sub(WORD_SIZE - 1)..=k.min(WORD_SIZE - 1) {
+                sum += u32::from(partial_prod[i * WORD_SIZE + k - i]);
+            }
+            prod[k] = (sum & 0xFF) as u8;
+            carry = sum >> 8;
+            if k < PROD_CARRY_SIZE {
+                prod_carry[k] = carry as u16;
+            }
+        }
+        assert_eq!(carry, 0, "product must fit into 64 bits");
+
+        let b_sign = value_b[WORD_SIZE - 1] >> 7;
+        let c_sign = value_c[WORD_SIZE - 1] >> 7;
+        let sb = u32::from(T::SIGNED_B && b_sign == 1);
+        let sc = u32::from(T::SIGNED_C && c_sign == 1);
+
+        let b = u32::from_le_bytes(value_b);
+        let c = u32::from_le_bytes(value_c);
+        let prod_word: Word = if T::HIGH {
+            prod[WORD_SIZE..].try_into().unwrap()
+        } else {
+            prod[..WORD_SIZE].try_into().unwrap()
+        };
+        let prod_word = u32::from_le_bytes(prod_word);
+        let value_a = prod_word.wrapping_sub((sb * c).wrapping_add(sc * b));
+
+        // a-val + correction = prod-word + 2^32 · k, split at the 16-bit boundary
+        let lo = |x: u32| x & 0xFFFF;
+        let hi = |x: u32| x >> 16;
+        let h_carry_1 = (lo(value_a) + sb * lo(c) + sc * lo(b) - lo(prod_word)) >> 16;
+        let h_carry_2 = (hi(value_a) + sb * hi(c) + sc * hi(b) + h_carry_1 - hi(prod_word)) >> 16;
+
+        ExecutionResult {
+            partial_prod,
+            prod,
+            prod_carry,
+            b_sign,
+            c_sign,
+            value_a: value_a.to_le_bytes(),
+            h_carry: [h_carry_1 as u8, h_carry_2 as u8],
+        }
+    }
+
+    fn generate_trace_row(
+        &self,
+        trace: &mut TraceBuilder<Column>,
+        row_idx: usize,
+        program_step: ProgramStep,
+        range_check_accum: &mut RangeCheckAccumulator,
+        product_accum: &mut ProductMultiplicities,
+    ) {
+        let step = &program_step.step;
+
+        let value_b = program_step.get_value_b();
+        let (value_c, _) = program_step.get_value_c();
+        let ExecutionResult {
+            partial_prod,
+            prod,
+            prod_carry,
+            b_sign,
+            c_sign,
+            value_a,
+            h_carry,
+        } = Self::execute_step(value_b, value_c);
+        assert_eq!(program_step.get_result(), Some(value_a), "{:?}", T::OPCODE);
+
+        let partial_prod_low = partial_prod.map(|partial| (partial & 0xFF) as u8);
+        let partial_prod_high = partial_prod.map(|partial| (partial >> 8) as u8);
+        let prod_carry_low = prod_carry.map(|carry| (carry & 0xFF) as u8);
+        let prod_carry_high = prod_carry.map(|carry| (carry >> 8) as u8);
+        let b_rem = value_b[WORD_SIZE - 1] & 0x7F;
+        let c_rem = value_c[WORD_SIZE - 1] & 0x7F;
+
+        STEP_COUNTERS.fill(trace, row_idx, step);
+
+        trace.fill_columns_bytes(row_idx, &value_b, Column::BVal);
+        trace.fill_columns_bytes(row_idx, &value_a, Column::AVal);
+
+        trace.fill_columns(row_idx, partial_prod_low, Column::PartialProdLow);
+        trace.fill_columns(row_idx, partial_prod_high, Column::PartialProdHigh);
+        trace.fill_columns(row_idx, prod, Column::Prod);
+        trace.fill_columns(row_idx, prod_carry_low, Column::ProdCarry);
+        trace.fill_columns(row_idx, prod_carry_high, Column::ProdCarryHigh);
+        trace.fill_columns(row_idx, b_sign, Column::BSign);
+        trace.fill_columns(row_idx, b_rem, Column::BRem);
+        trace.fill_columns(row_idx, c_sign, Column::CSign);
+        trace.fill_columns(row_idx, c_rem, Column::CRem);
+        trace.fill_columns(row_idx, h_carry, Column::HCarry);
+
+        range_check_accum.range256.add_values(&prod);
+        range_check_accum.range256.add_values(&prod_carry_low);
+        for value in prod_carry_high.into_iter().chain(h_carry) {
+            range_check_accum.range8.add_value(value);
+        }
+        range_check_accum.range128.add_value(b_rem);
+        range_check_accum.range128.add_value(c_rem);
+
+        for i in 0..WORD_SIZE {
+            for j in 0..WORD_SIZE {
+                product_accum.add_product(value_b[i], value_c[j]);
+            }
+        }
+    }
+}
+
+impl<T: MulOp> BuiltInComponent for Mul<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
+    const LOG_CONSTRAINT_DEGREE_BOUND: u32 = 2;
+
+    type PreprocessedColumn = PreprocessedColumn;
+
+    type MainColumn = Column;
+
+    type LookupElements = (
+        InstToProgMemoryLookupElements,
+        ProgramExecutionLookupElements,
+        InstToRegisterMemoryLookupElements,
+        ProductLookupElements,
+        RangeCheckLookupElements,
+    );
+
+    fn generate_preprocessed_trace(
+        &self,
+        _log_size: u32,
+        _program: &ProgramTraceRef,
+    ) -> FinalizedTrace {
+        FinalizedTrace::empty()
+    }
+
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
+    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
+        let mut product_accum = ProductMultiplicities::default();
+        let trace = <Self as ExecutionComponent>::generate_main_trace_with(
+            side_note,
+            Column::IsLocalPad,
+            |common_trace, local_trace, row_idx, program_step, range_check_accum| {
+                self.generate_trace_row(
+                    common_trace,
+                    row_idx,
+                    program_step,
+                    range_check_accum,
+                    &mut product_accum,
+                );
+                T::generate_trace_row(row_idx, local_trace, program_step, range_check_accum);
+            },
+        );
+        side_note.product.append(product_accum);
+        trace
+    }
+
+    fn generate_interaction_trace(
+        &self,
+        component_trace: ComponentTrace,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> (
+        ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        SecureField,
+    ) {
+        let (
+            rel_inst_to_prog_memory,
+            rel_cont_prog_exec,
+            rel_inst_to_reg_memory,
+            rel_product,
+            range_check,
+        ) = Self::LookupElements::get(lookup_elements);
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
+
+        let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
+        let prod = original_base_column!(component_trace, Column::Prod);
+        let prod_carry = original_base_column!(component_trace, Column::ProdCarry);
+        let prod_carry_high = original_base_column!(component_trace, Column::ProdCarryHigh);
+        let h_carry = original_base_column!(component_trace, Column::HCarry);
+        let [b_rem] = original_base_column!(component_trace, Column::BRem);
+        let [c_rem] = original_base_column!(component_trace, Column::CRem);
+
+        range_check.range256.generate_logup_col(
+            &mut logup_trace_builder,
+            is_local_pad.clone(),
+            &prod,
+        );
+        range_check.range256.generate_logup_col(
+            &mut logup_trace_builder,
+            is_local_pad.clone(),
+            &prod_carry,
+        );
+        for value in prod_carry_high.into_iter().chain(h_carry) {
+            range_check.range8.generate_logup_col(
+                &mut logup_trace_builder,
+                is_local_pad.clone(),
+                value,
+            );
+        }
+        for rem in [b_rem, c_rem] {
+            range_check.range128.generate_logup_col(
+                &mut logup_trace_builder,
+                is_local_pad.clone(),
+                rem,
+            );
+        }
+
+        let b_val = original_base_column!(component_trace, Column::BVal);
+        let c_val: [FinalizedColumn; WORD_SIZE] =
+            ComponentTraceRef::<'_, Column, type_r::DecodingColumn>::split(&component_trace)
+                .base_column(type_r::DecodingColumn::CVal);
+        let partial_prod_low = original_base_column!(component_trace, Column::PartialProdLow);
+        let partial_prod_high = original_base_column!(component_trace, Column::PartialProdHigh);
+        for idx in 0..PARTIAL_PROD_SIZE {
+            logup_trace_builder.add_to_relation_with(
+                &rel_product,
+                [is_local_pad.clone()],
+                |[is_local_pad]| (PackedBaseField::one() - is_local_pad).into(),
+                &[
+                    b_val[idx / WORD_SIZE].clone(),
+                    c_val[idx % WORD_SIZE].clone(),
+                    partial_prod_low[idx].clone(),
+                    partial_prod_high[idx].clone(),
+                ],
+            );
+        }
+
+        let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
+        <T as InstructionDecoding>::generate_interaction_trace(
+            &mut logup_trace_builder,
+            &component_trace,
+            &range_check,
+        );
+        <Self as ExecutionComponent>::generate_interaction_trace(
+            &mut logup_trace_builder,
+            &component_trace,
+            side_note,
+            &(
+                rel_inst_to_prog_memory,
+                rel_cont_prog_exec,
+                rel_inst_to_reg_memory,
+            ),
+            is_local_pad,
+        );
+        logup_trace_builder.finalize()
+    }
+
+    fn add_constraints<E: EvalAtRow>(
+        &self,
+        eval: &mut E,
+        trace_eval: TraceEval<Self::PreprocessedColumn, Self::MainColumn, E>,
+        lookup_elements: &Self::LookupElements,
+    ) {
+        let (
+            rel_inst_to_prog_memory,
+            rel_cont_prog_exec,
+            rel_inst_to_reg_memory,
+            rel_product,
+            range_check,
+        ) = lookup_elements;
+        let local_trace_eval = TraceEval::new(eval);
+
+        let [is_local_pad] = trace_eval!(trace_eval, Column::IsLocalPad);
+
+        let a_val = trace_eval!(trace_eval, Column::AVal);
+        let b_val = trace_eval!(trace_eval, Column::BVal);
+        let c_val = T::combine_c_val(&local_trace_eval);
+
+        let StepCountersEval {
+            clk,
+            clk_next,
+            pc,
+            pc_next,
+        } = STEP_COUNTERS.constrain(eval, &trace_eval);
+
+        let partial_prod_low = trace_eval!(trace_eval, Column::PartialProdLow);
+        let partial_prod_high = trace_eval!(trace_eval, Column::PartialProdHigh);
+        let prod = trace_eval!(trace_eval, Column::Prod);
+        let prod_carry_low = trace_eval!(trace_eval, Column::ProdCarry);
+        let prod_carry_high = trace_eval!(trace_eval, Column::ProdCarryHigh);
+        let [b_sign] = trace_eval!(trace_eval, Column::BSign);
+        let [b_rem] = trace_eval!(trace_eval, Column::BRem);
+        let [c_sign] = trace_eval!(trace_eval, Column::CSign);
+        let [c_rem] = trace_eval!(trace_eval, Column::CRem);
+        let [h_carry_1, h_carry_2] = trace_eval!(trace_eval, Column::HCarry);
+
+        let prod_carry: [E::F; PROD_CARRY_SIZE] = std::array::from_fn(|k| {
+            prod_carry_low[k].clone() + prod_carry_high[k].clone() * BaseField::from(1 << 8)
+        });
+        // the multiplication table lookup below ensures partial-prod(i, j) = b-val(i) · c-val(j)
+        let partial_prod: [E::F; PARTIAL_PROD_SIZE] = std::array::from_fn(|idx| {
+            partial_prod_low[idx].clone() + partial_prod_high[idx].clone() * BaseField::from(1 << 8)
+        });
+
+        // schoolbook multiplication, one product limb at a time
+        //
+        // (1 − is-local-pad) · (
+        //     Σ_{i + j = k} partial-prod(i, j) + prod-carry(k − 1)
+        //     − prod(k) − prod-carry(k) · 2^8
+        // ) = 0                                              for k = 1, …, 6, prod-carry(0) = 0
+        // (1 − is-local-pad) · (
+        //     partial-prod(4, 4) + prod-carry(6) − prod(7) − prod(8) · 2^8
+        // ) = 0
+        for k in 0..PROD_SIZE - 1 {
+            let mut sum = E::F::zero();
+            for i in k.saturating_sub(WORD_SIZE - 1)..=k.min(WORD_SIZE - 1) {
+                sum += partial_prod[i * WORD_SIZE + k - i].clone();
+            }
+            if k > 0 {
+                sum += prod_carry[k - 1].clone();
+            }
+            let carry_out = if k < PROD_CARRY_SIZE {
+                prod_carry[k].clone()
+            } else {
+                prod[k + 1].clone()
+            };
+            eval.add_constraint(
+                (E::F::one() - is_local_pad.clone())
+                    * (sum - prod[k].clone() - carry_out * BaseField::from(1 << 8)),
+            );
+        }
+
+        // (1 − is-local-pad) · (b-rem + b-sgn · 2^7 − b-val(4)) = 0
+        // (b-sgn) · (1 − b-sgn) = 0
+        // (1 − is-local-pad) · (c-rem + c-sgn · 2^7 − c-val(4)) = 0
+        // (c-sgn) · (1 − c-sgn) = 0
+        for (rem, sign, byte) in [
+            (&b_rem, &b_sign, &b_val[WORD_SIZE - 1]),
+            (&c_rem, &c_sign, &c_val[WORD_SIZE - 1]),
+        ] {
+            eval.add_constraint(
+                (E::F::one() - is_local_pad.clone())
+                    * (rem.clone() + sign.clone() * BaseField::from(1 << 7) - byte.clone()),
+            );
+            eval.add_constraint(sign.clone() * (E::F::one() - sign.clone()));
+        }
+
+        // the sign of an unsigned operand doesn't contribute to the correction
+        let sb = if T::SIGNED_B {
+            b_sign
+        } else {
+            E::F::zero()
+        };
+        let sc = if T::SIGNED_C {
+            c_sign
+        } else {
+            E::F::zero()
+        };
+        let prod_word = if T::HIGH {
+            &prod[WORD_SIZE..]
+        } else {
+            &prod[..WORD_SIZE]
+        };
+        let half = |word: &[E::F], i: usize| {
+            word[2 * i].clone() + word[2 * i + 1].clone() * BaseField::from(1 << 8)
+        };
+
+        // (1 − is-local-pad) · (
+        //     a-val(1) + a-val(2) · 2^8
+        //     + sb · (c-val(1) + c-val(2) · 2^8) + sc · (b-val(1) + b-val(2) · 2^8)
+        //     − prod-word(1) − prod-word(2) · 2^8 − h-carry(1) · 2^16
+        // ) = 0
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (half(&a_val, 0)
+                    + sb.clone() * half(&c_val, 0)
+                    + sc.clone() * half(&b_val, 0)
+                    - half(prod_word, 0)
+                    - h_carry_1.clone() * BaseField::from(1 << 16)),
+        );
+        // (1 − is-local-pad) · (
+        //     a-val(3) + a-val(4) · 2^8
+        //     + sb · (c-val(3) + c-val(4) · 2^8) + sc · (b-val(3) + b-val(4) · 2^8) + h-carry(1)
+        //     − prod-word(3) − prod-word(4) · 2^8 − h-carry(2) · 2^16
+        // ) = 0
+        eval.add_constraint(
+            (E::F::one() - is_local_pad.clone())
+                * (half(&a_val, 1)
+                    + sb * half(&c_val, 1)
+                    + sc * half(&b_val, 1)
+                    + h_carry_1.clone()
+                    - half(prod_word, 1)
+                    - h_carry_2.clone() * BaseField::from(1 << 16)),
+        );
+
+        // range checks
+        range_check
+            .range256
+            .constrain(eval, is_local_pad.clone(), &prod);
+        range_check
+            .range256
+            .constrain(eval, is_local_pad.clone(), &prod_carry_low);
+        for value in prod_carry_high.into_iter().chain([h_carry_1, h_carry_2]) {
+            range_check
+                .range8
+                .constrain(eval, is_local_pad.clone(), value);
+        }
+        for rem in [b_rem, c_rem] {
+            range_check
+                .range128
+                .constrain(eval, is_local_pad.clone(), rem);
+        }
+
+        // (b-val(i), c-val(j), partial-prod-low(i, j), partial-prod-high(i, j)) is in the table
+        for idx in 0..PARTIAL_PROD_SIZE {
+            eval.add_to_relation(RelationEntry::new(
+                rel_product,
+                (E::F::one() - is_local_pad.clone()).into(),
+                &[
+                    b_val[idx / WORD_SIZE].clone(),
+                    c_val[idx % WORD_SIZE].clone(),
+                    partial_prod_low[idx].clone(),
+                    partial_prod_high[idx].clone(),
+                ],
+            ));
+        }
+
+        T::constrain_decoding(eval, &trace_eval, &local_trace_eval, range_check);
+
+        // Logup Interactions
+        let instr_val = T::combine_instr_val(&local_trace_eval);
+        let reg_addrs = T::combine_reg_addresses(&local_trace_eval);
+
+        <Self as ExecutionComponent>::constrain_logups(
+            eval,
+            (
+                rel_inst_to_prog_memory,
+                rel_cont_prog_exec,
+                rel_inst_to_reg_memory,
+            ),
+            ExecutionLookupEval {
+                is_local_pad,
+                reg_addrs,
+                reg_values: [a_val, b_val, c_val],
+                instr_val,
+                clk,
+                clk_next,
+                pc,
+                pc_next,
+            },
+        );
+
+        eval.finalize_logup_in_pairs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        components::{
+            Cpu, CpuBoundary, ProductMultiplicity, ProgramMemory, ProgramMemoryBoundary,
+            RegisterMemory, RegisterMemoryBoundary, ADDI, LUI, RANGE128, RANGE16, RANGE256,
+            RANGE32, RANGE64, RANGE8,
+        },
+        framework::test_utils::{
+            assert_claimed_sum_zero, assert_component, assert_reference_table, AssertContext,
+        },
+    };
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    #[test]
+    fn assert_mul_constraints() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x1 = 0x7FF
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 0x7FF),
+            // x2 = -3
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 0xFFD),
+            // x3 = 0x80000000
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 3, 0, 0x80000),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MUL), 4, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MUL), 5, 2, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MULH), 6, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MULH), 7, 3, 3),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MULHU), 8, 2, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MULHU), 9, 3, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MULHSU), 10, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::MULHSU), 11, 1, 2),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        assert_component(MUL, assert_ctx);
+        assert_component(MULH, assert_ctx);
+        assert_component(MULHU, assert_ctx);
+        assert_component(MULHSU, assert_ctx);
+        assert_component(ProductMultiplicity, assert_ctx);
+
+        assert_claimed_sum_zero(
+            &[
+                &MUL,
+                &MULH,
+                &MULHU,
+                &MULHSU,
+                &ProductMultiplicity,
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &ADDI,
+                &LUI,
+                &RANGE8,
+                &RANGE16,
+                &RANGE32,
+                &RANGE64,
+                &RANGE128,
+                &RANGE256,
+            ],
+            &mut AssertContext::new(&program_trace, &view),
+        );
+    }
+
+    #[test]
+    fn mul_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::MUL,
+            MUL,
+            Column::AVal,
+            &[
+                (7, 6, 42),
+                (0x1234_5678, 0x9ABC_DEF0, 0x242D_2080),
+                (0xFFFF_FFFF, 2, 0xFFFF_FFFE),
+                (0x8000_0000, 0x8000_0000, 0),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 1),
+                (0x7FFF_FFFF, 0x8000_0000, 0x8000_0000),
+            ],
+            &[&ProductMultiplicity],
+        );
+    }
+
+    #[test]
+    fn mulh_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::MULH,
+            MULH,
+            Column::AVal,
+            &[
+                (7, 6, 0),
+                (0x1234_5678, 0x9ABC_DEF0, 0xF8CC_93D6),
+                (0xFFFF_FFFF, 2, 0xFFFF_FFFF),
+                (2, 0xFFFF_FFFF, 0xFFFF_FFFF),
+                (0x8000_0000, 0x8000_0000, 0x4000_0000),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0),
+                (0x7FFF_FFFF, 0x8000_0000, 0xC000_0000),
+            ],
+            &[&ProductMultiplicity],
+        );
+    }
+
+    #[test]
+    fn mulhu_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::MULHU,
+            MULHU,
+            Column::AVal,
+            &[
+                (7, 6, 0),
+                (0x1234_5678, 0x9ABC_DEF0, 0x0B00_EA4E),
+                (0xFFFF_FFFF, 2, 1),
+                (0x8000_0000, 0x8000_0000, 0x4000_0000),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFE),
+                (0x7FFF_FFFF, 0x8000_0000, 0x3FFF_FFFF),
+            ],
+            &[&ProductMultiplicity],
+        );
+    }
+
+    #[test]
+    fn mulhsu_reference_table() {
+        assert_reference_table(
+            BuiltinOpcode::MULHSU,
+            MULHSU,
+            Column::AVal,
+            &[
+                (7, 6, 0),
+                (0x1234_5678, 0x9ABC_DEF0, 0x0B00_EA4E),
+                (0xFFFF_FFFF, 2, 0xFFFF_FFFF),
+                (2, 0xFFFF_FFFF, 1),
+                (0x8000_0000, 0x8000_0000, 0xC000_0000),
+                (0xFFFF_FFFF, 0xFFFF_FFFF, 0xFFFF_FFFF),
+                (0x7FFF_FFFF, 0x8000_0000, 0x3FFF_FFFF),
+            ],
+            &[&ProductMultiplicity],
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, MulOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct MulDecoding;
+impl TypeRDecoding for MulDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::MUL;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Mul = TypeR<MulDecoding>;
+impl MulOp for Mul {
+    const HIGH: bool = false;
+    const SIGNED_B: bool = false;
+    const SIGNED_C: bool = false;
+}
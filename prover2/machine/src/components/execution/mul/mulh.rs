@@ -0,0 +1,20 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, MulOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct MulhDecoding;
+impl TypeRDecoding for MulhDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::MULH;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Mulh = TypeR<MulhDecoding>;
+impl MulOp for Mulh {
+    const HIGH: bool = true;
+    const SIGNED_B: bool = true;
+    const SIGNED_C: bool = true;
+}
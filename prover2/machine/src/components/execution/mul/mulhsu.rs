@@ -0,0 +1,20 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, MulOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct MulhsuDecoding;
+impl TypeRDecoding for MulhsuDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::MULHSU;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Mulhsu = TypeR<MulhsuDecoding>;
+impl MulOp for Mulhsu {
+    const HIGH: bool = true;
+    const SIGNED_B: bool = true;
+    const SIGNED_C: bool = false;
+}
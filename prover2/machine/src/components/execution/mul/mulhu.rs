@@ -0,0 +1,20 @@
+use nexus_vm::riscv::BuiltinOpcode;
+
+use super::{Column, MulOp, PreprocessedColumn};
+use crate::components::execution::decoding::type_r::{TypeR, TypeRDecoding};
+
+pub struct MulhuDecoding;
+impl TypeRDecoding for MulhuDecoding {
+    const OPCODE: BuiltinOpcode = BuiltinOpcode::MULHU;
+    const IS_LOCAL_PAD: Column = Column::IsLocalPad;
+
+    type PreprocessedColumn = PreprocessedColumn;
+    type MainColumn = Column;
+}
+
+pub type Mulhu = TypeR<MulhuDecoding>;
+impl MulOp for Mulhu {
+    const HIGH: bool = true;
+    const SIGNED_B: bool = false;
+    const SIGNED_C: bool = false;
+}
@@ -0,0 +1,27 @@
+use nexus_vm_prover_air_column::{AirColumn, PreprocessedAirColumn};
+
+#[derive(Debug, Copy, Clone, PreprocessedAirColumn)]
+#[preprocessed_prefix = "product_multiplicity"]
+pub enum PreprocessedColumn {
+    /// Eight-bit first operand of the multiplication table
+    #[size = 1]
+    BVal,
+    /// Eight-bit second operand of the multiplication table
+    #[size = 1]
+    CVal,
+    /// Lower 8 bits of the product b * c
+    #[size = 1]
+    ProdLow,
+    /// Upper 8 bits of the product b * c
+    #[size = 1]
+    ProdHigh,
+}
+
+/// Multiplicity column used for byte multiplication lookups.
+#[derive(Debug, Copy, Clone, AirColumn)]
+pub enum Column {
+    /// Multiplicity[b * 256 + c] counts how many times the product b * c is looked up in the
+    /// entire trace.
+    #[size = 1]
+    Multiplicity,
+}
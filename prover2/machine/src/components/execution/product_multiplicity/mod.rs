@@ -0,0 +1,139 @@
+//! Lookup table component for subtracting byte multiplication final multiplicities.
+
+use stwo::{
+    core::{
+        fields::{m31::BaseField, qm31::SecureField},
+        ColumnVec,
+    },
+    prover::{
+        backend::simd::{column::BaseColumn, SimdBackend},
+        poly::{circle::CircleEvaluation, BitReversedOrder},
+    },
+};
+use stwo_constraint_framework::{EvalAtRow, RelationEntry};
+
+use nexus_vm_prover_trace::{
+    builder::FinalizedTrace, component::ComponentTrace, eval::TraceEval, original_base_column,
+    preprocessed_base_column, preprocessed_trace_eval, trace_eval,
+};
+
+use crate::{
+    framework::BuiltInComponent,
+    lookups::{AllLookupElements, LogupTraceBuilder, ProductLookupElements},
+    side_note::{program::ProgramTraceRef, SideNote},
+};
+
+mod columns;
+use columns::{Column, PreprocessedColumn};
+
+pub struct ProductMultiplicity;
+
+impl ProductMultiplicity {
+    const NUM_BITS: u32 = 8;
+    const LOG_SIZE: u32 = Self::NUM_BITS * 2;
+}
+
+impl BuiltInComponent for ProductMultiplicity {
+    const NAME: &'static str = "ProductMultiplicity";
+
+    type PreprocessedColumn = PreprocessedColumn;
+
+    type MainColumn = Column;
+
+    type LookupElements = ProductLookupElements;
+
+    fn generate_preprocessed_trace(
+        &self,
+        _log_size: u32,
+        _program: &ProgramTraceRef,
+    ) -> FinalizedTrace {
+        let column_b = (0u32..1 << Self::NUM_BITS)
+            .flat_map(|i| std::iter::repeat_n(i, 1 << Self::NUM_BITS));
+        let column_c = (0u32..1 << Self::NUM_BITS).cycle().take(1 << Self::LOG_SIZE);
+        let range_iter = column_b.clone().zip(column_c.clone());
+
+        let column_b = BaseColumn::from_iter(column_b.map(BaseField::from));
+        let column_c = BaseColumn::from_iter(column_c.map(BaseField::from));
+        let column_prod_low =
+            BaseColumn::from_iter(range_iter.clone().map(|(b, c)| ((b * c) & 0xFF).into()));
+        let column_prod_high =
+            BaseColumn::from_iter(range_iter.map(|(b, c)| ((b * c) >> 8).into()));
+
+        FinalizedTrace {
+            cols: vec![column_b, column_c, column_prod_low, column_prod_high],
+            log_size: Self::LOG_SIZE,
+        }
+    }
+
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::LOG_SIZE
+    }
+
+    fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
+        let multiplicities = &side_note.product;
+        let multiplicity = BaseColumn::from_iter((0..=255u8).flat_map(|b| {
+            (0..=255u8).map(move |c| {
+                multiplicities
+                    .get(&(b, c))
+                    .copied()
+                    .unwrap_or_default()
+                    .into()
+            })
+        }));
+        FinalizedTrace {
+            cols: vec![multiplicity],
+            log_size: Self::LOG_SIZE,
+        }
+    }
+
+    fn generate_interaction_trace(
+        &self,
+        component_trace: ComponentTrace,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> (
+        ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        SecureField,
+    ) {
+        let rel_product: &Self::LookupElements = lookup_elements.as_ref();
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
+
+        let [b_val] = preprocessed_base_column!(component_trace, PreprocessedColumn::BVal);
+        let [c_val] = preprocessed_base_column!(component_trace, PreprocessedColumn::CVal);
+        let [prod_low] = preprocessed_base_column!(component_trace, PreprocessedColumn::ProdLow);
+        let [prod_high] = preprocessed_base_column!(component_trace, PreprocessedColumn::ProdHigh);
+        let [mult] = original_base_column!(component_trace, Column::Multiplicity);
+
+        logup_trace_builder.add_to_relation_with(
+            rel_product,
+            [mult],
+            |[mult]| (-mult).into(),
+            &[b_val, c_val, prod_low, prod_high],
+        );
+
+        logup_trace_builder.finalize()
+    }
+
+    fn add_constraints<E: EvalAtRow>(
+        &self,
+        eval: &mut E,
+        trace_eval: TraceEval<Self::PreprocessedColumn, Self::MainColumn, E>,
+        lookup_elements: &Self::LookupElements,
+    ) {
+        let [b_val] = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::BVal);
+        let [c_val] = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::CVal);
+        let [prod_low] = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::ProdLow);
+        let [prod_high] = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::ProdHigh);
+        let [mult] = trace_eval!(trace_eval, Column::Multiplicity);
+
+        let numerator: E::EF = (-mult).into();
+        eval.add_to_relation(RelationEntry::new(
+            lookup_elements,
+            numerator,
+            &[b_val, c_val, prod_low, prod_high],
+        ));
+
+        eval.finalize_logup_in_pairs();
+    }
+}
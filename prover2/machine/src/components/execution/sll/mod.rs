@@ -14,7 +14,6 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::AirColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -28,7 +27,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::InstructionDecoding,
         },
         utils::{
@@ -176,6 +175,8 @@ impl<T: SllOp> Sll<T> {
 }
 
 impl<T: SllOp> BuiltInComponent for Sll<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     const LOG_CONSTRAINT_DEGREE_BOUND: u32 = 2;
 
     type PreprocessedColumn = PreprocessedColumn;
@@ -197,6 +198,10 @@ impl<T: SllOp> BuiltInComponent for Sll<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -239,13 +244,10 @@ impl<T: SllOp> BuiltInComponent for Sll<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + T::DecodingColumn::COLUMNS_NUM
-        );
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
         let [h_rem] = original_base_column!(component_trace, Column::HRem);
@@ -530,4 +532,28 @@ mod tests {
 
         assert!(claimed_sum.is_zero());
     }
+
+    #[test]
+    // the constraint evaluator reports the row of the first constraint that doesn't vanish
+    #[should_panic(expected = "row: ")]
+    fn slli_reserved_imm_bits_rejected() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLLI), 2, 1, 3),
+        ])];
+        let (view, mut program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        // imm[5] is reserved for RV32 shifts and must be zero
+        let step = program_trace
+            .blocks
+            .iter_mut()
+            .flat_map(|block| block.steps.iter_mut())
+            .find(|step| step.instruction.opcode.builtin() == Some(BuiltinOpcode::SLLI))
+            .expect("SLLI step is present");
+        step.raw_instruction |= 1 << 25;
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        assert_component(SLLI, assert_ctx);
+    }
 }
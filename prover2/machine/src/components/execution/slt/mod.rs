@@ -14,7 +14,6 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::AirColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -28,7 +27,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::InstructionDecoding,
         },
         utils::{
@@ -155,6 +154,8 @@ impl<T: SltOp> Slt<T> {
 }
 
 impl<T: SltOp> BuiltInComponent for Slt<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -174,6 +175,10 @@ impl<T: SltOp> BuiltInComponent for Slt<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -218,13 +223,10 @@ impl<T: SltOp> BuiltInComponent for Slt<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + T::DecodingColumn::COLUMNS_NUM
-        );
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
 
@@ -417,7 +419,7 @@ mod tests {
     use crate::{
         components::{
             Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
-            RegisterMemoryBoundary, ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
+            RegisterMemoryBoundary, ADDI, LUI, RANGE16, RANGE256, RANGE64, RANGE8, SLTU,
         },
         framework::test_utils::{assert_component, components_claimed_sum, AssertContext},
     };
@@ -495,4 +497,61 @@ mod tests {
 
         assert!(claimed_sum.is_zero());
     }
+
+    #[test]
+    fn slt_sltu_diverge_at_sign_boundary() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x1 = 0x80000000
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 1, 0, 0x80000),
+            // x2 = 1
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 1),
+            // x3 = 0x7FFFFFFF
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 3, 1, -1i32 as u32),
+            // x4 = (x1 < x2) -> 1   // -2^31 < 1
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLT), 4, 1, 2),
+            // x5 = (x1 <u x2) -> 0  // 2^31 < 1
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 5, 1, 2),
+            // x6 = (x3 < x1) -> 0   // 2^31 - 1 < -2^31
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLT), 6, 3, 1),
+            // x7 = (x3 <u x1) -> 1  // 2^31 - 1 < 2^31
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 7, 3, 1),
+            // x8 = (x1 < x1) -> 0
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLT), 8, 1, 1),
+            // x9 = (x1 <u x1) -> 0
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 9, 1, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let results: Vec<_> = program_trace.blocks[3..]
+            .iter()
+            .map(|block| block.steps[0].result)
+            .collect();
+        assert_eq!(results, [Some(1), Some(0), Some(0), Some(1), Some(0), Some(0)]);
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = SecureField::zero();
+
+        claimed_sum += assert_component(SLT, assert_ctx);
+        claimed_sum += assert_component(SLTU, assert_ctx);
+
+        claimed_sum += components_claimed_sum(
+            &[
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &ADDI,
+                &LUI,
+                &RANGE8,
+                &RANGE16,
+                &RANGE64,
+                &RANGE256,
+            ],
+            assert_ctx,
+        );
+
+        assert!(claimed_sum.is_zero());
+    }
 }
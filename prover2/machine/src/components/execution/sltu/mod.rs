@@ -14,7 +14,6 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::AirColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -28,7 +27,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::InstructionDecoding,
         },
         utils::{
@@ -139,6 +138,8 @@ impl<T: SltuOp> Sltu<T> {
 }
 
 impl<T: SltuOp> BuiltInComponent for Sltu<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -158,6 +159,10 @@ impl<T: SltuOp> BuiltInComponent for Sltu<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -200,13 +205,10 @@ impl<T: SltuOp> BuiltInComponent for Sltu<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + T::DecodingColumn::COLUMNS_NUM
-        );
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         // range check h-rem
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
@@ -381,7 +383,7 @@ mod tests {
             Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTIU), 3, 1, 2),
             // x3 = 0 because 2 < 1 (immediate) doesn't hold
             Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTIU), 3, 2, 1),
-            // x3 = 1 because any number < 0xFFF (4095 in decimal, treated as unsigned)
+            // x3 = 1 because 1 < 0xFFFF_FFFF (0xFFF is sign-extended, then compared as unsigned)
             Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTIU), 3, 1, 0xFFF),
             // x3 = 0 because 0 < 0 doesn't hold (testing with immediate 0)
             Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTIU), 3, 0, 0),
@@ -420,4 +422,51 @@ mod tests {
 
         assert!(claimed_sum.is_zero());
     }
+
+    #[test]
+    fn sltiu_immediate_sign_extended() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x1 = 0xFFFF_FFFF
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 0xFFF),
+            // x2 = 0 because 0xFFFF_FFFF < 0xFFFF_F800 doesn't hold
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTIU), 2, 1, 0x800),
+            // x3 = 1 because 0 < 0xFFFF_F800
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTIU), 3, 0, 0x800),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let sltiu_steps: Vec<ProgramStep> = program_trace
+            .blocks
+            .iter()
+            .map(ProgramStep::from)
+            .filter(|step| step.step.instruction.opcode.builtin() == Some(BuiltinOpcode::SLTIU))
+            .collect();
+        for (step, expected) in sltiu_steps.iter().zip([0u32, 1]) {
+            assert_eq!(step.get_value_c(), (0xFFFF_F800u32.to_le_bytes(), 12));
+            assert_eq!(step.get_result(), Some(expected.to_le_bytes()));
+        }
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = assert_component(SLTIU, assert_ctx);
+
+        claimed_sum += components_claimed_sum(
+            &[
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &ADDI,
+                &RANGE8,
+                &RANGE16,
+                &RANGE64,
+                &RANGE256,
+            ],
+            assert_ctx,
+        );
+
+        assert!(claimed_sum.is_zero());
+    }
 }
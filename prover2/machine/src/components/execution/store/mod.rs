@@ -31,12 +31,11 @@ use nexus_vm_prover_trace::{
 
 use crate::{
     components::{
-        execution::common::{ExecutionComponent, ExecutionLookupEval},
+        execution::common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
         read_write_memory::ShiftedBaseAddr,
         utils::{
-            add_16bit_with_carry, add_with_carries,
-            constraints::{ClkIncrement, PcIncrement},
-            u32_to_16bit_parts_le,
+            add_with_carries,
+            constraints::{StepCounters, StepCountersEval},
         },
     },
     framework::BuiltInComponent,
@@ -56,8 +55,16 @@ mod decoding;
 
 mod columns;
 use columns::{Column, PreprocessedColumn};
+
 use decoding::Decoding;
 
+const STEP_COUNTERS: StepCounters<Column> = StepCounters {
+    clk: Column::Clk,
+    clk_carry: Column::ClkCarry,
+    pc: Column::Pc,
+    pc_carry: Column::PcCarry,
+};
+
 pub trait StoreOp {
     const RAM2_ACCESSED: bool;
     const RAM3_4ACCESSED: bool;
@@ -115,25 +122,13 @@ impl<T: StoreOp> Store<T> {
         let step = &program_step.step;
         assert_eq!(step.instruction.opcode.builtin(), Some(T::OPCODE));
 
-        let pc = step.pc;
-        let pc_parts = u32_to_16bit_parts_le(pc);
-        let (_pc_next, pc_carry) = add_16bit_with_carry(pc_parts, WORD_SIZE as u16);
-
-        let clk = step.timestamp;
-        let clk_parts = u32_to_16bit_parts_le(clk);
-        let (_clk_next, clk_carry) = add_16bit_with_carry(clk_parts, 1u16);
-
         let value_a = program_step.get_value_a();
         let value_b = program_step.get_value_b();
         let (value_c, _) = program_step.get_value_c();
 
         let (h_ram_base_addr, h_carry) = add_with_carries(value_a, value_c);
 
-        trace.fill_columns(row_idx, pc_parts, Column::Pc);
-        trace.fill_columns(row_idx, pc_carry, Column::PcCarry);
-
-        trace.fill_columns(row_idx, clk_parts, Column::Clk);
-        trace.fill_columns(row_idx, clk_carry, Column::ClkCarry);
+        STEP_COUNTERS.fill(trace, row_idx, step);
 
         trace.fill_columns_bytes(row_idx, &value_a, Column::AVal);
         trace.fill_columns_bytes(row_idx, &value_b, Column::BVal);
@@ -159,6 +154,8 @@ impl<T: StoreOp> Store<T> {
 }
 
 impl<T: StoreOp> BuiltInComponent for Store<T> {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -180,6 +177,10 @@ impl<T: StoreOp> BuiltInComponent for Store<T> {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_store_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_store_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -214,13 +215,6 @@ impl<T: StoreOp> BuiltInComponent for Store<T> {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        let expected_trace_len = if T::ALIGNMENT > 0 {
-            Column::COLUMNS_NUM + 1
-        } else {
-            Column::COLUMNS_NUM
-        };
-        assert_eq!(component_trace.original_trace.len(), expected_trace_len);
-
         let (
             rel_inst_to_ram,
             rel_inst_to_prog_memory,
@@ -229,7 +223,8 @@ impl<T: StoreOp> BuiltInComponent for Store<T> {
             rel_ram_write_addr,
             range_check,
         ) = Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
         let clk = original_base_column!(component_trace, Column::Clk);
@@ -324,19 +319,12 @@ impl<T: StoreOp> BuiltInComponent for Store<T> {
         let h_ram_base_addr = trace_eval!(trace_eval, Column::HRamBaseAddr);
         let h_carry = trace_eval!(trace_eval, Column::HCarry);
 
-        let clk = trace_eval!(trace_eval, Column::Clk);
-        let clk_next = ClkIncrement {
-            clk: Column::Clk,
-            clk_carry: Column::ClkCarry,
-        }
-        .eval(eval, &trace_eval);
-
-        let pc = trace_eval!(trace_eval, Column::Pc);
-        let pc_next = PcIncrement {
-            pc: Column::Pc,
-            pc_carry: Column::PcCarry,
-        }
-        .eval(eval, &trace_eval);
+        let StepCountersEval {
+            clk,
+            clk_next,
+            pc,
+            pc_next,
+        } = STEP_COUNTERS.constrain(eval, &trace_eval);
 
         // (1 − is-local-pad) · (
         //     h-ram-base-addr(1) + h-ram-base-addr(2) · 2^8
@@ -432,6 +420,8 @@ impl<T: StoreOp> BuiltInComponent for Store<T> {
                 rel_cont_prog_exec,
                 rel_inst_to_reg_memory,
             ),
+            // a-val is bound to the value of register op-a by the register memory lookup, the same
+            // columns are used for computing the ram base address.
             ExecutionLookupEval {
                 is_local_pad,
                 reg_addrs: [op_a, op_b, op_c],
@@ -524,10 +514,13 @@ pub const SW: Store<sw::Sw> = Store::new();
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nexus_common::cpu::Registers;
     use nexus_vm::{
-        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode, Register},
         trace::k_trace_direct,
     };
+    use nexus_vm_prover_trace::source::{SourceLocation, SourceMap};
+    use stwo::prover::backend::Column as _;
 
     use crate::{
         components::{
@@ -536,10 +529,16 @@ mod tests {
             RegisterMemoryBoundary, ADD, ADDI, RANGE128, RANGE16, RANGE256, RANGE64, RANGE8,
         },
         framework::{
-            test_utils::{assert_component, components_claimed_sum, AssertContext},
+            test_utils::{
+                assert_backends_agree, assert_component, assert_tampered_component,
+                components_claimed_sum, unconstrained_columns, AssertContext,
+            },
             MachineComponent,
         },
+        lookups::{record_relations, RelationLogEntry},
+        prove_with_source_map,
         verify::verify_logup_sum,
+        ProvingConfig, ProvingError, TraceGenError,
     };
 
     const BASE_TEST_COMPONENTS: &[&dyn MachineComponent] = &[
@@ -590,4 +589,364 @@ mod tests {
     fn assert_sw_constraints() {
         assert_store_constraints(SW, BuiltinOpcode::SW);
     }
+
+    #[test]
+    fn store_columns_constrained() {
+        assert!(unconstrained_columns(&SB).is_empty());
+        assert!(unconstrained_columns(&SH).is_empty());
+
+        // h-ram-base-addr-aux is appended after the common columns
+        assert!(unconstrained_columns(&SW).is_empty());
+    }
+
+    #[test]
+    fn store_relation_names() {
+        assert_eq!(
+            <Store<sw::Sw> as BuiltInComponent>::relation_names(),
+            [
+                "InstToRamLookupElements",
+                "InstToProgMemoryLookupElements",
+                "ProgramExecutionLookupElements",
+                "InstToRegisterMemoryLookupElements",
+                "RamWriteAddressLookupElements",
+                "Range8LookupElements",
+                "Range16LookupElements",
+                "Range32LookupElements",
+                "Range64LookupElements",
+                "Range128LookupElements",
+                "Range256LookupElements",
+            ]
+        );
+        assert_eq!(
+            SW.relation_names(),
+            <Store<sw::Sw> as BuiltInComponent>::relation_names()
+        );
+    }
+
+    #[test]
+    fn store_backends_agree() {
+        let mut instr = setup_ir();
+        instr.extend(
+            [BuiltinOpcode::SB, BuiltinOpcode::SH, BuiltinOpcode::SW]
+                .map(|opcode| Instruction::new_ir(Opcode::from(opcode), 2, 2, 0)),
+        );
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        assert_backends_agree(&SB, &program_trace, &view);
+        assert_backends_agree(&SH, &program_trace, &view);
+        assert_backends_agree(&SW, &program_trace, &view);
+    }
+
+    #[test]
+    fn store_ram_relation_log() {
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::SW),
+            2,
+            2,
+            0,
+        ));
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        let block = program_trace.blocks.last().unwrap();
+        let clk = block.steps[0].timestamp;
+        let addr = block.regs.read(Register::X2);
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let (_, log) = record_relations(|| assert_component(SW, assert_ctx));
+        let ram_entries: Vec<&RelationLogEntry> = log
+            .iter()
+            .filter(|entry| entry.relation == "InstToRamLookupElements")
+            .collect();
+
+        // a single word write of x2 to the address stored in x2
+        let expected_values: Vec<BaseField> = [
+            clk.to_le_bytes(),
+            addr.to_le_bytes(),
+            addr.to_le_bytes(),
+            [1, 1, 1, 1],
+        ]
+        .concat()
+        .into_iter()
+        .map(|byte| BaseField::from(byte as u32))
+        .collect();
+        assert_eq!(
+            ram_entries,
+            [&RelationLogEntry {
+                relation: "InstToRamLookupElements",
+                row: 0,
+                multiplicity: SecureField::one(),
+                values: expected_values,
+            }]
+        );
+    }
+
+    #[test]
+    fn relation_sums_balance() {
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::SW),
+            2,
+            2,
+            0,
+        ));
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let (claimed_sum, log) = record_relations(|| {
+            assert_component(SW, assert_ctx)
+                + components_claimed_sum(BASE_TEST_COMPONENTS, assert_ctx)
+        });
+        let lookup_elements = &assert_ctx.lookup_elements;
+        let sums = lookup_elements.relation_sums(&log);
+        assert_eq!(sums.values().sum::<SecureField>(), claimed_sum);
+
+        // the store consumes its write from the RAM, which provides it back
+        assert!(log.iter().any(|entry| entry.relation == "InstToRamLookupElements"));
+        assert_eq!(sums["InstToRamLookupElements"], SecureField::zero());
+
+        // only the memory boundary is left unbalanced, the verifier accounts for it separately
+        let memory_boundary =
+            PrivateMemoryBoundary::expected_logup_sum(&view, lookup_elements.as_ref());
+        for (relation, sum) in sums {
+            let expected = if relation == "RamUniqueAddrLookupElements" {
+                memory_boundary
+            } else {
+                SecureField::zero()
+            };
+            assert_eq!(sum, expected, "{relation} is unbalanced");
+        }
+    }
+
+    #[test]
+    fn store_padding_contributes_nothing() {
+        // no store steps, every row of the store traces is padding
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(setup_ir())], 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let (claimed_sums, log) = record_relations(|| {
+            [
+                assert_component(SB, assert_ctx),
+                assert_component(SH, assert_ctx),
+                assert_component(SW, assert_ctx),
+            ]
+        });
+        assert!(log.is_empty());
+        assert_eq!(claimed_sums, [SecureField::zero(); 3]);
+    }
+
+    #[test]
+    fn sh_offsets() {
+        // x2 = 0x81008, x3 = 0x201
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADDI),
+            3,
+            0,
+            0x201,
+        ));
+
+        for offset in [0, 2] {
+            let mut instr = instr.clone();
+            instr.push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::SH),
+                2,
+                3,
+                offset,
+            ));
+            let (view, program_trace) =
+                k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+            let block = program_trace.blocks.last().unwrap();
+            let clk = block.steps[0].timestamp;
+            let addr = block.regs.read(Register::X2) + offset;
+
+            let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+            let (_, log) = record_relations(|| assert_component(SH, assert_ctx));
+            let ram_entry = log
+                .into_iter()
+                .find(|entry| entry.relation == "InstToRamLookupElements")
+                .unwrap();
+
+            // low two bytes of x3 are written to addr, addr + 1
+            let expected_values: Vec<BaseField> = [
+                clk.to_le_bytes(),
+                addr.to_le_bytes(),
+                [0x01, 0x02, 0, 0],
+                [1, 1, 0, 1],
+            ]
+            .concat()
+            .into_iter()
+            .map(|byte| BaseField::from(byte as u32))
+            .collect();
+            assert_eq!(ram_entry.values, expected_values);
+        }
+
+        // the VM traps on misaligned halfword stores, they never reach the prover
+        for offset in [1, 3] {
+            let mut instr = instr.clone();
+            instr.push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::SH),
+                2,
+                3,
+                offset,
+            ));
+            assert!(k_trace_direct(&vec![BasicBlock::new(instr)], 1).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "claimed logup sum is not zero")]
+    fn a_val_register_mismatch_rejected() {
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::SW),
+            2,
+            1,
+            0,
+        ));
+        let (view, mut program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        // address the store with a base that differs from the value of x2 in register memory
+        let block = program_trace
+            .blocks
+            .iter_mut()
+            .find(|block| block.steps[0].instruction.opcode.builtin() == Some(BuiltinOpcode::SW))
+            .expect("SW step is present");
+        let base = block.regs.read(Register::X2);
+        block.regs.write(Register::X2, base + WORD_SIZE as u32);
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = assert_component(SW, assert_ctx);
+        claimed_sum += components_claimed_sum(BASE_TEST_COMPONENTS, assert_ctx);
+        verify_logup_sum(&[claimed_sum], &view, &assert_ctx.lookup_elements).unwrap();
+    }
+
+    #[test]
+    fn instr_val_matches_program_memory() {
+        let store = Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), 2, 2, 0x24);
+        let mut instr = setup_ir();
+        instr.push(store.clone());
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        let pc = program_trace.blocks.last().unwrap().steps[0].pc;
+        let instr_word = BasicBlock::new(vec![store]).encode()[0];
+        let expected_values: Vec<BaseField> = [pc, instr_word]
+            .into_iter()
+            .flat_map(|word| [word & 0xFFFF, word >> 16])
+            .map(BaseField::from)
+            .collect();
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let inst_to_prog_memory = |log: Vec<RelationLogEntry>| -> Vec<_> {
+            log.into_iter()
+                .filter(|entry| entry.relation == "InstToProgMemoryLookupElements")
+                .map(|entry| (entry.multiplicity, entry.values))
+                .collect()
+        };
+        let (_, store_log) = record_relations(|| assert_component(SW, assert_ctx));
+        let (_, prog_memory_log) = record_relations(|| assert_component(ProgramMemory, assert_ctx));
+
+        // the store consumes exactly the word the program memory provides at its pc
+        assert_eq!(
+            inst_to_prog_memory(store_log),
+            [(-SecureField::one(), expected_values.clone())]
+        );
+        assert!(inst_to_prog_memory(prog_memory_log)
+            .contains(&(SecureField::one(), expected_values)));
+    }
+
+    #[test]
+    fn instr_val_mismatch_rejected() {
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::SW),
+            2,
+            2,
+            0,
+        ));
+        let (view, mut program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        // execute a store with a different offset than the one in the program
+        let step = &mut program_trace.blocks.last_mut().unwrap().steps[0];
+        assert_eq!(step.instruction.opcode.builtin(), Some(BuiltinOpcode::SW));
+        step.raw_instruction ^= 1 << 7;
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let claimed_sum = components_claimed_sum(&[&SW], assert_ctx)
+            + components_claimed_sum(BASE_TEST_COMPONENTS, assert_ctx);
+        assert!(verify_logup_sum(&[claimed_sum], &view, &assert_ctx.lookup_elements).is_err());
+    }
+
+    #[test]
+    // the constraint evaluator reports the row of the first constraint that doesn't vanish
+    #[should_panic(expected = "row: ")]
+    fn inconsistent_base_addr_carry_rejected() {
+        let mut instr = setup_ir();
+        // x2 + (-8) carries out of every byte, only the carries at 16-bit boundaries are committed
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::SW),
+            2,
+            2,
+            0xFF8,
+        ));
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        assert_tampered_component(SW, assert_ctx, |component_trace| {
+            // flip the carry into the upper half while keeping the address bytes
+            let h_carry = &mut component_trace.original_trace[Column::HCarry.offset()];
+            h_carry.set(0, BaseField::one() - h_carry.at(0));
+        });
+    }
+
+    #[test]
+    fn misaligned_store_reports_source_line() {
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::SW),
+            2,
+            1,
+            0,
+        ));
+        let (view, mut program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        // move the base address of the store off the word boundary
+        let block = program_trace
+            .blocks
+            .iter_mut()
+            .find(|block| block.steps[0].instruction.opcode.builtin() == Some(BuiltinOpcode::SW))
+            .expect("SW step is present");
+        let base = block.regs.read(Register::X2);
+        block.regs.write(Register::X2, base + 1);
+
+        let location = SourceLocation {
+            file: "src/main.rs".to_string(),
+            line: 7,
+        };
+        let source_map: SourceMap = [(block.steps[0].pc, location.clone())]
+            .into_iter()
+            .collect();
+        let err = prove_with_source_map(&program_trace, &view, ProvingConfig::dev(), &source_map)
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            ProvingError::TraceGen(TraceGenError::MisalignedAccess {
+                address,
+                alignment: 4,
+                location: Some(l),
+                ..
+            }) if *address == base + 1 && *l == location
+        ));
+        assert!(err.to_string().contains("src/main.rs:7"));
+    }
 }
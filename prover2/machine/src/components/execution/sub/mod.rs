@@ -12,7 +12,7 @@ use stwo::{
 use stwo_constraint_framework::EvalAtRow;
 
 use nexus_vm::{riscv::BuiltinOpcode, WORD_SIZE};
-use nexus_vm_prover_air_column::{empty::EmptyPreprocessedColumn, AirColumn};
+use nexus_vm_prover_air_column::empty::EmptyPreprocessedColumn;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -24,7 +24,7 @@ use nexus_vm_prover_trace::{
 use crate::{
     components::{
         execution::{
-            common::{ExecutionComponent, ExecutionLookupEval},
+            common::{opcode_component_name, ExecutionComponent, ExecutionLookupEval},
             decoding::{
                 type_r::{self, TypeRDecoding},
                 InstructionDecoding,
@@ -124,6 +124,8 @@ impl Sub {
 }
 
 impl BuiltInComponent for Sub {
+    const NAME: &'static str = opcode_component_name(Self::OPCODE);
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -143,6 +145,10 @@ impl BuiltInComponent for Sub {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        Self::trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let num_steps = <Self as ExecutionComponent>::iter_program_steps(side_note).count();
         let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
@@ -180,13 +186,10 @@ impl BuiltInComponent for Sub {
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        assert_eq!(
-            component_trace.original_trace.len(),
-            Column::COLUMNS_NUM + type_r::DecodingColumn::COLUMNS_NUM
-        );
         let (rel_inst_to_prog_memory, rel_cont_prog_exec, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = component_trace.original_base_column(Column::IsLocalPad);
         Decoding::generate_interaction_trace(
@@ -315,7 +318,7 @@ mod tests {
     use crate::{
         components::{
             Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
-            RegisterMemoryBoundary, ADDI, RANGE16, RANGE256, RANGE64, RANGE8,
+            RegisterMemoryBoundary, ADDI, LUI, RANGE16, RANGE256, RANGE64, RANGE8,
         },
         framework::test_utils::{assert_component, components_claimed_sum, AssertContext},
     };
@@ -372,4 +375,47 @@ mod tests {
 
         assert!(claimed_sum.is_zero());
     }
+
+    #[test]
+    fn sub_borrow_across_halves() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x1 = 0x10000
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 1, 0, 0x10),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 1),
+            // x3 = 0x10000 - 1 = 0xFFFF, the low half borrows from the high half
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SUB), 3, 1, 2),
+            // x4 = 1 - 0x10000 = 0xFFFF0001, the high half borrows out of the word
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SUB), 4, 2, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let results: Vec<_> = program_trace.blocks[2..]
+            .iter()
+            .map(|block| block.steps[0].result)
+            .collect();
+        assert_eq!(results, [Some(0xFFFF), Some(0xFFFF_0001)]);
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let mut claimed_sum = assert_component(Sub, assert_ctx);
+
+        claimed_sum += components_claimed_sum(
+            &[
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &ADDI,
+                &LUI,
+                &RANGE8,
+                &RANGE16,
+                &RANGE64,
+                &RANGE256,
+            ],
+            assert_ctx,
+        );
+
+        assert!(claimed_sum.is_zero());
+    }
 }
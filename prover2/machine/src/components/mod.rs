@@ -13,6 +13,9 @@ mod execution;
 
 mod utils;
 
+#[cfg(test)]
+mod example_lookup;
+
 pub use cpu::Cpu;
 pub use cpu_boundary::CpuBoundary;
 
@@ -22,7 +25,7 @@ pub use read_write_memory_boundary::{
 };
 
 pub use register_memory::{RegisterMemory, RegisterMemorySideNote};
-pub use register_memory_boundary::RegisterMemoryBoundary;
+pub use register_memory_boundary::{RegisterMemoryBoundary, RegisterSnapshot};
 
 pub use program_memory::{ProgramMemory, ProgramMemorySideNote};
 pub use program_memory_boundary::ProgramMemoryBoundary;
@@ -31,16 +34,22 @@ pub use range_multiplicity::{RANGE128, RANGE16, RANGE256, RANGE32, RANGE64, RANG
 
 pub use execution::add::{ADD, ADDI};
 pub use execution::auipc::AUIPC;
-pub use execution::bitwise::{BitwiseMultiplicities, AND, ANDI, OR, ORI, XOR, XORI};
+pub use execution::bitwise::{
+    BitwiseMultiplicities, AND, ANDI, AND_LOOKUP_IDX, OR, ORI, OR_LOOKUP_IDX, XOR, XORI,
+    XOR_LOOKUP_IDX,
+};
 pub use execution::bitwise_multiplicity::BitwiseMultiplicity;
 pub use execution::branch_cmp_signed::{BGE, BLT};
 pub use execution::branch_cmp_unsigned::{BGEU, BLTU};
 pub use execution::branch_eq::{BEQ, BNE};
+pub use execution::div::{DIV, DIVU, REM, REMU};
 pub use execution::ecall::ECALL;
 pub use execution::jal::JAL;
 pub use execution::jalr::JALR;
 pub use execution::load::{LB, LBU, LH, LHU, LW};
 pub use execution::lui::LUI;
+pub use execution::mul::{MUL, MULH, MULHSU, MULHU};
+pub use execution::product_multiplicity::ProductMultiplicity;
 pub use execution::sll::{SLL, SLLI};
 pub use execution::slt::{SLT, SLTI};
 pub use execution::sltu::{SLTIU, SLTU};
@@ -48,3 +57,6 @@ pub use execution::sra::{SRA, SRAI};
 pub use execution::srl::{SRL, SRLI};
 pub use execution::store::{SB, SH, SW};
 pub use execution::sub::SUB;
+
+#[cfg(test)]
+pub use example_lookup::OpcodeLookupElements;
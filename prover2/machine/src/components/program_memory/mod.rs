@@ -34,6 +34,8 @@ pub use trace::ProgramMemorySideNote;
 pub struct ProgramMemory;
 
 impl BuiltInComponent for ProgramMemory {
+    const NAME: &'static str = "ProgramMemory";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -52,6 +54,10 @@ impl BuiltInComponent for ProgramMemory {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        trace::main_trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         trace::generate_main_trace(side_note)
     }
@@ -59,7 +65,7 @@ impl BuiltInComponent for ProgramMemory {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
@@ -67,7 +73,8 @@ impl BuiltInComponent for ProgramMemory {
     ) {
         let (rel_prog_memory_read, rel_inst_to_prog_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
         let pc = original_base_column!(component_trace, Column::Pc);
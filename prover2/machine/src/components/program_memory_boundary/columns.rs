@@ -13,6 +13,9 @@ pub enum PreprocessedColumn {
     /// A flag indicating whether prog-val-init and prog-ctr-final columns on the current row are being used
     #[size = 1]
     ProgInitFlag,
+    /// The cost weight of the instruction prog-val-init, zero if it's not supported by the prover
+    #[size = 1]
+    ProgCost,
 }
 
 #[derive(Debug, Copy, Clone, AirColumn)]
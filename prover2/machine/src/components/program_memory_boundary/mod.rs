@@ -1,9 +1,9 @@
 //! Helper component needed to eliminate boundary logup terms in the read-write memory component.
 
-use num_traits::Zero;
+use num_traits::{One, Zero};
 use stwo::{
     core::{
-        fields::{m31::BaseField, qm31::SecureField},
+        fields::{m31::BaseField, qm31::SecureField, FieldExpOps},
         ColumnVec,
     },
     prover::{
@@ -11,7 +11,7 @@ use stwo::{
         poly::{circle::CircleEvaluation, BitReversedOrder},
     },
 };
-use stwo_constraint_framework::{EvalAtRow, RelationEntry};
+use stwo_constraint_framework::{EvalAtRow, Relation, RelationEntry};
 
 use nexus_vm::{emulator::ProgramMemoryEntry, WORD_SIZE};
 use nexus_vm_prover_trace::{
@@ -23,8 +23,12 @@ use nexus_vm_prover_trace::{
 
 use crate::{
     components::utils::u32_to_16bit_parts_le,
+    cost::{instruction_cost, side_note_cost},
     framework::BuiltInComponent,
-    lookups::{AllLookupElements, LogupTraceBuilder, ProgramMemoryReadLookupElements},
+    lookups::{
+        AllLookupElements, ComponentLookupElements, LogupTraceBuilder, ProgramCostLookupElements,
+        ProgramMemoryReadLookupElements,
+    },
     side_note::{program::ProgramTraceRef, SideNote},
 };
 
@@ -33,12 +37,31 @@ use columns::{Column, PreprocessedColumn};
 
 pub struct ProgramMemoryBoundary;
 
+impl ProgramMemoryBoundary {
+    /// Returns the logup sum of the cost fractions provided by the component, which the verifier
+    /// consumes for the claimed total cost.
+    pub fn cost_logup_sum(cost: u32, lookup_elements: &ProgramCostLookupElements) -> SecureField {
+        let denom: SecureField = lookup_elements.combine(&[BaseField::one()]);
+        SecureField::from(BaseField::from(cost)) * denom.inverse()
+    }
+}
+
 impl BuiltInComponent for ProgramMemoryBoundary {
+    const NAME: &'static str = "ProgramMemoryBoundary";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
 
-    type LookupElements = ProgramMemoryReadLookupElements;
+    type LookupElements = (ProgramMemoryReadLookupElements, ProgramCostLookupElements);
+
+    fn public_logup_sum(
+        &self,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> SecureField {
+        Self::cost_logup_sum(side_note_cost(side_note), lookup_elements.as_ref())
+    }
 
     fn generate_preprocessed_trace(
         &self,
@@ -65,13 +88,20 @@ impl BuiltInComponent for ProgramMemoryBoundary {
         {
             let pc_parts = u32_to_16bit_parts_le(*pc);
             let instr_parts = u32_to_16bit_parts_le(*instruction_word);
+            let cost = instruction_cost(*instruction_word).unwrap_or_default();
             trace.fill_columns(row_idx, pc_parts, PreprocessedColumn::ProgInitBaseAddr);
             trace.fill_columns(row_idx, instr_parts, PreprocessedColumn::ProgValInit);
             trace.fill_columns(row_idx, true, PreprocessedColumn::ProgInitFlag);
+            trace.fill_columns(row_idx, BaseField::from(cost), PreprocessedColumn::ProgCost);
         }
         trace.finalize()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        let program_len = side_note.program.program_memory.program.len();
+        program_len.next_power_of_two().ilog2().max(LOG_N_LANES)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let program_ref = &side_note.program;
         let program_memory = &program_ref.program_memory.program;
@@ -97,14 +127,15 @@ impl BuiltInComponent for ProgramMemoryBoundary {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        let rel_prog_memory_read: &Self::LookupElements = lookup_elements.as_ref();
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let (rel_prog_memory_read, rel_prog_cost) = Self::LookupElements::get(lookup_elements);
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [prog_init_flag] =
             preprocessed_base_column!(component_trace, PreprocessedColumn::ProgInitFlag);
@@ -112,12 +143,13 @@ impl BuiltInComponent for ProgramMemoryBoundary {
             preprocessed_base_column!(component_trace, PreprocessedColumn::ProgInitBaseAddr);
         let prog_val_init =
             preprocessed_base_column!(component_trace, PreprocessedColumn::ProgValInit);
+        let [prog_cost] = preprocessed_base_column!(component_trace, PreprocessedColumn::ProgCost);
 
         let prog_ctr_final = original_base_column!(component_trace, Column::ProgCtrFinal);
 
         // consume(rel-prog-memory-read, prog-init-flag, (prog-init-base-addr, prog-val-init, prog-ctr-final))
         logup_trace_builder.add_to_relation_with(
-            rel_prog_memory_read,
+            &rel_prog_memory_read,
             [prog_init_flag.clone()],
             |[prog_init_flag]| (-prog_init_flag).into(),
             &[
@@ -130,8 +162,8 @@ impl BuiltInComponent for ProgramMemoryBoundary {
 
         // provide(rel-prog-memory-read, prog-init-flag, (prog-init-base-addr, prog-val-init, 0))
         logup_trace_builder.add_to_relation(
-            rel_prog_memory_read,
-            prog_init_flag,
+            &rel_prog_memory_read,
+            prog_init_flag.clone(),
             &[
                 prog_init_base_addr.as_slice(),
                 &prog_val_init,
@@ -139,6 +171,25 @@ impl BuiltInComponent for ProgramMemoryBoundary {
             ]
             .concat(),
         );
+
+        // provide(rel-prog-cost, prog-cost · prog-ctr-final, (prog-init-flag))
+        //
+        // the final counter is the number of executions of the instruction, its bytes are combined
+        // into a single field element
+        let [ctr_0, ctr_1, ctr_2, ctr_3] = prog_ctr_final;
+        logup_trace_builder.add_to_relation_with(
+            &rel_prog_cost,
+            [prog_cost, ctr_0, ctr_1, ctr_2, ctr_3],
+            |[prog_cost, ctr_0, ctr_1, ctr_2, ctr_3]| {
+                (prog_cost
+                    * (ctr_0
+                        + ctr_1 * BaseField::from(1 << 8)
+                        + ctr_2 * BaseField::from(1 << 16)
+                        + ctr_3 * BaseField::from(1 << 24)))
+                    .into()
+            },
+            &[prog_init_flag],
+        );
         logup_trace_builder.finalize()
     }
 
@@ -153,10 +204,11 @@ impl BuiltInComponent for ProgramMemoryBoundary {
         let prog_init_base_addr =
             preprocessed_trace_eval!(trace_eval, PreprocessedColumn::ProgInitBaseAddr);
         let prog_val_init = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::ProgValInit);
+        let [prog_cost] = preprocessed_trace_eval!(trace_eval, PreprocessedColumn::ProgCost);
 
         let prog_ctr_final = trace_eval!(trace_eval, Column::ProgCtrFinal);
 
-        let rel_prog_memory_read = lookup_elements;
+        let (rel_prog_memory_read, rel_prog_cost) = lookup_elements;
 
         // consume(rel-prog-memory-read, prog-init-flag, (prog-init-base-addr, prog-val-init, prog-ctr-final))
         eval.add_to_relation(RelationEntry::new(
@@ -172,7 +224,7 @@ impl BuiltInComponent for ProgramMemoryBoundary {
         // provide(rel-prog-memory-read, prog-init-flag, (prog-init-base-addr, prog-val-init, 0))
         eval.add_to_relation(RelationEntry::new(
             rel_prog_memory_read,
-            prog_init_flag.into(),
+            prog_init_flag.clone().into(),
             &[
                 prog_init_base_addr.as_slice(),
                 &prog_val_init,
@@ -180,6 +232,19 @@ impl BuiltInComponent for ProgramMemoryBoundary {
             ]
             .concat(),
         ));
+        // provide(rel-prog-cost, prog-cost · prog-ctr-final, (prog-init-flag))
+        //
+        // the numerator has degree 2, the fraction is placed last to be batched on its own
+        let [ctr_0, ctr_1, ctr_2, ctr_3] = prog_ctr_final;
+        let executions = ctr_0
+            + ctr_1 * BaseField::from(1 << 8)
+            + ctr_2 * BaseField::from(1 << 16)
+            + ctr_3 * BaseField::from(1 << 24);
+        eval.add_to_relation(RelationEntry::new(
+            rel_prog_cost,
+            (prog_cost * executions).into(),
+            &[prog_init_flag],
+        ));
         eval.finalize_logup_in_pairs();
     }
 }
@@ -1,4 +1,8 @@
 //! Components that subtract final range check multiplicities.
+//!
+//! Range checks are deferred: other components only add looked up values to the shared
+//! [`RangeCheckAccumulator`] in the side note, and each table is generated once from the combined
+//! multiplicities, which requires range components to be placed after all their users.
 
 use std::{collections::BTreeMap, marker::PhantomData};
 
@@ -26,12 +30,13 @@ use stwo_constraint_framework::{
 use nexus_vm_prover_trace::component::ComponentTrace;
 
 use crate::{
-    framework::MachineComponent,
+    framework::{check_constraints, ConstraintViolation, MachineComponent},
     lookups::{
         AllLookupElements, ComponentLookupElements, LogupTraceBuilder, Range128LookupElements,
         Range16LookupElements, Range32LookupElements, Range64LookupElements, Range8LookupElements,
         RegisteredLookupBound,
     },
+    report::ExpressionBound,
     side_note::{program::ProgramTraceRef, range_check::RangeCheckAccumulator, SideNote},
 };
 
@@ -48,6 +53,17 @@ impl<const LOG_SIZE: u32, R> MachineComponent for RangeMultiplicity<LOG_SIZE, R>
 where
     R: RegisteredLookupBound,
 {
+    fn ordering_key(&self) -> &'static str {
+        match LOG_SIZE {
+            3 => "RANGE8",
+            4 => "RANGE16",
+            5 => "RANGE32",
+            6 => "RANGE64",
+            7 => "RANGE128",
+            _ => panic!("unsupported range multiplicity size"),
+        }
+    }
+
     fn max_constraint_log_degree_bound(&self, _log_size: u32) -> u32 {
         Self::log_size() + 1
     }
@@ -66,6 +82,18 @@ where
         vec![log_size]
     }
 
+    fn n_constraints(&self) -> usize {
+        RangeMultiplicityEval::<LOG_SIZE, R> {
+            lookup_elements: R::dummy(),
+        }
+        .evaluate(InfoEvaluator::empty())
+        .n_constraints
+    }
+
+    fn relation_names(&self) -> Vec<&'static str> {
+        <R as ComponentLookupElements>::relation_names()
+    }
+
     fn draw_lookup_elements(
         &self,
         lookup_elements: &mut AllLookupElements,
@@ -87,6 +115,10 @@ where
             .collect()
     }
 
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::log_size()
+    }
+
     fn generate_component_trace(&self, side_note: &mut SideNote) -> ComponentTrace {
         let preprocessed_trace = Self::preprocessed_trace_columns();
         let original_trace = Self::original_trace_columns(&side_note.range_check);
@@ -100,13 +132,14 @@ where
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
         let lookup_elements: &R = lookup_elements.as_ref();
         let values = &component_trace.preprocessed_trace[0];
         let mult = &component_trace.original_trace[0];
@@ -121,6 +154,28 @@ where
         logup_trace_builder.finalize()
     }
 
+    fn expression_bounds(&self, _component_trace: &ComponentTrace) -> Vec<ExpressionBound> {
+        // multiplicities are only constrained by the logup sum
+        Vec::new()
+    }
+
+    fn check_constraints(
+        &self,
+        component_trace: &ComponentTrace,
+        interaction_trace: ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        lookup_elements: &AllLookupElements,
+        claimed_sum: SecureField,
+    ) -> Result<(), ConstraintViolation> {
+        check_constraints(
+            &RangeMultiplicityEval::<LOG_SIZE, R> {
+                lookup_elements: R::get(lookup_elements),
+            },
+            component_trace,
+            interaction_trace,
+            claimed_sum,
+        )
+    }
+
     fn to_component_prover<'a>(
         &'a self,
         tree_span_provider: &mut TraceLocationAllocator,
@@ -231,3 +286,93 @@ pub const RANGE32: RangeMultiplicity<5, Range32LookupElements> = RangeMultiplici
 pub const RANGE64: RangeMultiplicity<6, Range64LookupElements> = RangeMultiplicity::new();
 pub const RANGE128: RangeMultiplicity<7, Range128LookupElements> = RangeMultiplicity::new();
 pub use range256::RANGE256;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    use crate::{
+        components::{
+            execution::load::tests::setup_ir, Cpu, CpuBoundary, PrivateMemoryBoundary,
+            ProgramMemory, ProgramMemoryBoundary, ReadWriteMemory, RegisterMemory,
+            RegisterMemoryBoundary, ADD, ADDI, SLTU, SW,
+        },
+        framework::{
+            test_utils::{assert_component, components_claimed_sum, AssertContext},
+            BuiltInComponent,
+        },
+        verify::verify_logup_sum,
+    };
+
+    fn num_lookups(accum: &RangeCheckAccumulator) -> u32 {
+        let RangeCheckAccumulator {
+            range8,
+            range16,
+            range32,
+            range64,
+            range128,
+            range256,
+        } = accum;
+        range8.values().sum::<u32>()
+            + range16.values().sum::<u32>()
+            + range32.values().sum::<u32>()
+            + range64.values().sum::<u32>()
+            + range128.values().sum::<u32>()
+            + range256.values().sum::<u32>()
+    }
+
+    /// Asserts the component and checks that it deferred range checks into the side note.
+    fn assert_deferred<C>(component: C, assert_ctx: &mut AssertContext) -> SecureField
+    where
+        C: BuiltInComponent + 'static + Sync,
+        C::LookupElements: 'static + Sync,
+    {
+        let num_lookups_before = num_lookups(&assert_ctx.side_note.range_check);
+        let claimed_sum = assert_component(component, assert_ctx);
+        assert!(num_lookups(&assert_ctx.side_note.range_check) > num_lookups_before);
+        claimed_sum
+    }
+
+    #[test]
+    fn range_checks_batched_across_components() {
+        let mut instr = setup_ir();
+        instr.extend([
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 3, 1, 2),
+        ]);
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+
+        // components only accumulate multiplicities, no range table is built yet
+        let mut claimed_sum = assert_deferred(ADD, assert_ctx);
+        claimed_sum += assert_deferred(SW, assert_ctx);
+        claimed_sum += assert_deferred(SLTU, assert_ctx);
+        claimed_sum += components_claimed_sum(
+            &[
+                &Cpu,
+                &CpuBoundary,
+                &RegisterMemory,
+                &RegisterMemoryBoundary,
+                &ProgramMemory,
+                &ProgramMemoryBoundary,
+                &ReadWriteMemory,
+                &PrivateMemoryBoundary,
+                &ADDI,
+            ],
+            assert_ctx,
+        );
+
+        // a single pass over the combined multiplicities balances every lookup
+        claimed_sum += components_claimed_sum(
+            &[&RANGE8, &RANGE16, &RANGE32, &RANGE64, &RANGE128, &RANGE256],
+            assert_ctx,
+        );
+        verify_logup_sum(&[claimed_sum], &view, &assert_ctx.lookup_elements).unwrap();
+    }
+}
@@ -27,10 +27,11 @@ use stwo_constraint_framework::{
 use nexus_vm_prover_trace::component::ComponentTrace;
 
 use crate::{
-    framework::MachineComponent,
+    framework::{check_constraints, ConstraintViolation, MachineComponent},
     lookups::{
         AllLookupElements, ComponentLookupElements, LogupTraceBuilder, Range256LookupElements,
     },
+    report::ExpressionBound,
     side_note::{program::ProgramTraceRef, SideNote},
 };
 
@@ -39,6 +40,10 @@ pub const RANGE256: Range256Multiplicity = Range256Multiplicity;
 pub struct Range256Multiplicity;
 
 impl MachineComponent for Range256Multiplicity {
+    fn ordering_key(&self) -> &'static str {
+        "RANGE256"
+    }
+
     fn max_constraint_log_degree_bound(&self, _log_size: u32) -> u32 {
         Self::log_size() + 1
     }
@@ -57,6 +62,18 @@ impl MachineComponent for Range256Multiplicity {
         vec![log_size]
     }
 
+    fn n_constraints(&self) -> usize {
+        RangeMultiplicityEval {
+            lookup_elements: Range256LookupElements::dummy(),
+        }
+        .evaluate(InfoEvaluator::empty())
+        .n_constraints
+    }
+
+    fn relation_names(&self) -> Vec<&'static str> {
+        <Range256LookupElements as ComponentLookupElements>::relation_names()
+    }
+
     fn draw_lookup_elements(
         &self,
         lookup_elements: &mut AllLookupElements,
@@ -78,6 +95,10 @@ impl MachineComponent for Range256Multiplicity {
             .collect()
     }
 
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::log_size()
+    }
+
     fn generate_component_trace(&self, side_note: &mut SideNote) -> ComponentTrace {
         let preprocessed_trace = Self::preprocessed_trace_columns();
         let original_trace = Self::original_trace_columns(side_note);
@@ -91,13 +112,14 @@ impl MachineComponent for Range256Multiplicity {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
         let lookup_elements: &Range256LookupElements = lookup_elements.as_ref();
 
         let b = &component_trace.preprocessed_trace[0];
@@ -117,6 +139,28 @@ impl MachineComponent for Range256Multiplicity {
         logup_trace_builder.finalize()
     }
 
+    fn expression_bounds(&self, _component_trace: &ComponentTrace) -> Vec<ExpressionBound> {
+        // multiplicities are only constrained by the logup sum
+        Vec::new()
+    }
+
+    fn check_constraints(
+        &self,
+        component_trace: &ComponentTrace,
+        interaction_trace: ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        lookup_elements: &AllLookupElements,
+        claimed_sum: SecureField,
+    ) -> Result<(), ConstraintViolation> {
+        check_constraints(
+            &RangeMultiplicityEval {
+                lookup_elements: Range256LookupElements::get(lookup_elements),
+            },
+            component_trace,
+            interaction_trace,
+            claimed_sum,
+        )
+    }
+
     fn to_component_prover<'a>(
         &'a self,
         tree_span_provider: &mut TraceLocationAllocator,
@@ -38,9 +38,13 @@ use columns::{Column, PreprocessedColumn};
 pub use columns::ShiftedBaseAddr;
 pub use trace::ReadWriteMemorySideNote;
 
+pub(crate) use trace::accessed_addresses;
+
 pub struct ReadWriteMemory;
 
 impl BuiltInComponent for ReadWriteMemory {
+    const NAME: &'static str = "ReadWriteMemory";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -59,6 +63,10 @@ impl BuiltInComponent for ReadWriteMemory {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        trace::main_trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         trace::generate_main_trace(side_note)
     }
@@ -66,7 +74,7 @@ impl BuiltInComponent for ReadWriteMemory {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
@@ -74,7 +82,8 @@ impl BuiltInComponent for ReadWriteMemory {
     ) {
         let (rel_ram_read_write, rel_inst_to_ram, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
         let clk = original_base_column!(component_trace, Column::Clk);
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use stwo::prover::backend::simd::m31::LOG_N_LANES;
 
 use nexus_vm::{emulator::MemoryInitializationEntry, riscv::BuiltinOpcode, WORD_SIZE};
@@ -60,6 +60,33 @@ fn iter_program_steps<'a>(side_note: &SideNote<'a>) -> impl Iterator<Item = Prog
     })
 }
 
+/// Returns the addresses of initialized and accessed memory bytes, these are the keys of the final
+/// [`ReadWriteMemorySideNote::last_access`].
+pub fn accessed_addresses(side_note: &SideNote) -> BTreeSet<u32> {
+    let program = side_note.program;
+    let initialized = program
+        .public_input
+        .iter()
+        .chain(program.static_memory)
+        .chain(program.ro_memory)
+        .map(|entry| entry.address);
+    let accessed = iter_program_steps(side_note)
+        .flat_map(|program_step| &program_step.step.memory_records)
+        .flat_map(|record| {
+            let address = record.get_address();
+            address..address + record.get_size() as u32
+        });
+    initialized.chain(accessed).collect()
+}
+
+pub fn main_trace_log_size(side_note: &SideNote) -> u32 {
+    let num_memory_steps = iter_program_steps(side_note).count();
+    num_memory_steps
+        .next_power_of_two()
+        .ilog2()
+        .max(LOG_N_LANES)
+}
+
 pub fn generate_main_trace(side_note: &mut SideNote) -> FinalizedTrace {
     let program = side_note.program;
     let mut rw_memory_side_note = ReadWriteMemorySideNote::new(
@@ -71,10 +98,7 @@ pub fn generate_main_trace(side_note: &mut SideNote) -> FinalizedTrace {
     let mut write_access = AddressAccessSideNote::default();
 
     let num_memory_steps = iter_program_steps(side_note).count();
-    let log_size = num_memory_steps
-        .next_power_of_two()
-        .ilog2()
-        .max(LOG_N_LANES);
+    let log_size = main_trace_log_size(side_note);
 
     let mut range_check_mults = Range256Multiplicities::default();
     let mut trace = TraceBuilder::new(log_size);
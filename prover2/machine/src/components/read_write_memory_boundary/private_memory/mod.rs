@@ -18,7 +18,7 @@ use stwo::{
 };
 use stwo_constraint_framework::{EvalAtRow, Relation, RelationEntry};
 
-use nexus_common::constants::WORD_SIZE_HALVED;
+use nexus_vm::emulator::View;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
     component::ComponentTrace,
@@ -27,14 +27,21 @@ use nexus_vm_prover_trace::{
 };
 
 use crate::{
-    components::utils::{subtract_with_borrow, u32_to_16bit_parts_le},
+    components::{
+        read_write_memory,
+        utils::{boundary::BoundaryGadget, subtract_with_borrow, u32_to_16bit_parts_le},
+    },
     framework::BuiltInComponent,
     lookups::{
         AllLookupElements, ComponentLookupElements, LogupTraceBuilder,
         RamReadAddressLookupElements, RamReadWriteLookupElements, RamUniqueAddrLookupElements,
         RamWriteAddressLookupElements, RangeCheckLookupElements,
     },
-    side_note::{program::ProgramTraceRef, range_check::Range256Multiplicities, SideNote},
+    side_note::{
+        program::{private_memory_bounds, ProgramTraceRef},
+        range_check::Range256Multiplicities,
+        SideNote,
+    },
 };
 
 mod columns;
@@ -44,11 +51,10 @@ pub struct PrivateMemoryBoundary;
 
 impl PrivateMemoryBoundary {
     pub fn expected_logup_sum(
-        program: &ProgramTraceRef,
+        view: &View,
         lookup_elements: &RamUniqueAddrLookupElements,
     ) -> SecureField {
-        let memory_start = program.private_memory_start;
-        let memory_end = program.private_memory_end;
+        let (memory_start, memory_end) = private_memory_bounds(view);
         if memory_start == memory_end {
             SecureField::zero()
         } else {
@@ -72,6 +78,8 @@ impl PrivateMemoryBoundary {
 }
 
 impl BuiltInComponent for PrivateMemoryBoundary {
+    const NAME: &'static str = "PrivateMemoryBoundary";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -92,6 +100,18 @@ impl BuiltInComponent for PrivateMemoryBoundary {
         FinalizedTrace::empty()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        let memory_start = side_note.program.private_memory_start;
+        let memory_end = side_note.program.private_memory_end;
+        if memory_start == memory_end {
+            return LOG_N_LANES;
+        }
+        let len = read_write_memory::accessed_addresses(side_note)
+            .range(memory_start..)
+            .count();
+        len.next_power_of_two().ilog2().max(LOG_N_LANES)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let memory_start = side_note.program.private_memory_start;
         let memory_end = side_note.program.private_memory_end;
@@ -170,7 +190,7 @@ impl BuiltInComponent for PrivateMemoryBoundary {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
@@ -184,7 +204,7 @@ impl BuiltInComponent for PrivateMemoryBoundary {
             range_check,
         ) = Self::LookupElements::get(lookup_elements);
         let log_size = component_trace.log_size();
-        let mut logup_trace_builder = LogupTraceBuilder::new(log_size);
+        let mut logup_trace_builder = LogupTraceBuilder::new(log_size, side_note.logup_backend);
 
         let [is_pad] = original_base_column!(component_trace, Column::IsPad);
         let addr = original_base_column!(component_trace, Column::CurrAddress);
@@ -206,29 +226,16 @@ impl BuiltInComponent for PrivateMemoryBoundary {
             .generate_logup_col(&mut logup_trace_builder, is_pad.clone(), &diff);
 
         // consume(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-final, ram-ts-final))
-        logup_trace_builder.add_to_relation_with(
-            &rel_ram_read_write,
-            [is_pad.clone()],
-            |[is_pad]| (is_pad - PackedBaseField::one()).into(),
-            &[
-                addr.as_slice(),
-                std::slice::from_ref(&ram_val_final),
-                &ram_ts_final,
-            ]
-            .concat(),
-        );
-
         // provide(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-init, 0))
-        logup_trace_builder.add_to_relation_with(
+        BoundaryGadget::generate_interaction_trace(
+            &mut logup_trace_builder,
             &rel_ram_read_write,
             [is_pad.clone()],
-            |[is_pad]| (PackedBaseField::one() - is_pad).into(),
-            &[
-                addr.as_slice(),
-                std::slice::from_ref(&ram_val_init),
-                vec![BaseField::zero().into(); WORD_SIZE_HALVED].as_slice(),
-            ]
-            .concat(),
+            |[is_pad]| PackedBaseField::one() - is_pad,
+            &addr,
+            std::slice::from_ref(&ram_val_init),
+            std::slice::from_ref(&ram_val_final),
+            &ram_ts_final,
         );
 
         // consume(rel-ram-unique-addr, ram-init-final-flag, ram-init-final-addr)
@@ -333,27 +340,16 @@ impl BuiltInComponent for PrivateMemoryBoundary {
         range_check.range256.constrain(eval, is_pad.clone(), &diff);
 
         // consume(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-final, ram-ts-final))
-        eval.add_to_relation(RelationEntry::new(
-            rel_ram_read_write,
-            (is_pad.clone() - E::F::one()).into(),
-            &[
-                curr_addr.as_slice(),
-                std::slice::from_ref(&ram_val_final),
-                &ram_ts_final,
-            ]
-            .concat(),
-        ));
         // provide(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-init, 0))
-        eval.add_to_relation(RelationEntry::new(
+        BoundaryGadget::constrain(
+            eval,
             rel_ram_read_write,
-            (E::F::one() - is_pad.clone()).into(),
-            &[
-                curr_addr.as_slice(),
-                std::slice::from_ref(&ram_val_init),
-                vec![E::F::zero(); WORD_SIZE_HALVED].as_slice(),
-            ]
-            .concat(),
-        ));
+            E::F::one() - is_pad.clone(),
+            &curr_addr,
+            std::slice::from_ref(&ram_val_init),
+            std::slice::from_ref(&ram_val_final),
+            &ram_ts_final,
+        );
 
         // consume(rel-ram-unique-addr, ram-init-final-flag, ram-init-final-addr)
         eval.add_to_relation(RelationEntry::new(
@@ -18,20 +18,19 @@ use stwo::{
 };
 use stwo_constraint_framework::{EvalAtRow, RelationEntry};
 
-use nexus_common::constants::WORD_SIZE_HALVED;
 use nexus_vm::{
     emulator::{MemoryInitializationEntry, PublicOutputEntry},
     WORD_SIZE,
 };
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
-    component::ComponentTrace,
+    component::{ComponentTrace, FinalizedColumn},
     eval::TraceEval,
     original_base_column, preprocessed_base_column, preprocessed_trace_eval, trace_eval,
 };
 
 use crate::{
-    components::utils::u32_to_16bit_parts_le,
+    components::utils::{boundary::BoundaryGadget, u32_to_16bit_parts_le},
     framework::BuiltInComponent,
     lookups::{
         AllLookupElements, ComponentLookupElements, LogupTraceBuilder,
@@ -62,23 +61,6 @@ impl PubMemoryBoundary {
             .chain(wo_iter.map(|entry| entry.address))
     }
 
-    fn generate_init_final_flag_column(side_note: &SideNote, log_size: u32) -> BaseColumn {
-        let program_ref = &side_note.program;
-
-        let ro_memory = program_ref.ro_memory;
-        let public_input = program_ref.public_input;
-        let exit_code = program_ref.exit_code;
-        let public_output = program_ref.public_output;
-
-        let len = ro_memory.len() + public_input.len() + exit_code.len() + public_output.len();
-        assert!(1 << log_size >= len as u32);
-
-        std::iter::repeat_n(BaseField::one(), len)
-            .chain(std::iter::repeat(BaseField::zero()))
-            .take(1 << log_size)
-            .collect()
-    }
-
     fn generate_ram_val_final_column(side_note: &SideNote, log_size: u32) -> BaseColumn {
         let program_ref = &side_note.program;
 
@@ -102,6 +84,8 @@ impl PubMemoryBoundary {
 }
 
 impl BuiltInComponent for PubMemoryBoundary {
+    const NAME: &'static str = "PubMemoryBoundary";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -156,6 +140,15 @@ impl BuiltInComponent for PubMemoryBoundary {
         trace.finalize()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        let program_ref = &side_note.program;
+        let len = program_ref.ro_memory.len()
+            + program_ref.public_input.len()
+            + program_ref.exit_code.len()
+            + program_ref.public_output.len();
+        len.next_power_of_two().ilog2().max(LOG_N_LANES)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let program_ref = &side_note.program;
 
@@ -210,13 +203,15 @@ impl BuiltInComponent for PubMemoryBoundary {
         let (rel_ram_read_write, rel_ram_read_addr, rel_ram_write_addr) =
             Self::LookupElements::get(lookup_elements);
         let log_size = component_trace.log_size();
-        let mut logup_trace_builder = LogupTraceBuilder::new(log_size);
+        let mut logup_trace_builder = LogupTraceBuilder::new(log_size, side_note.logup_backend);
 
-        let ram_init_final_flag: BaseColumn =
-            Self::generate_init_final_flag_column(side_note, log_size);
         let ram_val_final = Self::generate_ram_val_final_column(side_note, log_size);
 
         let ram_ts_final = original_base_column!(component_trace, Column::RamTsFinal);
+        let [pub_in_flag] =
+            preprocessed_base_column!(component_trace, PreprocessedColumn::PubInFlag);
+        let [pub_out_flag] =
+            preprocessed_base_column!(component_trace, PreprocessedColumn::PubOutFlag);
         let [ram_val_init] =
             preprocessed_base_column!(component_trace, PreprocessedColumn::PubInVal);
         let ram_init_final_addr =
@@ -225,28 +220,19 @@ impl BuiltInComponent for PubMemoryBoundary {
         let [read_mult] = original_base_column!(component_trace, Column::MultiplicityRead);
         let [write_mult] = original_base_column!(component_trace, Column::MultiplicityWrite);
 
+        // ram-init-final-flag = pub-in-flag + pub-out-flag
+        //
         // consume(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-final, ram-ts-final))
-        logup_trace_builder.add_to_relation_with(
-            &rel_ram_read_write,
-            [(&ram_init_final_flag).into()],
-            |[ram_init_final_flag]| (-ram_init_final_flag).into(),
-            &[
-                ram_init_final_addr.as_slice(),
-                &[(&ram_val_final).into()],
-                &ram_ts_final,
-            ]
-            .concat(),
-        );
         // provide(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-init, 0))
-        logup_trace_builder.add_to_relation(
+        BoundaryGadget::generate_interaction_trace(
+            &mut logup_trace_builder,
             &rel_ram_read_write,
-            &ram_init_final_flag,
-            &[
-                ram_init_final_addr.as_slice(),
-                std::slice::from_ref(&ram_val_init),
-                vec![BaseField::zero().into(); WORD_SIZE_HALVED].as_slice(),
-            ]
-            .concat(),
+            [pub_in_flag, pub_out_flag],
+            |[pub_in_flag, pub_out_flag]| pub_in_flag + pub_out_flag,
+            &ram_init_final_addr,
+            std::slice::from_ref(&ram_val_init),
+            &[FinalizedColumn::from(&ram_val_final)],
+            &ram_ts_final,
         );
 
         // consume(rel-ram-read-addr, read-mult, ram-init-final-addr)
@@ -304,27 +290,16 @@ impl BuiltInComponent for PubMemoryBoundary {
         let ram_init_final_flag = pub_in_flag + pub_out_flag;
 
         // consume(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-final, ram-ts-final))
-        eval.add_to_relation(RelationEntry::new(
-            rel_ram_read_write,
-            (-ram_init_final_flag.clone()).into(),
-            &[
-                pub_io_addr.as_slice(),
-                std::slice::from_ref(&ram_val_final),
-                &ram_ts_final,
-            ]
-            .concat(),
-        ));
         // provide(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-init, 0))
-        eval.add_to_relation(RelationEntry::new(
+        BoundaryGadget::constrain(
+            eval,
             rel_ram_read_write,
-            ram_init_final_flag.into(),
-            &[
-                pub_io_addr.as_slice(),
-                std::slice::from_ref(&ram_val_init),
-                vec![E::F::zero(); WORD_SIZE_HALVED].as_slice(),
-            ]
-            .concat(),
-        ));
+            ram_init_final_flag,
+            &pub_io_addr,
+            std::slice::from_ref(&ram_val_init),
+            std::slice::from_ref(&ram_val_final),
+            &ram_ts_final,
+        );
 
         // consume(rel-ram-read-addr, read-mult, ram-init-final-addr)
         eval.add_to_relation(RelationEntry::new(
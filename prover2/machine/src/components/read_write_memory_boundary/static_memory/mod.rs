@@ -2,7 +2,7 @@
 
 use std::collections::BTreeMap;
 
-use num_traits::{One, Zero};
+use num_traits::One;
 use stwo::{
     core::{
         fields::{
@@ -18,7 +18,6 @@ use stwo::{
 };
 use stwo_constraint_framework::{EvalAtRow, RelationEntry};
 
-use nexus_common::constants::WORD_SIZE_HALVED;
 use nexus_vm::emulator::MemoryInitializationEntry;
 use nexus_vm_prover_trace::{
     builder::{FinalizedTrace, TraceBuilder},
@@ -28,7 +27,7 @@ use nexus_vm_prover_trace::{
 };
 
 use crate::{
-    components::utils::u32_to_16bit_parts_le,
+    components::utils::{boundary::BoundaryGadget, u32_to_16bit_parts_le},
     framework::BuiltInComponent,
     lookups::{
         AllLookupElements, ComponentLookupElements, LogupTraceBuilder,
@@ -43,6 +42,8 @@ use columns::{Column, PreprocessedColumn};
 pub struct StaticMemoryBoundary;
 
 impl BuiltInComponent for StaticMemoryBoundary {
+    const NAME: &'static str = "StaticMemoryBoundary";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -73,6 +74,15 @@ impl BuiltInComponent for StaticMemoryBoundary {
         trace.finalize()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        let static_memory = side_note.program.static_memory;
+        static_memory
+            .len()
+            .next_power_of_two()
+            .ilog2()
+            .max(LOG_N_LANES)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let static_memory = side_note.program.static_memory;
         let last_access = side_note.memory.read_write_memory.last_access();
@@ -115,7 +125,7 @@ impl BuiltInComponent for StaticMemoryBoundary {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
@@ -124,7 +134,7 @@ impl BuiltInComponent for StaticMemoryBoundary {
         let (rel_ram_read_write, rel_ram_read_addr, rel_ram_write_addr) =
             Self::LookupElements::get(lookup_elements);
         let log_size = component_trace.log_size();
-        let mut logup_trace_builder = LogupTraceBuilder::new(log_size);
+        let mut logup_trace_builder = LogupTraceBuilder::new(log_size, side_note.logup_backend);
 
         let [ram_init_final_flag] =
             preprocessed_base_column!(component_trace, PreprocessedColumn::IsStaticAddr);
@@ -139,27 +149,16 @@ impl BuiltInComponent for StaticMemoryBoundary {
         let [write_mult] = original_base_column!(component_trace, Column::MultiplicityWrite);
 
         // consume(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-final, ram-ts-final))
-        logup_trace_builder.add_to_relation_with(
-            &rel_ram_read_write,
-            [ram_init_final_flag.clone()],
-            |[ram_init_final_flag]| (-ram_init_final_flag).into(),
-            &[
-                addr.as_slice(),
-                std::slice::from_ref(&ram_val_final),
-                &ram_ts_final,
-            ]
-            .concat(),
-        );
         // provide(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-init, 0))
-        logup_trace_builder.add_to_relation(
+        BoundaryGadget::generate_interaction_trace(
+            &mut logup_trace_builder,
             &rel_ram_read_write,
-            ram_init_final_flag,
-            &[
-                addr.as_slice(),
-                std::slice::from_ref(&ram_val_init),
-                vec![BaseField::zero().into(); WORD_SIZE_HALVED].as_slice(),
-            ]
-            .concat(),
+            [ram_init_final_flag],
+            |[ram_init_final_flag]| ram_init_final_flag,
+            &addr,
+            std::slice::from_ref(&ram_val_init),
+            std::slice::from_ref(&ram_val_final),
+            &ram_ts_final,
         );
 
         // consume(rel-ram-read-addr, read-mult, ram-init-final-addr)
@@ -204,27 +203,16 @@ impl BuiltInComponent for StaticMemoryBoundary {
         let (rel_ram_read_write, rel_ram_read_addr, rel_ram_write_addr) = lookup_elements;
 
         // consume(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-final, ram-ts-final))
-        eval.add_to_relation(RelationEntry::new(
-            rel_ram_read_write,
-            (-is_static_memory.clone()).into(),
-            &[
-                addr.as_slice(),
-                std::slice::from_ref(&ram_val_final),
-                &ram_ts_final,
-            ]
-            .concat(),
-        ));
         // provide(rel-ram-read-write, ram-init-final-flag, (ram-init-final-addr, ram-val-init, 0))
-        eval.add_to_relation(RelationEntry::new(
+        BoundaryGadget::constrain(
+            eval,
             rel_ram_read_write,
-            is_static_memory.into(),
-            &[
-                addr.as_slice(),
-                std::slice::from_ref(&ram_val_init),
-                vec![E::F::zero(); WORD_SIZE_HALVED].as_slice(),
-            ]
-            .concat(),
-        ));
+            is_static_memory,
+            &addr,
+            std::slice::from_ref(&ram_val_init),
+            std::slice::from_ref(&ram_val_final),
+            &ram_ts_final,
+        );
 
         // consume(rel-ram-read-addr, read-mult, ram-init-final-addr)
         eval.add_to_relation(RelationEntry::new(
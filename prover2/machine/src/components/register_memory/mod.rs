@@ -40,6 +40,8 @@ pub use trace::RegisterMemorySideNote;
 pub struct RegisterMemory;
 
 impl BuiltInComponent for RegisterMemory {
+    const NAME: &'static str = "RegisterMemory";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -67,6 +69,10 @@ impl BuiltInComponent for RegisterMemory {
         }
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        trace::main_trace_log_size(side_note)
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         trace::generate_main_trace(side_note)
     }
@@ -74,7 +80,7 @@ impl BuiltInComponent for RegisterMemory {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
@@ -82,7 +88,8 @@ impl BuiltInComponent for RegisterMemory {
     ) {
         let (rel_reg_memory_read_write, rel_inst_to_reg_memory, range_check) =
             Self::LookupElements::get(lookup_elements);
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [is_local_pad] = original_base_column!(component_trace, Column::IsLocalPad);
         let clk = preprocessed_base_column!(component_trace, PreprocessedColumn::Clk);
@@ -66,10 +66,15 @@ pub fn preprocessed_timestamp_trace(log_size: u32, shift: u32) -> Vec<BaseColumn
     result
 }
 
+pub fn main_trace_log_size(side_note: &SideNote) -> u32 {
+    let num_steps = side_note.num_program_steps();
+    num_steps.next_power_of_two().ilog2().max(LOG_N_LANES)
+}
+
 pub fn generate_main_trace(side_note: &mut SideNote) -> FinalizedTrace {
     // Main routine for generating register-memory trace.
     let num_steps = side_note.num_program_steps();
-    let log_size = num_steps.next_power_of_two().ilog2().max(LOG_N_LANES);
+    let log_size = main_trace_log_size(side_note);
 
     let mut trace = TraceBuilder::new(log_size);
     let mut reg_mem_side_note = RegisterMemorySideNote::default();
@@ -1,4 +1,8 @@
 //! Helper component needed to eliminate boundary logup terms in the register memory component.
+//!
+//! Final register values are part of the witness and are never revealed to the verifier, public
+//! output is only exposed through the output memory segment, see
+//! [`PubMemoryBoundary`](super::PubMemoryBoundary).
 
 use num_traits::{One, Zero};
 use stwo::{
@@ -11,7 +15,7 @@ use stwo::{
         poly::{circle::CircleEvaluation, BitReversedOrder},
     },
 };
-use stwo_constraint_framework::{EvalAtRow, RelationEntry};
+use stwo_constraint_framework::EvalAtRow;
 
 use nexus_common::constants::NUM_REGISTERS;
 use nexus_vm::WORD_SIZE;
@@ -23,13 +27,17 @@ use nexus_vm_prover_trace::{
 };
 
 use crate::{
+    components::utils::boundary::BoundaryGadget,
     framework::BuiltInComponent,
     lookups::{AllLookupElements, LogupTraceBuilder, RegisterMemoryLookupElements},
     side_note::{program::ProgramTraceRef, SideNote},
 };
 
 mod columns;
+mod snapshot;
+
 use columns::{Column, PreprocessedColumn};
+pub use snapshot::RegisterSnapshot;
 
 pub struct RegisterMemoryBoundary;
 
@@ -38,6 +46,8 @@ impl RegisterMemoryBoundary {
 }
 
 impl BuiltInComponent for RegisterMemoryBoundary {
+    const NAME: &'static str = "RegisterMemoryBoundary";
+
     type PreprocessedColumn = PreprocessedColumn;
 
     type MainColumn = Column;
@@ -56,12 +66,16 @@ impl BuiltInComponent for RegisterMemoryBoundary {
         }
     }
 
+    fn main_trace_log_size(&self, _side_note: &SideNote) -> u32 {
+        Self::LOG_SIZE
+    }
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace {
         let register_memory = &side_note.memory.register_memory;
         let mut trace = TraceBuilder::new(Self::LOG_SIZE);
 
         let final_ts = register_memory.timestamps();
-        let final_values = register_memory.values();
+        let final_values = RegisterSnapshot::from_side_note(register_memory).to_boundary_words();
         for reg_idx in 0..NUM_REGISTERS as usize {
             trace.fill_columns(reg_idx, final_values[reg_idx], Column::FinalVal);
             trace.fill_columns(reg_idx, final_ts[reg_idx], Column::FinalTs);
@@ -72,37 +86,32 @@ impl BuiltInComponent for RegisterMemoryBoundary {
     fn generate_interaction_trace(
         &self,
         component_trace: ComponentTrace,
-        _side_note: &SideNote,
+        side_note: &SideNote,
         lookup_elements: &AllLookupElements,
     ) -> (
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
         let rel_reg_memory_read_write: &Self::LookupElements = lookup_elements.as_ref();
-        let mut logup_trace_builder = LogupTraceBuilder::new(component_trace.log_size());
+        let mut logup_trace_builder =
+            LogupTraceBuilder::new(component_trace.log_size(), side_note.logup_backend);
 
         let [reg_addr] = preprocessed_base_column!(component_trace, PreprocessedColumn::RegAddr);
         let reg_val_final = original_base_column!(component_trace, Column::FinalVal);
         let reg_ts_final = original_base_column!(component_trace, Column::FinalTs);
 
-        // consume(rel-reg-memory-read-write, is-reg-addr, (reg-init-final-addr, reg-val-final, reg-ts-final))
-        logup_trace_builder.add_to_relation(
-            rel_reg_memory_read_write,
-            -BaseField::one(),
-            &[
-                std::slice::from_ref(&reg_addr),
-                &reg_val_final,
-                &reg_ts_final,
-            ]
-            .concat(),
-        );
-
         let zero_word = vec![FinalizedColumn::from(BaseField::zero()); WORD_SIZE];
+        // consume(rel-reg-memory-read-write, is-reg-addr, (reg-init-final-addr, reg-val-final, reg-ts-final))
         // provide(rel-reg-memory-read-write, is-reg-addr, (reg-init-final-addr, 0, 0))
-        logup_trace_builder.add_to_relation(
+        BoundaryGadget::generate_interaction_trace(
+            &mut logup_trace_builder,
             rel_reg_memory_read_write,
-            BaseField::one(),
-            &[std::slice::from_ref(&reg_addr), &zero_word, &zero_word].concat(),
+            [BaseField::one().into()],
+            |[is_reg_addr]| is_reg_addr,
+            std::slice::from_ref(&reg_addr),
+            &zero_word,
+            &reg_val_final,
+            &reg_ts_final,
         );
 
         logup_trace_builder.finalize()
@@ -120,25 +129,18 @@ impl BuiltInComponent for RegisterMemoryBoundary {
 
         let rel_reg_memory_read_write = lookup_elements;
 
-        // consume(rel-reg-memory-read-write, is-reg-addr, (reg-init-final-addr, reg-val-final, reg-ts-final))
-        eval.add_to_relation(RelationEntry::new(
-            rel_reg_memory_read_write,
-            -E::EF::one(),
-            &[
-                std::slice::from_ref(&reg_addr),
-                &reg_val_final,
-                &reg_ts_final,
-            ]
-            .concat(),
-        ));
-
         let zero_word = vec![E::F::zero(); WORD_SIZE];
+        // consume(rel-reg-memory-read-write, is-reg-addr, (reg-init-final-addr, reg-val-final, reg-ts-final))
         // provide(rel-reg-memory-read-write, is-reg-addr, (reg-init-final-addr, 0, 0))
-        eval.add_to_relation(RelationEntry::new(
+        BoundaryGadget::constrain(
+            eval,
             rel_reg_memory_read_write,
-            E::EF::one(),
-            &[std::slice::from_ref(&reg_addr), &zero_word, &zero_word].concat(),
-        ));
+            E::F::one(),
+            std::slice::from_ref(&reg_addr),
+            &zero_word,
+            &reg_val_final,
+            &reg_ts_final,
+        );
 
         eval.finalize_logup_in_pairs();
     }
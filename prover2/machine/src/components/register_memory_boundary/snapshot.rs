@@ -0,0 +1,88 @@
+use nexus_common::{constants::NUM_REGISTERS, cpu::Registers};
+use nexus_vm::{cpu::RegisterFile, riscv::Register, WORD_SIZE};
+
+use crate::components::RegisterMemorySideNote;
+
+/// Snapshot of the register file.
+///
+/// Values are indexed by the register number, i.e. `values[i]` holds the value of `x{i}`. This is
+/// the same convention used by the VM's [`RegisterFile`], by the register memory side note, and by
+/// the rows of the register memory boundary component, where the row index is the register address
+/// and the value is committed as little-endian bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterSnapshot {
+    values: [u32; NUM_REGISTERS],
+}
+
+impl RegisterSnapshot {
+    /// Creates a snapshot from values indexed by register number.
+    ///
+    /// Panics if `x0` is non-zero.
+    pub fn new(values: [u32; NUM_REGISTERS]) -> Self {
+        assert_eq!(values[0], 0, "x0 must be zero");
+        Self { values }
+    }
+
+    /// Returns register values indexed by register number.
+    pub fn values(&self) -> &[u32; NUM_REGISTERS] {
+        &self.values
+    }
+
+    /// Reads the register file of the VM.
+    pub fn from_registers(registers: &impl Registers) -> Self {
+        Self {
+            values: std::array::from_fn(|i| registers.read(Register::from(i as u8))),
+        }
+    }
+
+    /// Converts the snapshot into the VM's register file.
+    pub fn to_register_file(&self) -> RegisterFile {
+        let mut registers = RegisterFile::new();
+        for (i, &value) in self.values.iter().enumerate() {
+            registers.write(Register::from(i as u8), value);
+        }
+        registers
+    }
+
+    /// Returns final register values as committed by the register memory boundary component:
+    /// one little-endian word per row, ordered by register address.
+    pub fn to_boundary_words(&self) -> [[u8; WORD_SIZE]; NUM_REGISTERS] {
+        self.values.map(u32::to_le_bytes)
+    }
+
+    /// Inverse of [`Self::to_boundary_words`].
+    pub fn from_boundary_words(words: &[[u8; WORD_SIZE]; NUM_REGISTERS]) -> Self {
+        Self::new(words.map(u32::from_le_bytes))
+    }
+
+    /// Final register state recorded by the register memory component.
+    pub(crate) fn from_side_note(side_note: &RegisterMemorySideNote) -> Self {
+        Self::new(*side_note.values())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_snapshot_round_trip() {
+        let mut registers = RegisterFile::new();
+        for i in 1..NUM_REGISTERS {
+            registers.write(Register::from(i as u8), 0x0101_0101 * i as u32 + 0xFF);
+        }
+
+        let snapshot = RegisterSnapshot::from_registers(&registers);
+        for (i, &value) in snapshot.values().iter().enumerate() {
+            assert_eq!(value, registers.read(Register::from(i as u8)));
+        }
+
+        let words = snapshot.to_boundary_words();
+        assert_eq!(words[1], [0x00, 0x02, 0x01, 0x01]);
+        assert_eq!(words[0], [0; WORD_SIZE]);
+
+        let decoded = RegisterSnapshot::from_boundary_words(&words);
+        assert_eq!(decoded, snapshot);
+        assert_eq!(decoded.to_register_file(), registers);
+    }
+}
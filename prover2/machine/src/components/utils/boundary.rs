@@ -0,0 +1,182 @@
+//! Terminal state of a memory address space, shared by register and RAM boundary components.
+
+use num_traits::Zero;
+use stwo::{core::fields::m31::BaseField, prover::backend::simd::m31::PackedBaseField};
+use stwo_constraint_framework::{EvalAtRow, RelationEntry};
+
+use nexus_vm_prover_trace::component::FinalizedColumn;
+
+use crate::lookups::{LogupTraceBuilder, RegisteredLookupBound};
+
+/// Closes the memory checking argument of an address space.
+///
+/// For every address with a non-zero flag, the last access is consumed as the final state and the
+/// initial value is provided with a zero timestamp, so that the first access reads the initial value:
+///
+/// ```text
+/// consume(rel, flag, (addr, val-final, ts-final))
+/// provide(rel, flag, (addr, val-init, 0))
+/// ```
+///
+/// The address space is determined by the relation and the width of address, value and timestamp.
+/// The flag is an expression over trace columns, e.g. `1 − is-pad`, when generating the interaction
+/// trace it's computed from `flag_columns` the same way [`LogupTraceBuilder::add_to_relation_with`]
+/// computes multiplicities.
+pub struct BoundaryGadget;
+
+impl BoundaryGadget {
+    pub fn constrain<E: EvalAtRow, R: RegisteredLookupBound>(
+        eval: &mut E,
+        relation: &R,
+        flag: E::F,
+        addr: &[E::F],
+        val_init: &[E::F],
+        val_final: &[E::F],
+        ts_final: &[E::F],
+    ) {
+        let ts_init = vec![E::F::zero(); ts_final.len()];
+
+        // consume(rel, flag, (addr, val-final, ts-final))
+        eval.add_to_relation(RelationEntry::new(
+            relation.as_relation_ref(),
+            (-flag.clone()).into(),
+            &[addr, val_final, ts_final].concat(),
+        ));
+        // provide(rel, flag, (addr, val-init, 0))
+        eval.add_to_relation(RelationEntry::new(
+            relation.as_relation_ref(),
+            flag.into(),
+            &[addr, val_init, &ts_init].concat(),
+        ));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_interaction_trace<const N: usize, R: RegisteredLookupBound>(
+        logup_trace_builder: &mut LogupTraceBuilder,
+        relation: &R,
+        flag_columns: [FinalizedColumn; N],
+        flag: impl Fn([PackedBaseField; N]) -> PackedBaseField,
+        addr: &[FinalizedColumn],
+        val_init: &[FinalizedColumn],
+        val_final: &[FinalizedColumn],
+        ts_final: &[FinalizedColumn],
+    ) {
+        let ts_init = vec![FinalizedColumn::from(BaseField::zero()); ts_final.len()];
+
+        // consume(rel, flag, (addr, val-final, ts-final))
+        logup_trace_builder.add_to_relation_with(
+            relation,
+            flag_columns.clone(),
+            |columns| (-flag(columns)).into(),
+            &[addr, val_final, ts_final].concat(),
+        );
+        // provide(rel, flag, (addr, val-init, 0))
+        logup_trace_builder.add_to_relation_with(
+            relation,
+            flag_columns,
+            |columns| flag(columns).into(),
+            &[addr, val_init, &ts_init].concat(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_traits::{One, Zero};
+    use stwo::core::fields::{m31::BaseField, qm31::SecureField};
+
+    use nexus_vm::{
+        emulator::{InternalView, MemoryInitializationEntry, View},
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode, Register},
+        trace::k_trace_direct,
+    };
+
+    use crate::{
+        components::{
+            execution::load::tests::setup_ir, RegisterMemoryBoundary, StaticMemoryBoundary,
+        },
+        framework::test_utils::{components_claimed_sum, AssertContext},
+        lookups::{record_relations, RelationLogEntry},
+        verify::verify_logup_sum,
+        BASE_COMPONENTS,
+    };
+
+    /// Returns the consumed final and the provided initial tuple of the boundary entries whose
+    /// tuple starts with `addr`.
+    fn boundary_tuples(
+        entries: &[RelationLogEntry],
+        relation: &str,
+        addr: &[BaseField],
+    ) -> (Vec<BaseField>, Vec<BaseField>) {
+        let find = |multiplicity: SecureField| {
+            entries
+                .iter()
+                .find(|entry| {
+                    entry.relation == relation
+                        && entry.multiplicity == multiplicity
+                        && entry.values.starts_with(addr)
+                })
+                .expect("boundary entry is missing")
+                .values[addr.len()..]
+                .to_vec()
+        };
+        (find(-SecureField::one()), find(SecureField::one()))
+    }
+
+    #[test]
+    fn register_and_static_memory_boundaries_agree() {
+        // x1 = 0x2A is stored into a static byte initialized to zero
+        let mut instr = setup_ir();
+        instr.push(Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADDI),
+            1,
+            0,
+            0x2A,
+        ));
+        instr.push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SB), 2, 1, 0));
+        let (view, program_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+        let address = program_trace.blocks.last().unwrap().regs.read(Register::X2);
+        let view = View::new(
+            &view.view_memory_layout().copied(),
+            &vec![],
+            view.get_program_memory(),
+            &view.get_ro_initial_memory().to_vec(),
+            &vec![MemoryInitializationEntry { address, value: 0 }],
+            &view.get_public_input().to_vec(),
+            view.view_tracked_ram_size(),
+            &view.get_exit_code().to_vec(),
+            &view.get_public_output().to_vec(),
+            &vec![],
+        );
+
+        let assert_ctx = &mut AssertContext::new(&program_trace, &view);
+        let claimed_sum = components_claimed_sum(BASE_COMPONENTS, assert_ctx);
+        verify_logup_sum(&[claimed_sum], &view, &assert_ctx.lookup_elements).unwrap();
+
+        let (_, reg_entries) =
+            record_relations(|| components_claimed_sum(&[&RegisterMemoryBoundary], assert_ctx));
+        let (_, ram_entries) =
+            record_relations(|| components_claimed_sum(&[&StaticMemoryBoundary], assert_ctx));
+
+        // (val, ts) tuples, the register value is split into bytes
+        let (reg_final, reg_init) =
+            boundary_tuples(&reg_entries, "RegisterMemoryLookupElements", &[BaseField::one()]);
+        let (ram_final, ram_init) = boundary_tuples(
+            &ram_entries,
+            "RamReadWriteLookupElements",
+            &address.to_le_bytes().map(|byte| BaseField::from(byte as u32)),
+        );
+
+        // both boundaries consume the stored value with the last access timestamp
+        assert_eq!(reg_final[0], BaseField::from(0x2A_u32));
+        assert_eq!(ram_final[0], reg_final[0]);
+        assert!(reg_final[4..].iter().any(|ts| !ts.is_zero()));
+        assert!(ram_final[1..].iter().any(|ts| !ts.is_zero()));
+
+        // and provide the zero initial value at timestamp zero
+        assert!(reg_init.iter().all(BaseField::is_zero));
+        assert!(ram_init.iter().all(BaseField::is_zero));
+    }
+}
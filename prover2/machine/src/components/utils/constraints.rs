@@ -1,10 +1,28 @@
+use std::ops::{Add, Mul, Sub};
+
 use num_traits::One;
 use stwo::core::fields::m31::BaseField;
 use stwo_constraint_framework::EvalAtRow;
 
-use nexus_common::constants::WORD_SIZE_HALVED;
+use nexus_common::constants::{WORD_SIZE, WORD_SIZE_HALVED};
+use nexus_vm::trace::Step;
 use nexus_vm_prover_air_column::{AirColumn, PreprocessedAirColumn};
-use nexus_vm_prover_trace::eval::TraceEval;
+use nexus_vm_prover_trace::{builder::TraceBuilder, eval::TraceEval};
+
+use super::{add_16bit_with_carry, u32_to_16bit_parts_le};
+
+/// Selects between two words limb by limb: `sel · a(i) + (1 − sel) · b(i)`.
+///
+/// Evaluates to `a` if `sel` = 1 and to `b` if `sel` = 0, the caller is responsible for
+/// constraining the selector to be boolean.
+pub fn select_word<F>(sel: &F, a: [F; WORD_SIZE], b: [F; WORD_SIZE]) -> [F; WORD_SIZE]
+where
+    F: Clone + One + Add<Output = F> + Sub<Output = F> + Mul<Output = F>,
+{
+    std::array::from_fn(|i| {
+        sel.clone() * a[i].clone() + (F::one() - sel.clone()) * b[i].clone()
+    })
+}
 
 /// Helper struct for constraining clock increments.
 pub struct ClkIncrement<C> {
@@ -57,3 +75,87 @@ impl<C: AirColumn> PcIncrement<C> {
         [pc_next_0, pc_next_1]
     }
 }
+
+/// Helper struct bundling clock and program counter columns of an execution component,
+/// for components that increment pc by the word size.
+pub struct StepCounters<C> {
+    pub clk: C,
+    pub clk_carry: C,
+    pub pc: C,
+    pub pc_carry: C,
+}
+
+/// Evaluated clock and program counter values of the current and the next step.
+pub struct StepCountersEval<F> {
+    pub clk: [F; WORD_SIZE_HALVED],
+    pub clk_next: [F; WORD_SIZE_HALVED],
+    pub pc: [F; WORD_SIZE_HALVED],
+    pub pc_next: [F; WORD_SIZE_HALVED],
+}
+
+impl<C: AirColumn> StepCounters<C> {
+    /// Fills clock and program counter columns along with their carry bits.
+    pub fn fill(&self, trace: &mut TraceBuilder<C>, row_idx: usize, step: &Step) {
+        let pc_parts = u32_to_16bit_parts_le(step.pc);
+        let (_pc_next, pc_carry) = add_16bit_with_carry(pc_parts, WORD_SIZE as u16);
+
+        let clk_parts = u32_to_16bit_parts_le(step.timestamp);
+        let (_clk_next, clk_carry) = add_16bit_with_carry(clk_parts, 1u16);
+
+        trace.fill_columns(row_idx, pc_parts, self.pc);
+        trace.fill_columns(row_idx, pc_carry, self.pc_carry);
+
+        trace.fill_columns(row_idx, clk_parts, self.clk);
+        trace.fill_columns(row_idx, clk_carry, self.clk_carry);
+    }
+
+    /// Constrains both increments, returning current and next values of clk and pc.
+    pub fn constrain<E: EvalAtRow, P: PreprocessedAirColumn>(
+        &self,
+        eval: &mut E,
+        trace_eval: &TraceEval<P, C, E>,
+    ) -> StepCountersEval<E::F> {
+        let clk = trace_eval.column_eval(self.clk);
+        let clk_next = ClkIncrement {
+            clk: self.clk,
+            clk_carry: self.clk_carry,
+        }
+        .eval(eval, trace_eval);
+
+        let pc = trace_eval.column_eval(self.pc);
+        let pc_next = PcIncrement {
+            pc: self.pc,
+            pc_carry: self.pc_carry,
+        }
+        .eval(eval, trace_eval);
+
+        StepCountersEval {
+            clk,
+            clk_next,
+            pc,
+            pc_next,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_WORDS: [u32; 5] = [0, 4, 0x1234_5678, 0x8000_0000, u32::MAX];
+
+    fn to_base_word(value: u32) -> [BaseField; WORD_SIZE] {
+        value.to_le_bytes().map(|byte| BaseField::from(byte as u32))
+    }
+
+    #[test]
+    fn select_word_picks_operand() {
+        for a in SAMPLE_WORDS {
+            for b in SAMPLE_WORDS {
+                let (a, b) = (to_base_word(a), to_base_word(b));
+                assert_eq!(select_word(&BaseField::from(1), a, b), a);
+                assert_eq!(select_word(&BaseField::from(0), a, b), b);
+            }
+        }
+    }
+}
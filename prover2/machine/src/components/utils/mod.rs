@@ -1,6 +1,7 @@
 use nexus_vm::WORD_SIZE;
 use nexus_vm_prover_trace::program::{BoolWord, Word};
 
+pub mod boundary;
 pub mod constraints;
 
 /// Adds two 4-byte words with carry propagation across each byte.
@@ -118,4 +119,46 @@ mod tests {
             assert_eq!(carry, a_parts[0] > u16::MAX - WORD_SIZE as u16);
         }
     }
+
+    const SAMPLE_VALUES: [u32; 10] = [
+        0,
+        1,
+        0xFF,
+        0x100,
+        0xFFFF,
+        0x1_0000,
+        0x1234_5678,
+        0x7FFF_FFFF,
+        0x8000_0000,
+        u32::MAX,
+    ];
+
+    #[test]
+    fn test_add_with_carries() {
+        for a in SAMPLE_VALUES {
+            for b in SAMPLE_VALUES {
+                let (sum, carry) = add_with_carries(a.to_le_bytes(), b.to_le_bytes());
+
+                assert_eq!(u32::from_le_bytes(sum), a.wrapping_add(b));
+                assert_eq!(carry[WORD_SIZE - 1], a.checked_add(b).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_subtract_with_borrow() {
+        for a in SAMPLE_VALUES {
+            for b in SAMPLE_VALUES {
+                let (diff, borrow) = subtract_with_borrow(a.to_le_bytes(), b.to_le_bytes());
+
+                assert_eq!(u32::from_le_bytes(diff), a.wrapping_sub(b));
+                assert_eq!(borrow[WORD_SIZE - 1], a < b);
+                // intermediate borrows match comparisons of the lower limbs
+                for i in 0..WORD_SIZE - 1 {
+                    let mask = (1u64 << (8 * (i + 1))) - 1;
+                    assert_eq!(borrow[i], (a as u64 & mask) < (b as u64 & mask));
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,91 @@
+use stwo::core::{fri::FriConfig, pcs::PcsConfig};
+
+use crate::lookups::LogupBackend;
+
+/// Security parameters used by the prover and the verifier.
+///
+/// The verifier must be configured with the same parameters that were used to generate the proof.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProvingConfig {
+    pub(crate) pcs_config: PcsConfig,
+    /// Maximum number of trace rows across all components, ignored by the verifier.
+    pub(crate) row_budget: Option<usize>,
+    /// Whether to report bounds of constraint expressions, ignored by the verifier.
+    pub(crate) expression_bounds: bool,
+    /// Backend used for computing logup fractions, ignored by the verifier.
+    pub(crate) logup_backend: LogupBackend,
+}
+
+impl ProvingConfig {
+    /// Parameters for fast development iteration.
+    ///
+    /// **INSECURE**: disables proof of work and uses a single FRI query, must not be used in
+    /// production. Only these two are reduced, the blowup factor is the default one.
+    pub fn dev() -> Self {
+        let log_blowup_factor = PcsConfig::default().fri_config.log_blowup_factor;
+        Self {
+            pcs_config: PcsConfig {
+                pow_bits: 0,
+                fri_config: FriConfig::new(0, log_blowup_factor, 1),
+            },
+            row_budget: None,
+            expression_bounds: false,
+            logup_backend: LogupBackend::Simd,
+        }
+    }
+
+    /// Aborts proving with [`TraceGenError::RowBudgetExceeded`] if component traces, padding
+    /// included, would add up to more than `rows` rows.
+    ///
+    /// Sizes of the traces are projected from the execution, the check happens before any of
+    /// them is generated.
+    ///
+    /// [`TraceGenError::RowBudgetExceeded`]: crate::TraceGenError::RowBudgetExceeded
+    pub fn with_row_budget(self, rows: usize) -> Self {
+        Self {
+            row_budget: Some(rows),
+            ..self
+        }
+    }
+
+    /// Evaluates constraints over the integers on every row while proving and reports the
+    /// largest magnitude reached by each of them in [`ComponentReport::expression_bounds`].
+    ///
+    /// Magnitudes close to the field modulus point at constraints that only hold because
+    /// intermediates don't wrap around. The evaluation is slow, intended for diagnostics with
+    /// [`crate::prove_with_report`].
+    ///
+    /// [`ComponentReport::expression_bounds`]: crate::ComponentReport::expression_bounds
+    pub fn with_expression_bounds(self) -> Self {
+        Self {
+            expression_bounds: true,
+            ..self
+        }
+    }
+
+    /// Generates interaction traces using the given logup backend.
+    ///
+    /// The resulting proof doesn't depend on the backend, [`LogupBackend::Scalar`] is intended for
+    /// isolating SIMD-specific bugs.
+    pub fn with_logup_backend(self, backend: LogupBackend) -> Self {
+        Self {
+            logup_backend: backend,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dev_lowers_security_parameters() {
+        let default = ProvingConfig::default().pcs_config;
+        let dev = ProvingConfig::dev().pcs_config;
+
+        assert!(dev.fri_config.log_blowup_factor <= default.fri_config.log_blowup_factor);
+        assert!(dev.fri_config.n_queries < default.fri_config.n_queries);
+        assert!(dev.security_bits() < default.security_bits());
+    }
+}
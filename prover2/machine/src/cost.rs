@@ -0,0 +1,137 @@
+//! Per-opcode cost model for estimating proving work.
+//!
+//! Each executed instruction is charged a weight depending on the component that proves it.
+//! The weights are hand-assigned estimates rather than measurements, they rank components by the
+//! width of their main trace and the number of lookups they add to shared tables:
+//!
+//! | weight | opcodes                         | component work                                  |
+//! |--------|---------------------------------|-------------------------------------------------|
+//! | 1      | ADD, ADDI, SUB, LUI, AUIPC      | a single 32-bit addition with carries           |
+//! | 2      | bitwise, SLT, branches, jumps   | a comparison or a lookup into the bitwise table |
+//! | 3      | shifts, loads and stores        | shift amount decomposition or RAM lookups       |
+//! | 4      | multiplication, ECALL           | 64-bit product limbs or system call effects     |
+//! | 5      | division and remainder          | a multiplication and a remainder comparison     |
+//!
+//! The total is bound by the proof: the program memory boundary provides the weight of each
+//! instruction times the number of times it was executed, and the verifier consumes the total
+//! claimed in [`Proof::cost`](crate::Proof::cost).
+
+use nexus_vm::{
+    riscv::{decode_instruction, BuiltinOpcode},
+    trace::Trace,
+};
+use nexus_vm_prover_trace::program::ProgramStep;
+
+use crate::side_note::SideNote;
+
+/// Returns the cost weight of an opcode, or `None` if it's not supported by the prover.
+pub fn opcode_cost(opcode: BuiltinOpcode) -> Option<u32> {
+    use BuiltinOpcode::*;
+
+    let cost = match opcode {
+        ADD | ADDI | SUB | LUI | AUIPC => 1,
+        AND | ANDI | OR | ORI | XOR | XORI => 2,
+        SLT | SLTI | SLTU | SLTIU => 2,
+        BEQ | BNE | BLT | BGE | BLTU | BGEU => 2,
+        JAL | JALR => 2,
+        SLL | SLLI | SRL | SRLI | SRA | SRAI => 3,
+        LB | LH | LW | LBU | LHU | SB | SH | SW => 3,
+        MUL | MULH | MULHU | MULHSU => 4,
+        DIV | DIVU | REM | REMU => 5,
+        ECALL => 4,
+        _ => return None,
+    };
+    Some(cost)
+}
+
+/// Returns the cost weight of an encoded instruction, or `None` if it's not supported by the
+/// prover.
+pub(crate) fn instruction_cost(instruction_word: u32) -> Option<u32> {
+    decode_instruction(instruction_word)
+        .opcode
+        .builtin()
+        .and_then(opcode_cost)
+}
+
+/// Returns the cost weight of an executed step, or `None` if its opcode is not supported by the
+/// prover.
+pub fn step_cost(step: &ProgramStep) -> Option<u32> {
+    step.step.instruction.opcode.builtin().and_then(opcode_cost)
+}
+
+/// Returns the total cost of the program steps in the side note, unsupported steps are free.
+///
+/// Matches the total bound by the program memory boundary, which assigns zero weight to
+/// unsupported instructions.
+pub(crate) fn side_note_cost(side_note: &SideNote) -> u32 {
+    side_note
+        .iter_program_steps()
+        .map(|program_step| step_cost(&program_step).unwrap_or_default())
+        .sum()
+}
+
+/// Returns the total cost of all steps in the execution trace, or `None` if any of them is not
+/// supported by the prover.
+pub fn trace_cost(trace: &impl Trace) -> Option<u64> {
+    trace
+        .get_blocks_iter()
+        .map(|block| step_cost(&ProgramStep::from(block)).map(u64::from))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prove, verify};
+
+    use nexus_vm::{
+        riscv::{BasicBlock, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+
+    #[test]
+    fn trace_cost_is_weighted_sum() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 8),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 3),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::XOR), 4, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLL), 5, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 6, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SRAI), 7, 1, 1),
+        ])];
+        let (_view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        // addi, addi, add, xor, sll, sltu, srai
+        let expected = 1 + 1 + 1 + 2 + 3 + 2 + 3;
+        assert_eq!(trace_cost(&program_trace), Some(expected));
+    }
+
+    #[test]
+    fn unsupported_opcode_has_no_cost() {
+        assert_eq!(opcode_cost(BuiltinOpcode::EBREAK), None);
+        // ebreak
+        assert_eq!(instruction_cost(0x0010_0073), None);
+    }
+
+    #[test]
+    fn cost_is_bound_by_proof() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 8),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 0, 3),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::XOR), 3, 1, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLL), 4, 1, 2),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let proof = prove(&program_trace, &view).unwrap();
+        assert_eq!(proof.cost, 1 + 1 + 2 + 3);
+        verify(proof.clone(), &view).unwrap();
+
+        let mut proof = proof;
+        proof.cost -= 1;
+        assert!(verify(proof, &view).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Integer bounds of constraint expressions, see [`ProvingConfig::with_expression_bounds`].
+//!
+//! [`ProvingConfig::with_expression_bounds`]: crate::ProvingConfig::with_expression_bounds
+
+use std::collections::HashMap;
+
+use num_traits::Zero;
+use stwo::{
+    core::{
+        fields::m31::{self, BaseField},
+        utils::{bit_reverse_index, coset_index_to_circle_domain_index},
+    },
+    prover::backend::{simd::column::BaseColumn, Column},
+};
+use stwo_constraint_framework::expr::{BaseExpr, ExprEvaluator, ExtExpr};
+
+use nexus_vm_prover_trace::{
+    component::ComponentTrace,
+    eval::{ORIGINAL_TRACE_IDX, PREPROCESSED_TRACE_IDX},
+};
+
+use crate::report::ExpressionBound;
+
+/// Evaluates constraints over the integers on every row of the trace and returns the largest
+/// magnitude reached by each constraint or any of its subexpressions.
+///
+/// Field elements above `P / 2` are read as negative integers. Constraints over the extension
+/// field, such as logup constraints, and constraints that read the interaction trace or invert
+/// a value are skipped.
+pub(crate) fn expression_bounds(
+    evaluator: &ExprEvaluator,
+    preprocessed_ids: &[&str],
+    component_trace: &ComponentTrace,
+) -> Vec<ExpressionBound> {
+    let intermediates = evaluator
+        .intermediates
+        .iter()
+        .map(|(name, expr)| (name.as_str(), expr))
+        .collect();
+    let mut row_eval = RowEvaluator {
+        intermediates,
+        preprocessed_ids,
+        component_trace,
+        row: 0,
+        max_magnitude: 0,
+    };
+
+    evaluator
+        .constraints
+        .iter()
+        .enumerate()
+        .filter_map(|(constraint_idx, constraint)| {
+            let constraint = base_constraint(constraint)?;
+            row_eval.max_magnitude = 0;
+            for row in 0..1 << component_trace.log_size() {
+                row_eval.row = row;
+                row_eval.eval(constraint)?;
+            }
+            Some(ExpressionBound {
+                constraint_idx,
+                max_magnitude: u64::try_from(row_eval.max_magnitude).unwrap_or(u64::MAX),
+            })
+        })
+        .collect()
+}
+
+/// Returns the expression of a constraint over the base field, which is embedded into the first
+/// coordinate of the extension.
+fn base_constraint(constraint: &ExtExpr) -> Option<&BaseExpr> {
+    match constraint {
+        ExtExpr::SecureCol([value, rest @ ..])
+            if rest
+                .iter()
+                .all(|expr| matches!(**expr, BaseExpr::Const(c) if c.is_zero())) =>
+        {
+            Some(&**value)
+        }
+        _ => None,
+    }
+}
+
+/// Reads a field element as an integer in `(-P / 2, P / 2]`.
+fn signed(value: BaseField) -> i128 {
+    let value = i128::from(value.0);
+    if value > i128::from(m31::P / 2) {
+        value - i128::from(m31::P)
+    } else {
+        value
+    }
+}
+
+struct RowEvaluator<'a> {
+    intermediates: HashMap<&'a str, &'a BaseExpr>,
+    preprocessed_ids: &'a [&'a str],
+    component_trace: &'a ComponentTrace,
+    /// Index of the row in coset order.
+    row: usize,
+    max_magnitude: u128,
+}
+
+impl RowEvaluator<'_> {
+    fn eval(&mut self, expr: &BaseExpr) -> Option<i128> {
+        let value = match expr {
+            BaseExpr::Col(col) => {
+                let trace = match col.interaction {
+                    PREPROCESSED_TRACE_IDX => &self.component_trace.preprocessed_trace,
+                    ORIGINAL_TRACE_IDX => &self.component_trace.original_trace,
+                    _ => return None,
+                };
+                self.read(&trace[col.idx], col.offset)
+            }
+            BaseExpr::Const(c) => signed(*c),
+            BaseExpr::Param(name) => {
+                if let Some(&intermediate) = self.intermediates.get(name.as_str()) {
+                    self.eval(intermediate)?
+                } else {
+                    // preprocessed columns are referred to by their ids
+                    let idx = self.preprocessed_ids.iter().position(|id| id == name)?;
+                    self.read(&self.component_trace.preprocessed_trace[idx], 0)
+                }
+            }
+            BaseExpr::Add(a, b) => self.eval(a)?.saturating_add(self.eval(b)?),
+            BaseExpr::Sub(a, b) => self.eval(a)?.saturating_sub(self.eval(b)?),
+            BaseExpr::Mul(a, b) => self.eval(a)?.saturating_mul(self.eval(b)?),
+            BaseExpr::Neg(a) => self.eval(a)?.saturating_neg(),
+            BaseExpr::Inv(_) => return None,
+        };
+        self.max_magnitude = self.max_magnitude.max(value.unsigned_abs());
+        Some(value)
+    }
+
+    fn read(&self, col: &BaseColumn, offset: isize) -> i128 {
+        let log_size = self.component_trace.log_size();
+        let row = (self.row as isize + offset).rem_euclid(1 << log_size) as usize;
+        let idx = bit_reverse_index(coset_index_to_circle_domain_index(row, log_size), log_size);
+        signed(col.at(idx))
+    }
+}
@@ -1,7 +1,13 @@
+mod bounds;
 mod eval;
+mod row_eval;
 mod traits;
 
-pub(crate) use traits::{builtin::BuiltInComponent, erased::MachineComponent};
+pub(crate) use row_eval::{check_constraints, ConstraintViolation};
+pub(crate) use traits::{
+    builtin::BuiltInComponent,
+    erased::{sort_components, MachineComponent},
+};
 
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -0,0 +1,138 @@
+//! Evaluation of constraints on every row of a trace, see [`MachineComponent::check_constraints`].
+//!
+//! [`MachineComponent::check_constraints`]: super::MachineComponent::check_constraints
+
+use std::ops::Mul;
+
+use num_traits::{One, Zero};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use stwo::{
+    core::{
+        fields::{
+            m31::BaseField,
+            qm31::{SecureField, SECURE_EXTENSION_DEGREE},
+        },
+        fraction::Fraction,
+        pcs::TreeVec,
+        utils::{bit_reverse_index, coset_index_to_circle_domain_index},
+        ColumnVec,
+    },
+    prover::{
+        backend::{
+            simd::{column::BaseColumn, SimdBackend},
+            Column,
+        },
+        poly::{circle::CircleEvaluation, BitReversedOrder},
+    },
+};
+use stwo_constraint_framework::{
+    logup_proxy, EvalAtRow, FrameworkEval, LogupAtRow, INTERACTION_TRACE_IDX,
+};
+
+use nexus_vm_prover_trace::component::ComponentTrace;
+
+/// Error returned by [`check_constraints`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    /// First row of the trace, in coset order, on which a constraint doesn't vanish.
+    pub row: usize,
+}
+
+/// Evaluates constraints of a component on every row of its trace domain, shared by
+/// implementations of [`MachineComponent::check_constraints`].
+///
+/// [`MachineComponent::check_constraints`]: super::MachineComponent::check_constraints
+pub(crate) fn check_constraints(
+    component_eval: &(impl FrameworkEval + Sync),
+    component_trace: &ComponentTrace,
+    interaction_trace: ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+    claimed_sum: SecureField,
+) -> Result<(), ConstraintViolation> {
+    let interaction_trace: Vec<BaseColumn> = interaction_trace
+        .into_iter()
+        .map(|eval| eval.values)
+        .collect();
+    let trace = TreeVec::new(vec![
+        &component_trace.preprocessed_trace[..],
+        &component_trace.original_trace[..],
+        &interaction_trace[..],
+    ]);
+    let log_size = component_trace.log_size();
+
+    let violation = (0..1 << log_size).into_par_iter().find_first(|&row| {
+        let eval = RowEvaluator::new(&trace, row, log_size, claimed_sum);
+        !component_eval.evaluate(eval).constraints_hold
+    });
+    match violation {
+        Some(row) => Err(ConstraintViolation { row }),
+        None => Ok(()),
+    }
+}
+
+/// Evaluates constraints on a single row, like [`stwo_constraint_framework::AssertEvaluator`],
+/// but records a constraint that doesn't vanish instead of panicking.
+struct RowEvaluator<'a> {
+    trace: &'a TreeVec<&'a [BaseColumn]>,
+    col_index: TreeVec<usize>,
+    /// Index of the row in coset order.
+    row: usize,
+    log_size: u32,
+    constraints_hold: bool,
+    logup: LogupAtRow<Self>,
+}
+
+impl<'a> RowEvaluator<'a> {
+    fn new(
+        trace: &'a TreeVec<&'a [BaseColumn]>,
+        row: usize,
+        log_size: u32,
+        claimed_sum: SecureField,
+    ) -> Self {
+        Self {
+            trace,
+            col_index: TreeVec::new(vec![0; trace.len()]),
+            row,
+            log_size,
+            constraints_hold: true,
+            logup: LogupAtRow::new(INTERACTION_TRACE_IDX, claimed_sum, log_size),
+        }
+    }
+}
+
+impl EvalAtRow for RowEvaluator<'_> {
+    type F = BaseField;
+    type EF = SecureField;
+
+    fn next_interaction_mask<const N: usize>(
+        &mut self,
+        interaction: usize,
+        offsets: [isize; N],
+    ) -> [Self::F; N] {
+        let col = &self.trace[interaction][self.col_index[interaction]];
+        self.col_index[interaction] += 1;
+        offsets.map(|offset| {
+            // columns are stored in bit-reversed circle domain order, masks wrap around the coset
+            let row = (self.row as isize + offset).rem_euclid(1 << self.log_size) as usize;
+            let idx = bit_reverse_index(
+                coset_index_to_circle_domain_index(row, self.log_size),
+                self.log_size,
+            );
+            col.at(idx)
+        })
+    }
+
+    fn add_constraint<G>(&mut self, constraint: G)
+    where
+        Self::EF: Mul<G, Output = Self::EF> + From<G>,
+    {
+        if !(SecureField::one() * constraint).is_zero() {
+            self.constraints_hold = false;
+        }
+    }
+
+    fn combine_ef(values: [Self::F; SECURE_EXTENSION_DEGREE]) -> Self::EF {
+        SecureField::from_m31_array(values)
+    }
+
+    logup_proxy!();
+}
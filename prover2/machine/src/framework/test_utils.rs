@@ -1,15 +1,41 @@
+use std::collections::HashSet;
+
 use num_traits::Zero;
-use stwo::core::{
-    channel::Blake2sChannel, fields::qm31::SecureField, pcs::TreeVec, poly::circle::CanonicCoset,
+use stwo::{
+    core::{
+        channel::Blake2sChannel,
+        fields::{m31::BaseField, qm31::SecureField},
+        pcs::TreeVec,
+        poly::circle::CanonicCoset,
+    },
+    prover::backend::{simd::m31::LOG_N_LANES, Column},
+};
+use stwo_constraint_framework::{
+    assert_constraints_on_polys,
+    expr::{BaseExpr, ExprEvaluator, ExtExpr},
+    FrameworkEval,
 };
-use stwo_constraint_framework::{assert_constraints_on_polys, FrameworkEval};
 
-use nexus_vm::{emulator::View, trace::Trace};
-use nexus_vm_prover_trace::eval::{ORIGINAL_TRACE_IDX, PREPROCESSED_TRACE_IDX};
+use nexus_vm::{
+    emulator::View,
+    riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+    trace::{k_trace_direct, Trace},
+};
+use nexus_vm_prover_trace::{
+    component::ComponentTrace,
+    eval::{ORIGINAL_TRACE_IDX, PREPROCESSED_TRACE_IDX},
+};
 
 use super::{eval::BuiltInComponentEval, BuiltInComponent, MachineComponent};
 use crate::{
-    lookups::{AllLookupElements, ComponentLookupElements},
+    components::{
+        Cpu, CpuBoundary, ProgramMemory, ProgramMemoryBoundary, RegisterMemory,
+        RegisterMemoryBoundary, ADDI, LUI, RANGE128, RANGE16, RANGE256, RANGE32, RANGE64, RANGE8,
+    },
+    lookups::{
+        record_relations, AllLookupElements, ComponentLookupElements, LogupBackend,
+        RelationLogEntry,
+    },
     side_note::SideNote,
     BASE_COMPONENTS,
 };
@@ -40,6 +66,23 @@ impl<'a> AssertContext<'a> {
 
 /// Asserts constraints of a builtin component.
 pub fn assert_component<C>(component: C, assert_ctx: &mut AssertContext) -> SecureField
+where
+    C: BuiltInComponent + 'static + Sync,
+    C::LookupElements: 'static + Sync,
+{
+    assert_tampered_component(component, assert_ctx, |_| {})
+}
+
+/// Asserts constraints of a builtin component after `tamper` modifies its main trace.
+///
+/// The interaction trace is generated from the modified trace, which lets tests check that
+/// constraints reject values a malicious prover could commit to. The returned sum excludes the
+/// [`BuiltInComponent::public_logup_sum`] of the component.
+pub fn assert_tampered_component<C>(
+    component: C,
+    assert_ctx: &mut AssertContext,
+    tamper: impl FnOnce(&mut ComponentTrace),
+) -> SecureField
 where
     C: BuiltInComponent + 'static + Sync,
     C::LookupElements: 'static + Sync,
@@ -50,7 +93,8 @@ where
     } = assert_ctx;
 
     // Main trace.
-    let component_trace = component.generate_component_trace(prover_side_note);
+    let mut component_trace = component.generate_component_trace(prover_side_note);
+    tamper(&mut component_trace);
     let log_size = component_trace.log_size();
 
     // Interaction trace.
@@ -86,11 +130,15 @@ where
         claimed_sum,
     );
 
-    claimed_sum
+    let public_sum =
+        <C as BuiltInComponent>::public_logup_sum(&component, prover_side_note, lookup_elements);
+    claimed_sum - public_sum
 }
 
 /// Computes total logup sum for a slice of components, without asserting that constraints are satisfied.
 ///
+/// Public logup terms are excluded, the sum of a complete set of components is zero.
+///
 /// Note that depending on the logic, the ordering is important, e.g. for range checks.
 pub fn components_claimed_sum(
     components: &[&dyn MachineComponent],
@@ -106,7 +154,246 @@ pub fn components_claimed_sum(
             &ctx.side_note,
             &ctx.lookup_elements,
         );
-        total_sum += claimed_sum;
+        total_sum +=
+            claimed_sum - component.public_logup_sum(&ctx.side_note, &ctx.lookup_elements);
     }
     total_sum
 }
+
+/// Returns the logup sum of a permissive provider that balances every relation except `checked`.
+///
+/// The stub provides back whatever the recorded entries consume, so that a component's constraints
+/// can be asserted in isolation, e.g. without the register and memory components.
+pub fn stub_claimed_sum(
+    entries: &[RelationLogEntry],
+    lookup_elements: &AllLookupElements,
+    checked: &[&'static str],
+) -> SecureField {
+    -lookup_elements
+        .relation_sums(entries)
+        .into_iter()
+        .filter(|(relation, _)| !checked.contains(relation))
+        .map(|(_, sum)| sum)
+        .sum::<SecureField>()
+}
+
+/// Asserts that the logup sums of the components add up to zero.
+///
+/// On failure, the panic message lists the contribution of each component and the balance of each
+/// relation, so that an unbalanced relation can be traced back to the components using it.
+pub fn assert_claimed_sum_zero(components: &[&dyn MachineComponent], ctx: &mut AssertContext) {
+    let (claimed_sums, log) = record_relations(|| {
+        components
+            .iter()
+            .map(|component| components_claimed_sum(&[*component], ctx))
+            .collect::<Vec<_>>()
+    });
+    let total_sum: SecureField = claimed_sums.iter().sum();
+    if total_sum.is_zero() {
+        return;
+    }
+
+    let mut message = format!("claimed sum is not zero: {total_sum}\ncomponent contributions:");
+    for (component, claimed_sum) in components.iter().zip(&claimed_sums) {
+        message += &format!("\n  {}: {claimed_sum}", component.ordering_key());
+    }
+    message += "\nrelation balances:";
+    for (relation, sum) in ctx.lookup_elements.relation_sums(&log) {
+        let status = if sum.is_zero() { "balanced" } else { "unbalanced" };
+        message += &format!("\n  {relation}: {sum} ({status})");
+    }
+    panic!("{message}");
+}
+
+/// Returns LUI and ADDI instructions that load `value` into the register `reg`.
+pub fn load_word(reg: u8, value: u32) -> [Instruction; 2] {
+    // ADDI sign-extends its immediate, round the upper part to compensate
+    let upper = value.wrapping_add(0x800) >> 12;
+    [
+        Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), reg, 0, upper),
+        Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), reg, reg, value & 0xFFF),
+    ]
+}
+
+/// Asserts that a register-register `opcode` computes `a` for each `(b, c, a)` entry of a reference
+/// table.
+///
+/// Entries run as a single program that loads `b` and `c` with LUI and ADDI, every entry is a row
+/// of the `component` trace. The result is read from the `a_val` column of its main trace, which
+/// ties the committed value to the expected one. Constraints of `component` must hold on these
+/// rows, and the logup sum must be zero. `multiplicities` should contain the multiplicity tables
+/// used by `component`, apart from range checks.
+pub fn assert_reference_table<C>(
+    opcode: BuiltinOpcode,
+    component: C,
+    a_val: C::MainColumn,
+    table: &[(u32, u32, u32)],
+    multiplicities: &[&dyn MachineComponent],
+) where
+    C: BuiltInComponent + 'static + Sync,
+    C::LookupElements: 'static + Sync,
+{
+    let mut instr = Vec::new();
+    for &(b, c, _) in table {
+        instr.extend(load_word(1, b));
+        instr.extend(load_word(2, c));
+        instr.push(Instruction::new_ir(Opcode::from(opcode), 3, 1, 2));
+    }
+    let (view, program_trace) =
+        k_trace_direct(&vec![BasicBlock::new(instr)], 1).expect("error generating trace");
+
+    let main_trace =
+        component.generate_main_trace(&mut AssertContext::new(&program_trace, &view).side_note);
+    for (row_idx, &(b, c, a)) in table.iter().enumerate() {
+        assert_eq!(
+            main_trace.read_word(row_idx, a_val),
+            a,
+            "{opcode:?} {b:#010x}, {c:#010x}"
+        );
+    }
+
+    let base_components: [&dyn MachineComponent; 8] = [
+        &Cpu,
+        &CpuBoundary,
+        &RegisterMemory,
+        &RegisterMemoryBoundary,
+        &ProgramMemory,
+        &ProgramMemoryBoundary,
+        &ADDI,
+        &LUI,
+    ];
+    let range_components: [&dyn MachineComponent; 6] =
+        [&RANGE8, &RANGE16, &RANGE32, &RANGE64, &RANGE128, &RANGE256];
+    let all_components: Vec<&dyn MachineComponent> = base_components
+        .into_iter()
+        .chain([&component as &dyn MachineComponent])
+        .chain(multiplicities.iter().copied())
+        .chain(range_components)
+        .collect();
+    assert_claimed_sum_zero(&all_components, &mut AssertContext::new(&program_trace, &view));
+
+    assert_component(component, &mut AssertContext::new(&program_trace, &view));
+}
+
+/// Generates the traces of a component on both logup backends and asserts that the results are
+/// identical.
+///
+/// Each backend starts from a fresh [`AssertContext`], the main trace is regenerated along with
+/// the interaction trace.
+pub fn assert_backends_agree(component: &dyn MachineComponent, trace: &impl Trace, view: &View) {
+    let [(simd_main, simd_trace, simd_sum), (scalar_main, scalar_trace, scalar_sum)] =
+        [LogupBackend::Simd, LogupBackend::Scalar].map(|backend| {
+            let mut ctx = AssertContext::new(trace, view);
+            ctx.side_note.logup_backend = backend;
+            let component_trace = component.generate_component_trace(&mut ctx.side_note);
+            let main_trace: Vec<Vec<BaseField>> = component_trace
+                .original_trace
+                .iter()
+                .map(|col| col.to_cpu())
+                .collect();
+            let (interaction_trace, claimed_sum) = component.generate_interaction_trace(
+                component_trace,
+                &ctx.side_note,
+                &ctx.lookup_elements,
+            );
+            (main_trace, interaction_trace, claimed_sum)
+        });
+
+    assert_eq!(simd_main, scalar_main, "main trace mismatch");
+    assert_eq!(simd_trace.len(), scalar_trace.len());
+    for (col_idx, (simd_col, scalar_col)) in simd_trace.iter().zip(&scalar_trace).enumerate() {
+        assert_eq!(
+            simd_col.values.to_cpu(),
+            scalar_col.values.to_cpu(),
+            "interaction column {col_idx} mismatch"
+        );
+    }
+    assert_eq!(simd_sum, scalar_sum);
+}
+
+/// Returns indices of main trace columns that are not referenced by any constraint or lookup.
+///
+/// Constraints and intermediates are evaluated symbolically, columns pushed to the trace outside of
+/// the component's [`BuiltInComponent::MainColumn`] are included as long as they are read by the
+/// evaluator.
+pub fn unconstrained_columns<C>(component: &C) -> Vec<usize>
+where
+    C: BuiltInComponent + 'static + Sync,
+    C::LookupElements: 'static + Sync,
+{
+    let num_columns = component.trace_sizes(LOG_N_LANES)[ORIGINAL_TRACE_IDX].len();
+    let evaluator = BuiltInComponentEval::<C> {
+        component,
+        log_size: LOG_N_LANES,
+        lookup_elements: C::LookupElements::dummy(),
+    }
+    .evaluate(ExprEvaluator::new());
+
+    let mut referenced = HashSet::new();
+    for constraint in &evaluator.constraints {
+        ext_expr_columns(constraint, &mut referenced);
+    }
+    for (_, intermediate) in &evaluator.intermediates {
+        base_expr_columns(intermediate, &mut referenced);
+    }
+    for (_, intermediate) in &evaluator.ext_intermediates {
+        ext_expr_columns(intermediate, &mut referenced);
+    }
+
+    (0..num_columns)
+        .filter(|idx| !referenced.contains(idx))
+        .collect()
+}
+
+/// Collects indices of main trace columns read by the expression.
+fn base_expr_columns(expr: &BaseExpr, columns: &mut HashSet<usize>) {
+    match expr {
+        BaseExpr::Col(col) => {
+            if col.interaction == ORIGINAL_TRACE_IDX {
+                columns.insert(col.idx);
+            }
+        }
+        BaseExpr::Const(_) | BaseExpr::Param(_) => {}
+        BaseExpr::Add(a, b) | BaseExpr::Sub(a, b) | BaseExpr::Mul(a, b) => {
+            base_expr_columns(a, columns);
+            base_expr_columns(b, columns);
+        }
+        BaseExpr::Neg(a) | BaseExpr::Inv(a) => base_expr_columns(a, columns),
+    }
+}
+
+fn ext_expr_columns(expr: &ExtExpr, columns: &mut HashSet<usize>) {
+    match expr {
+        ExtExpr::SecureCol(coordinates) => coordinates
+            .iter()
+            .for_each(|coordinate| base_expr_columns(coordinate, columns)),
+        ExtExpr::Const(_) | ExtExpr::Param(_) => {}
+        ExtExpr::Add(a, b) | ExtExpr::Sub(a, b) | ExtExpr::Mul(a, b) => {
+            ext_expr_columns(a, columns);
+            ext_expr_columns(b, columns);
+        }
+        ExtExpr::Neg(a) => ext_expr_columns(a, columns),
+    }
+}
+
+/// Serializes the preprocessed and main traces of all base components into a flat byte string.
+///
+/// Each component is written as its log size followed by its columns as little-endian field
+/// elements, components appear in the order their traces are generated.
+pub fn serialize_traces(trace: &impl Trace, view: &View) -> Vec<u8> {
+    let mut side_note = SideNote::new(trace, view);
+    let mut bytes = Vec::new();
+    for component in BASE_COMPONENTS {
+        let component_trace = component.generate_component_trace(&mut side_note);
+        bytes.extend(component_trace.log_size().to_le_bytes());
+
+        let columns = component_trace
+            .preprocessed_trace
+            .iter()
+            .chain(&component_trace.original_trace);
+        for col in columns {
+            bytes.extend((0..col.len()).flat_map(|row| col.at(row).0.to_le_bytes()));
+        }
+    }
+    bytes
+}
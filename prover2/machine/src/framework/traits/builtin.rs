@@ -1,3 +1,4 @@
+use num_traits::Zero;
 use stwo::{
     core::{
         fields::{m31::BaseField, qm31::SecureField},
@@ -15,10 +16,17 @@ use nexus_vm_prover_trace::{builder::FinalizedTrace, component::ComponentTrace,
 
 use crate::{
     lookups::{AllLookupElements, ComponentLookupElements},
-    side_note::{program::ProgramTraceRef, SideNote},
+    side_note::{custom::StepHook, program::ProgramTraceRef, SideNote},
 };
 
 pub trait BuiltInComponent {
+    /// Unique name of the component, determines its position in the proof.
+    ///
+    /// See [`MachineComponent::ordering_key`].
+    ///
+    /// [`MachineComponent::ordering_key`]: crate::framework::MachineComponent::ordering_key
+    const NAME: &'static str;
+
     /// Logarithmic bound for the maximum constraint degree.
     const LOG_CONSTRAINT_DEGREE_BOUND: u32 = 1;
 
@@ -31,12 +39,47 @@ pub trait BuiltInComponent {
     /// Lookups elements used by the component.
     type LookupElements: ComponentLookupElements;
 
+    /// Hook run on every program step before any trace is generated.
+    ///
+    /// Components that own a lookup table use it to count lookups into a custom side note,
+    /// which is complete by the time their main trace is generated regardless of the order of
+    /// components.
+    ///
+    /// A table that defines its own relation must also register it in the `register_relation!`
+    /// list of `lookups/mod.rs`, otherwise its lookup elements are never drawn. Relations of
+    /// test-only components are registered under `#[cfg(test)]`, see `components::example_lookup`.
+    const STEP_HOOK: Option<StepHook> = None;
+
+    /// Returns names of the relations in [`Self::LookupElements`].
+    fn relation_names() -> Vec<&'static str> {
+        Self::LookupElements::relation_names()
+    }
+
+    /// Returns the part of the component's logup sum that the verifier balances from public
+    /// values, e.g. the claimed cost of the execution.
+    ///
+    /// Logup sums of a complete set of components add up to the sum of these terms.
+    fn public_logup_sum(
+        &self,
+        _side_note: &SideNote,
+        _lookup_elements: &AllLookupElements,
+    ) -> SecureField {
+        SecureField::zero()
+    }
+
     fn generate_preprocessed_trace(
         &self,
         log_size: u32,
         program: &ProgramTraceRef,
     ) -> FinalizedTrace;
 
+    /// Returns the log size of the trace generated by [`Self::generate_main_trace`], without
+    /// generating it.
+    ///
+    /// Used to check the row budget before any trace is generated, see
+    /// [`crate::ProvingConfig::with_row_budget`].
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32;
+
     fn generate_main_trace(&self, side_note: &mut SideNote) -> FinalizedTrace;
 
     fn generate_interaction_trace(
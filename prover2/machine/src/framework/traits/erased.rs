@@ -1,5 +1,6 @@
 //! Erased dyn-compatible version of the [`BuiltInComponent`] trait.
 
+use num_traits::Zero;
 use stwo::{
     core::{
         air::Component,
@@ -10,24 +11,37 @@ use stwo::{
         ColumnVec,
     },
     prover::{
-        backend::simd::SimdBackend,
+        backend::{simd::SimdBackend, Column},
         poly::{circle::CircleEvaluation, BitReversedOrder},
         ComponentProver,
     },
 };
-use stwo_constraint_framework::{FrameworkEval, InfoEvaluator, TraceLocationAllocator};
+use stwo_constraint_framework::{
+    expr::ExprEvaluator, FrameworkEval, InfoEvaluator, TraceLocationAllocator,
+};
 
-use nexus_vm_prover_air_column::AirColumn;
-use nexus_vm_prover_trace::component::ComponentTrace;
+use nexus_vm_prover_air_column::{AirColumn, PreprocessedAirColumn};
+use nexus_vm_prover_trace::{component::ComponentTrace, eval::ORIGINAL_TRACE_IDX};
 
 use super::builtin::BuiltInComponent;
 use crate::{
-    framework::eval::{BuiltInComponentEval, FrameworkComponent},
+    framework::{
+        bounds,
+        eval::{BuiltInComponentEval, FrameworkComponent},
+        row_eval::{self, ConstraintViolation},
+    },
     lookups::{AllLookupElements, ComponentLookupElements},
-    side_note::{program::ProgramTraceRef, SideNote},
+    report::ExpressionBound,
+    side_note::{custom::StepHook, program::ProgramTraceRef, SideNote},
 };
 
 pub trait MachineComponent {
+    /// Returns the key that determines the position of the component in the proof.
+    ///
+    /// Components are committed in the order of their keys, which makes the transcript
+    /// independent of the order in which the component set was assembled.
+    fn ordering_key(&self) -> &'static str;
+
     /// Returns the log size of the evaluation domain.
     fn max_constraint_log_degree_bound(&self, log_size: u32) -> u32;
 
@@ -37,6 +51,68 @@ pub trait MachineComponent {
     /// Returns the log_sizes of each preprocessed columns
     fn preprocessed_trace_sizes(&self, log_size: u32) -> Vec<u32>;
 
+    /// Returns the number of constraints of the component's circuit.
+    fn n_constraints(&self) -> usize;
+
+    /// Returns names of the relations used by the component.
+    fn relation_names(&self) -> Vec<&'static str>;
+
+    /// Returns the part of the component's logup sum that the verifier balances from public
+    /// values, see [`BuiltInComponent::public_logup_sum`].
+    fn public_logup_sum(
+        &self,
+        _side_note: &SideNote,
+        _lookup_elements: &AllLookupElements,
+    ) -> SecureField {
+        SecureField::zero()
+    }
+
+    /// Checks that the main trace has as many columns as the component's constraints read,
+    /// including decoding and other columns appended after [`BuiltInComponent::MainColumn`].
+    fn validate_trace_width(
+        &self,
+        component_trace: &ComponentTrace,
+    ) -> Result<(), TraceWidthMismatch> {
+        let expected = self.trace_sizes(component_trace.log_size())[ORIGINAL_TRACE_IDX].len();
+        let actual = component_trace.original_trace.len();
+        if expected != actual {
+            return Err(TraceWidthMismatch {
+                component: self.ordering_key(),
+                expected,
+                actual,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that every preprocessed column has the log size of the main trace, both are
+    /// committed over the same domain.
+    fn validate_trace_log_sizes(
+        &self,
+        component_trace: &ComponentTrace,
+    ) -> Result<(), TraceLogSizeMismatch> {
+        let main = component_trace.log_size();
+        let mismatch = component_trace
+            .preprocessed_trace
+            .iter()
+            .map(|col| col.len().ilog2())
+            .find(|&log_size| log_size != main);
+        match mismatch {
+            Some(preprocessed) => Err(TraceLogSizeMismatch {
+                component: self.ordering_key(),
+                main,
+                preprocessed,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the hook the component runs on every program step, see
+    /// [`BuiltInComponent::STEP_HOOK`].
+    fn step_hook(&self) -> Option<StepHook> {
+        None
+    }
+
     /// Inserts component's lookup elements into the mapping.
     ///
     /// This method should be infallible: all lookup elements are shared by multiple components.
@@ -53,6 +129,10 @@ pub trait MachineComponent {
         program: &ProgramTraceRef,
     ) -> ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>;
 
+    /// Returns the log size of the component's traces, computed from the side note without
+    /// generating them.
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32;
+
     fn generate_component_trace(&self, side_note: &mut SideNote) -> ComponentTrace;
 
     fn generate_interaction_trace(
@@ -65,6 +145,20 @@ pub trait MachineComponent {
         SecureField,
     );
 
+    /// Evaluates the component's constraints over the integers on every row of its main trace,
+    /// see [`crate::ProvingConfig::with_expression_bounds`].
+    fn expression_bounds(&self, component_trace: &ComponentTrace) -> Vec<ExpressionBound>;
+
+    /// Evaluates the component's constraints on every row of its traces, without committing to
+    /// them, and returns the first row on which some of them don't vanish.
+    fn check_constraints(
+        &self,
+        component_trace: &ComponentTrace,
+        interaction_trace: ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        lookup_elements: &AllLookupElements,
+        claimed_sum: SecureField,
+    ) -> Result<(), ConstraintViolation>;
+
     fn to_component_prover<'a>(
         &'a self,
         tree_span_provider: &mut TraceLocationAllocator,
@@ -82,11 +176,68 @@ pub trait MachineComponent {
     ) -> Box<dyn Component + 'a>;
 }
 
+/// Error returned by [`MachineComponent::validate_trace_width`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceWidthMismatch {
+    pub component: &'static str,
+    /// Number of main trace columns read by the constraints.
+    pub expected: usize,
+    /// Number of columns in the generated main trace.
+    pub actual: usize,
+}
+
+impl std::fmt::Display for TraceWidthMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: main trace has {} columns, but constraints read {}",
+            self.component, self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for TraceWidthMismatch {}
+
+/// Error returned by [`MachineComponent::validate_trace_log_sizes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceLogSizeMismatch {
+    pub component: &'static str,
+    /// Log size of the main trace.
+    pub main: u32,
+    /// Log size of the first preprocessed column that differs from the main trace.
+    pub preprocessed: u32,
+}
+
+impl std::fmt::Display for TraceLogSizeMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: preprocessed trace has log size {}, but main trace has {}",
+            self.component, self.preprocessed, self.main
+        )
+    }
+}
+
+impl std::error::Error for TraceLogSizeMismatch {}
+
+/// Returns components sorted by their [`MachineComponent::ordering_key`].
+pub(crate) fn sort_components<'a>(
+    components: &[&'a dyn MachineComponent],
+) -> Vec<&'a dyn MachineComponent> {
+    let mut sorted = components.to_vec();
+    sorted.sort_by_key(|c| c.ordering_key());
+    sorted
+}
+
 impl<C: BuiltInComponent> MachineComponent for C
 where
     C: 'static + Sync,
     C::LookupElements: Sync + 'static,
 {
+    fn ordering_key(&self) -> &'static str {
+        C::NAME
+    }
+
     fn max_constraint_log_degree_bound(&self, log_size: u32) -> u32 {
         BuiltInComponentEval::<C>::max_constraint_log_degree_bound(log_size)
     }
@@ -107,6 +258,32 @@ where
         vec![log_size; C::PreprocessedColumn::COLUMNS_NUM]
     }
 
+    fn n_constraints(&self) -> usize {
+        BuiltInComponentEval::<C> {
+            component: self,
+            log_size: 0,
+            lookup_elements: C::LookupElements::dummy(),
+        }
+        .evaluate(InfoEvaluator::empty())
+        .n_constraints
+    }
+
+    fn relation_names(&self) -> Vec<&'static str> {
+        <C as BuiltInComponent>::relation_names()
+    }
+
+    fn public_logup_sum(
+        &self,
+        side_note: &SideNote,
+        lookup_elements: &AllLookupElements,
+    ) -> SecureField {
+        <C as BuiltInComponent>::public_logup_sum(self, side_note, lookup_elements)
+    }
+
+    fn step_hook(&self) -> Option<StepHook> {
+        C::STEP_HOOK
+    }
+
     fn draw_lookup_elements(
         &self,
         lookup_elements: &mut AllLookupElements,
@@ -130,21 +307,35 @@ where
             .collect()
     }
 
+    fn main_trace_log_size(&self, side_note: &SideNote) -> u32 {
+        <C as BuiltInComponent>::main_trace_log_size(self, side_note)
+    }
+
     fn generate_component_trace(&self, side_note: &mut SideNote) -> ComponentTrace {
         let original_trace = <C as BuiltInComponent>::generate_main_trace(self, side_note);
 
         let log_size = original_trace.log_size;
+        debug_assert_eq!(
+            log_size,
+            <C as BuiltInComponent>::main_trace_log_size(self, side_note),
+            "{}: main trace log size differs from the projected one",
+            C::NAME,
+        );
         let preprocessed_trace = <C as BuiltInComponent>::generate_preprocessed_trace(
             self,
             log_size,
             &side_note.program,
         );
 
-        ComponentTrace {
+        let component_trace = ComponentTrace {
             log_size,
             preprocessed_trace: preprocessed_trace.cols,
             original_trace: original_trace.cols,
+        };
+        if let Err(err) = self.validate_trace_log_sizes(&component_trace) {
+            panic!("{err}");
         }
+        component_trace
     }
 
     fn generate_interaction_trace(
@@ -156,6 +347,9 @@ where
         ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
         SecureField,
     ) {
+        if let Err(err) = self.validate_trace_width(&component_trace) {
+            panic!("{err}");
+        }
         <C as BuiltInComponent>::generate_interaction_trace(
             self,
             component_trace,
@@ -164,6 +358,40 @@ where
         )
     }
 
+    fn expression_bounds(&self, component_trace: &ComponentTrace) -> Vec<ExpressionBound> {
+        let evaluator = BuiltInComponentEval::<C> {
+            component: self,
+            log_size: component_trace.log_size(),
+            lookup_elements: C::LookupElements::dummy(),
+        }
+        .evaluate(ExprEvaluator::new());
+        bounds::expression_bounds(
+            &evaluator,
+            C::PreprocessedColumn::PREPROCESSED_IDS,
+            component_trace,
+        )
+    }
+
+    fn check_constraints(
+        &self,
+        component_trace: &ComponentTrace,
+        interaction_trace: ColumnVec<CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>>,
+        lookup_elements: &AllLookupElements,
+        claimed_sum: SecureField,
+    ) -> Result<(), ConstraintViolation> {
+        let component_eval = BuiltInComponentEval::<C> {
+            component: self,
+            log_size: component_trace.log_size(),
+            lookup_elements: C::LookupElements::get(lookup_elements),
+        };
+        row_eval::check_constraints(
+            &component_eval,
+            component_trace,
+            interaction_trace,
+            claimed_sum,
+        )
+    }
+
     fn to_component_prover<'a>(
         &'a self,
         tree_span_provider: &mut TraceLocationAllocator,
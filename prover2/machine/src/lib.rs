@@ -3,8 +3,15 @@ mod framework;
 mod lookups;
 mod side_note;
 
+mod bisect;
+mod check;
+mod compact;
+mod config;
+mod cost;
 mod prove;
+mod report;
 mod verify;
+mod watch;
 
 const BASE_COMPONENTS: &[&dyn framework::MachineComponent] = &[
     &components::Cpu,
@@ -20,6 +27,14 @@ const BASE_COMPONENTS: &[&dyn framework::MachineComponent] = &[
     &components::ADD,
     &components::ADDI,
     &components::SUB,
+    &components::MUL,
+    &components::MULH,
+    &components::MULHU,
+    &components::MULHSU,
+    &components::DIV,
+    &components::DIVU,
+    &components::REM,
+    &components::REMU,
     &components::SLTU,
     &components::SLTIU,
     &components::SLT,
@@ -56,6 +71,7 @@ const BASE_COMPONENTS: &[&dyn framework::MachineComponent] = &[
     &components::AUIPC,
     &components::ECALL,
     &components::BitwiseMultiplicity,
+    &components::ProductMultiplicity,
     &components::RANGE8,
     &components::RANGE16,
     &components::RANGE32,
@@ -64,5 +80,20 @@ const BASE_COMPONENTS: &[&dyn framework::MachineComponent] = &[
     &components::RANGE256,
 ];
 
-pub use prove::{prove, Proof};
-pub use verify::verify;
+pub use bisect::{bisect_failing_prefix, check_prefix};
+pub use check::{check, CheckError};
+pub use compact::{verify_compact, verify_compact_with_config, CompactProof, StrippedData};
+pub use components::RegisterSnapshot;
+pub use config::ProvingConfig;
+pub use cost::{opcode_cost, step_cost, trace_cost};
+pub use lookups::{record_relations, LogupBackend, RelationLogEntry};
+pub use prove::{
+    prove, prove_with_config, prove_with_report, prove_with_source_map, Proof, ProvingError,
+    TraceGenError,
+};
+pub use report::{ComponentReport, ExpressionBound, ProvingReport, TimingBreakdown};
+pub use verify::{
+    verify, verify_batch, verify_with_config, verify_with_step_bound,
+    verify_with_step_bound_with_config, BatchVerificationError,
+};
+pub use watch::{first_violation, prove_with_watchpoint, WatchpointError};
@@ -1,10 +1,13 @@
 //! Interaction trace builder for component framework.
 
+use std::cell::RefCell;
+
+use num_traits::Zero;
 use stwo::{
     core::fields::{m31::BaseField, qm31::SecureField},
     prover::{
         backend::simd::{
-            m31::{PackedBaseField, LOG_N_LANES},
+            m31::{PackedBaseField, LOG_N_LANES, N_LANES},
             qm31::PackedSecureField,
             SimdBackend,
         },
@@ -13,25 +16,61 @@ use stwo::{
 };
 use stwo_constraint_framework::{LogupTraceGenerator, Relation};
 
-use super::RegisteredLookupBound;
+use super::{relation_name, RegisteredLookupBound};
 use nexus_vm_prover_trace::component::FinalizedColumn;
 
 type LogUpFrac = (PackedSecureField, PackedSecureField);
 
+/// Backend used for computing logup fractions, see [`crate::ProvingConfig::with_logup_backend`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogupBackend {
+    /// Fractions are computed on packed values.
+    #[default]
+    Simd,
+    /// Multiplicities and lookup tuples are computed lane by lane, used for isolating
+    /// SIMD-specific bugs.
+    Scalar,
+}
+
+/// Provide or consume of a lookup tuple on a single row of the component trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationLogEntry {
+    pub relation: &'static str,
+    pub row: usize,
+    /// Positive for provides, negative for consumes.
+    pub multiplicity: SecureField,
+    pub values: Vec<BaseField>,
+}
+
+thread_local! {
+    static RELATION_LOG: RefCell<Option<Vec<RelationLogEntry>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns every relation entry with non-zero multiplicity added by logup trace
+/// builders on the current thread.
+pub fn record_relations<R>(f: impl FnOnce() -> R) -> (R, Vec<RelationLogEntry>) {
+    let prev = RELATION_LOG.replace(Some(Vec::new()));
+    let result = f();
+    let log = RELATION_LOG.replace(prev).unwrap_or_default();
+    (result, log)
+}
+
 /// Helper struct for generating logup trace in pairs.
 pub struct LogupTraceBuilder {
     pub log_size: u32,
     pub logup_trace_gen: LogupTraceGenerator,
     pub pending_logup: Vec<LogUpFrac>,
+    pub backend: LogupBackend,
 }
 
 impl LogupTraceBuilder {
-    pub fn new(log_size: u32) -> Self {
+    pub fn new(log_size: u32, backend: LogupBackend) -> Self {
         assert!(log_size >= LOG_N_LANES);
         Self {
             log_size,
             logup_trace_gen: LogupTraceGenerator::new(log_size),
             pending_logup: Vec::with_capacity(1 << (log_size - LOG_N_LANES)),
+            backend,
         }
     }
 }
@@ -39,6 +78,7 @@ impl LogupTraceBuilder {
 impl LogupTraceBuilder {
     fn iter_logup_fractions<'a, const N: usize, R, F>(
         log_size: u32,
+        backend: LogupBackend,
         relation: &'a R,
         mult_columns: &'a [FinalizedColumn<'a>; N],
         mult_expr: F,
@@ -50,15 +90,78 @@ impl LogupTraceBuilder {
     {
         (0..1 << (log_size - LOG_N_LANES)).map(move |vec_idx| {
             let mult_vals = mult_columns.clone().map(|col| col.at(vec_idx));
-            let p0 = mult_expr(mult_vals);
-
             let tuple: Vec<PackedBaseField> = tuple.iter().map(|col| col.at(vec_idx)).collect();
-            let p1: PackedSecureField = relation.as_relation_ref().combine(&tuple);
-
-            (p0, p1)
+            match backend {
+                LogupBackend::Simd => (
+                    mult_expr(mult_vals),
+                    relation.as_relation_ref().combine(&tuple),
+                ),
+                LogupBackend::Scalar => (
+                    Self::eval_scalar(&mult_expr, mult_vals),
+                    Self::combine_scalar(relation, &tuple),
+                ),
+            }
         })
     }
 
+    fn relation_entries<const N: usize, R, F>(
+        log_size: u32,
+        mult_columns: &[FinalizedColumn; N],
+        mult_expr: &F,
+        tuple: &[FinalizedColumn],
+    ) -> Vec<RelationLogEntry>
+    where
+        R: RegisteredLookupBound,
+        F: Fn([PackedBaseField; N]) -> PackedSecureField,
+    {
+        let relation = relation_name::<R>();
+        let mut entries = Vec::new();
+        for vec_idx in 0..1 << (log_size - LOG_N_LANES) {
+            let mult = mult_expr(mult_columns.clone().map(|col| col.at(vec_idx))).to_array();
+            let lanes: Vec<[BaseField; N_LANES]> =
+                tuple.iter().map(|col| col.at(vec_idx).to_array()).collect();
+
+            for (lane, multiplicity) in mult.into_iter().enumerate() {
+                if multiplicity.is_zero() {
+                    continue;
+                }
+                entries.push(RelationLogEntry {
+                    relation,
+                    row: vec_idx * N_LANES + lane,
+                    multiplicity,
+                    values: lanes.iter().map(|v| v[lane]).collect(),
+                });
+            }
+        }
+        entries
+    }
+
+    /// Evaluates `mult_expr` separately on every lane, with the values of the lane broadcast to
+    /// all lanes of its arguments.
+    fn eval_scalar<const N: usize>(
+        mult_expr: &impl Fn([PackedBaseField; N]) -> PackedSecureField,
+        mult_vals: [PackedBaseField; N],
+    ) -> PackedSecureField {
+        let lanes: [[BaseField; N_LANES]; N] = mult_vals.map(|v| v.to_array());
+        PackedSecureField::from_array(std::array::from_fn(|lane| {
+            let values = lanes.map(|v| PackedBaseField::broadcast(v[lane]));
+            mult_expr(values).to_array()[0]
+        }))
+    }
+
+    fn combine_scalar<R: RegisteredLookupBound>(
+        relation: &R,
+        tuple: &[PackedBaseField],
+    ) -> PackedSecureField {
+        let lanes: Vec<[BaseField; N_LANES]> = tuple.iter().map(|v| v.to_array()).collect();
+        PackedSecureField::from_array(std::array::from_fn(|lane| {
+            let values: Vec<BaseField> = lanes.iter().map(|v| v[lane]).collect();
+            relation
+                .as_relation_ref::<BaseField, SecureField>()
+                .combine(&values)
+        }))
+    }
+
     pub fn add_to_relation<'a, T, R>(
         &mut self,
         relation: &'a R,
@@ -82,8 +185,24 @@ impl LogupTraceBuilder {
         R: RegisteredLookupBound,
         F: Fn([PackedBaseField; N]) -> PackedSecureField,
     {
-        let frac_iter =
-            Self::iter_logup_fractions(self.log_size, relation, &mult_columns, mult_expr, tuple);
+        if RELATION_LOG.with_borrow(Option::is_some) {
+            let entries =
+                Self::relation_entries::<N, R, F>(self.log_size, &mult_columns, &mult_expr, tuple);
+            RELATION_LOG.with_borrow_mut(|log| {
+                if let Some(log) = log {
+                    log.extend(entries);
+                }
+            });
+        }
+
+        let frac_iter = Self::iter_logup_fractions(
+            self.log_size,
+            self.backend,
+            relation,
+            &mult_columns,
+            mult_expr,
+            tuple,
+        );
 
         if self.pending_logup.is_empty() {
             self.pending_logup.extend(frac_iter);
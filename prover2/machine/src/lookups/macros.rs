@@ -6,11 +6,14 @@ macro_rules! replace_expr {
 pub(super) use replace_expr;
 
 macro_rules! register_relation {
-    (enum $_enum:ident { $( $name:ident ),* $(,)? }; $_vis:vis trait $_trait:ident {}) => {
+    (
+        enum $_enum:ident { $( $(#[$meta:meta])* $name:ident ),* $(,)? };
+        $_vis:vis trait $_trait:ident {}
+    ) => {
         #[allow(clippy::enum_variant_names)]
         #[derive(Debug, Clone)]
         pub enum $_enum {
-            $($name(Box<$name>),)*
+            $($(#[$meta])* $name(Box<$name>),)*
         }
 
         #[allow(unused)]
@@ -35,12 +38,14 @@ macro_rules! register_relation {
         }
 
         $(
+            $(#[$meta])*
             impl From<$name> for $_enum {
                 fn from(it: $name) -> Self {
                     Self::$name(Box::new(it))
                 }
             }
 
+            $(#[$meta])*
             impl $_trait for $name {
                 type Relation<
                     F: Clone,
@@ -78,12 +83,35 @@ macro_rules! register_relation {
             #![allow(unused)]
 
             const NUM_VARIANTS: usize = {
-                <[()]>::len(&[$($crate::lookups::macros::replace_expr!($name ())),*])
+                <[()]>::len(&[$($(#[$meta])* $crate::lookups::macros::replace_expr!($name ())),*])
             };
 
+            /// Returns the name of the relation, as reported in `RelationLogEntry::relation`.
+            fn relation_name(&self) -> &'static str {
+                match self {
+                    $($(#[$meta])* Self::$name(_) => relation_name::<$name>(),)*
+                }
+            }
+
+            fn combine(
+                &self,
+                values: &[stwo::core::fields::m31::BaseField],
+            ) -> stwo::core::fields::qm31::SecureField {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$name(inner) => <$name as stwo_constraint_framework::Relation<
+                            stwo::core::fields::m31::BaseField,
+                            stwo::core::fields::qm31::SecureField,
+                        >>::combine(inner, values),
+                    )*
+                }
+            }
+
             fn dummy_array() -> [(std::any::TypeId, Self); Self::NUM_VARIANTS] {
                 [
                     $(
+                        $(#[$meta])*
                         (std::any::TypeId::of::<$name>(), Self::$name(Box::new($name::dummy()))),
                     )*
                 ]
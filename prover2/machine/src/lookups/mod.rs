@@ -1,4 +1,7 @@
-use std::{any::TypeId, collections::HashMap};
+use std::{
+    any::TypeId,
+    collections::{hash_map::Entry, HashMap},
+};
 
 use impl_trait_for_tuples::impl_for_tuples;
 
@@ -8,21 +11,25 @@ mod range_check;
 mod relations;
 
 pub use self::{
-    logup_trace_builder::LogupTraceBuilder,
+    logup_trace_builder::{record_relations, LogupBackend, LogupTraceBuilder, RelationLogEntry},
     range_check::{
         Range128LookupElements, Range16LookupElements, Range256LookupElements,
         Range32LookupElements, Range64LookupElements, Range8LookupElements,
         RangeCheckLookupElements,
     },
     relations::{
-        BitwiseInstrLookupElements, InstToProgMemoryLookupElements, InstToRamLookupElements,
-        InstToRegisterMemoryLookupElements, ProgramExecutionLookupElements,
-        ProgramMemoryReadLookupElements, RamReadAddressLookupElements, RamReadWriteLookupElements,
-        RamUniqueAddrLookupElements, RamWriteAddressLookupElements, RegisterMemoryLookupElements,
+        AddCarryOutLookupElements, BitwiseInstrLookupElements, InstToProgMemoryLookupElements,
+        InstToRamLookupElements, InstToRegisterMemoryLookupElements, ProductLookupElements,
+        ProgramCostLookupElements, ProgramExecutionLookupElements, ProgramMemoryReadLookupElements,
+        RamReadAddressLookupElements, RamReadWriteLookupElements, RamUniqueAddrLookupElements,
+        RamWriteAddressLookupElements, RegisterMemoryLookupElements,
     },
 };
 pub use range_check::RangeLookupBound;
 
+#[cfg(test)]
+use crate::components::OpcodeLookupElements;
+
 macros::register_relation! {
     enum RelationVariant {
         ProgramExecutionLookupElements,
@@ -35,13 +42,18 @@ macros::register_relation! {
         RamWriteAddressLookupElements,
         ProgramMemoryReadLookupElements,
         InstToProgMemoryLookupElements,
+        ProgramCostLookupElements,
         BitwiseInstrLookupElements,
+        ProductLookupElements,
+        AddCarryOutLookupElements,
         Range8LookupElements,
         Range16LookupElements,
         Range32LookupElements,
         Range64LookupElements,
         Range128LookupElements,
         Range256LookupElements,
+        #[cfg(test)]
+        OpcodeLookupElements,
     };
     pub(crate) trait RegisteredLookupBound {}
 }
@@ -49,6 +61,88 @@ macros::register_relation! {
 #[derive(Default, Debug, Clone)]
 pub struct AllLookupElements(HashMap<TypeId, RelationVariant>);
 
+/// Error returned when lookup elements for the same relation are registered more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateRelationError {
+    pub relation: &'static str,
+}
+
+impl std::fmt::Display for DuplicateRelationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "lookup elements for {} are already registered",
+            self.relation
+        )
+    }
+}
+
+impl std::error::Error for DuplicateRelationError {}
+
+impl DuplicateRelationError {
+    /// Returns an error for the first relation that appears more than once in `names`.
+    fn check_unique(names: &[&'static str]) -> Result<(), Self> {
+        for (i, &relation) in names.iter().enumerate() {
+            if names[..i].contains(&relation) {
+                return Err(Self { relation });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AllLookupElements {
+    /// Registers lookup elements for the relation `T`.
+    ///
+    /// Each relation must be drawn exactly once, inserting the same relation type twice is
+    /// rejected.
+    pub(crate) fn insert<T: RegisteredLookupBound>(
+        &mut self,
+        lookup_elements: T,
+    ) -> Result<(), DuplicateRelationError> {
+        match self.0.entry(TypeId::of::<T>()) {
+            Entry::Occupied(_) => Err(DuplicateRelationError {
+                relation: relation_name::<T>(),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(lookup_elements.into());
+                Ok(())
+            }
+        }
+    }
+
+    fn contains<T: RegisteredLookupBound>(&self) -> bool {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Sums logup fractions of the recorded entries separately for each relation.
+    ///
+    /// Claimed sums of components mix fractions of all relations they use, the breakdown shows
+    /// whether each relation balances on its own.
+    #[cfg(test)]
+    pub(crate) fn relation_sums(
+        &self,
+        entries: &[RelationLogEntry],
+    ) -> std::collections::BTreeMap<&'static str, stwo::core::fields::qm31::SecureField> {
+        let relations: HashMap<&'static str, &RelationVariant> = self
+            .0
+            .values()
+            .map(|relation| (relation.relation_name(), relation))
+            .collect();
+        let mut sums = std::collections::BTreeMap::new();
+        for entry in entries {
+            let denom = relations
+                .get(entry.relation)
+                .expect("lookup elements weren't initialized")
+                .combine(&entry.values);
+            *sums
+                .entry(entry.relation)
+                .or_insert_with(num_traits::Zero::zero) += entry.multiplicity / denom;
+        }
+        sums
+    }
+}
+
 impl<T: RegisteredLookupBound> AsRef<T> for AllLookupElements {
     fn as_ref(&self) -> &T {
         let variant = self
@@ -62,6 +156,12 @@ impl<T: RegisteredLookupBound> AsRef<T> for AllLookupElements {
 mod private {
     pub trait Sealed {}
 }
+
+/// Returns the type name of a relation without the module path.
+fn relation_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
 impl<T: RegisteredLookupBound> private::Sealed for T {}
 
 pub(crate) trait ComponentLookupElements: private::Sealed {
@@ -69,6 +169,9 @@ pub(crate) trait ComponentLookupElements: private::Sealed {
 
     fn get(lookup_elements: &AllLookupElements) -> Self;
 
+    /// Returns names of all relations, in the order they appear in the elements.
+    fn relation_names() -> Vec<&'static str>;
+
     fn draw(
         lookup_elements: &mut AllLookupElements,
         channel: &mut impl stwo::core::channel::Channel,
@@ -85,15 +188,21 @@ impl<T: RegisteredLookupBound> ComponentLookupElements for T {
         this.clone()
     }
 
+    fn relation_names() -> Vec<&'static str> {
+        vec![relation_name::<Self>()]
+    }
+
     fn draw(
         lookup_elements: &mut AllLookupElements,
         channel: &mut impl stwo::core::channel::Channel,
     ) {
-        let type_id = TypeId::of::<Self>();
+        // components may share relations, elements are only drawn on the first request
+        if lookup_elements.contains::<Self>() {
+            return;
+        }
         lookup_elements
-            .0
-            .entry(type_id)
-            .or_insert_with(|| <Self as RegisteredLookupBound>::draw(channel).into());
+            .insert(<Self as RegisteredLookupBound>::draw(channel))
+            .expect("relation was checked to be absent");
     }
 }
 
@@ -111,10 +220,47 @@ impl ComponentLookupElements for T {
         for_tuples!( ( #( <T as ComponentLookupElements>::get(lookup_elements) ),* ) )
     }
 
+    fn relation_names() -> Vec<&'static str> {
+        let mut names = Vec::new();
+        for_tuples!( #( names.extend(<T as ComponentLookupElements>::relation_names()); )* );
+        names
+    }
+
     fn draw(
         lookup_elements: &mut AllLookupElements,
         channel: &mut impl stwo::core::channel::Channel,
     ) {
+        // relations shared with other components are skipped, but a component must not list the
+        // same relation twice
+        if let Err(err) = DuplicateRelationError::check_unique(&Self::relation_names()) {
+            panic!("{err}");
+        }
         for_tuples!( #( <T as ComponentLookupElements>::draw(lookup_elements, channel); )* );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_relation_rejected() {
+        let mut lookup_elements = AllLookupElements::default();
+        lookup_elements
+            .insert(InstToRamLookupElements::dummy())
+            .expect("first registration must succeed");
+
+        let err = lookup_elements
+            .insert(InstToRamLookupElements::dummy())
+            .unwrap_err();
+        assert_eq!(err.relation, "InstToRamLookupElements");
+    }
+
+    #[test]
+    #[should_panic(expected = "lookup elements for InstToRamLookupElements are already registered")]
+    fn duplicate_relation_in_component_rejected() {
+        let mut lookup_elements = AllLookupElements::default();
+        let channel = &mut stwo::core::channel::Blake2sChannel::default();
+        <(InstToRamLookupElements, InstToRamLookupElements)>::draw(&mut lookup_elements, channel);
+    }
+}
@@ -4,7 +4,7 @@ use stwo_constraint_framework::{EvalAtRow, RelationEntry};
 
 use nexus_vm_prover_trace::component::FinalizedColumn;
 
-use super::{private, AllLookupElements, ComponentLookupElements};
+use super::{private, relation_name, AllLookupElements, ComponentLookupElements};
 use crate::lookups::{LogupTraceBuilder, RegisteredLookupBound};
 
 // lookup single value at a time
@@ -58,6 +58,17 @@ impl ComponentLookupElements for RangeCheckLookupElements {
         }
     }
 
+    fn relation_names() -> Vec<&'static str> {
+        vec![
+            relation_name::<Range8LookupElements>(),
+            relation_name::<Range16LookupElements>(),
+            relation_name::<Range32LookupElements>(),
+            relation_name::<Range64LookupElements>(),
+            relation_name::<Range128LookupElements>(),
+            relation_name::<Range256LookupElements>(),
+        ]
+    }
+
     fn draw(_: &mut AllLookupElements, _: &mut impl Channel) {
         // handled by a corresponding multiplicity components
     }
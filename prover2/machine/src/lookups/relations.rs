@@ -2,7 +2,8 @@ use nexus_common::constants::WORD_SIZE_HALVED;
 use nexus_vm::WORD_SIZE;
 
 pub use multiplicity::{
-    BitwiseInstrLookupElements, RamReadAddressLookupElements, RamWriteAddressLookupElements,
+    BitwiseInstrLookupElements, ProductLookupElements, RamReadAddressLookupElements,
+    RamWriteAddressLookupElements,
 };
 
 /// Relations for multiplicity components, such as range checks and lookup tables.
@@ -11,6 +12,12 @@ mod multiplicity {
     const REL_BITWISE_INSTR_LOOKUP_SIZE: usize = 4;
     stwo_constraint_framework::relation!(BitwiseInstrLookupElements, REL_BITWISE_INSTR_LOOKUP_SIZE);
 
+    // (b, c, prod-low, prod-high)
+    //
+    // Bytes b and c, and the lower and upper bytes of their 16-bit product.
+    const REL_PRODUCT_LOOKUP_SIZE: usize = 4;
+    stwo_constraint_framework::relation!(ProductLookupElements, REL_PRODUCT_LOOKUP_SIZE);
+
     // memory address is a word
     const REL_RAM_READ_LOOKUP_SIZE: usize = nexus_vm::WORD_SIZE;
     const REL_RAM_WRITE_LOOKUP_SIZE: usize = nexus_vm::WORD_SIZE;
@@ -90,3 +97,18 @@ stwo_constraint_framework::relation!(
     InstToProgMemoryLookupElements,
     REL_CPU_TO_PROG_MEMORY_LOOKUP_SIZE
 );
+
+// (prog-init-flag)
+//
+// Provided by the program memory boundary with the cost weight of each instruction times the
+// number of times it was executed, the verifier consumes the claimed total cost. The flag is one
+// on every row that contributes, so all fractions share the same denominator.
+const REL_PROGRAM_COST_LOOKUP_SIZE: usize = 1;
+stwo_constraint_framework::relation!(ProgramCostLookupElements, REL_PROGRAM_COST_LOOKUP_SIZE);
+
+// (clk, carry-out)
+//
+// Provided by the addition component when it exposes the carry out of the 32-bit sum, clk is
+// split into half words and identifies the step.
+const REL_ADD_CARRY_OUT_LOOKUP_SIZE: usize = WORD_SIZE_HALVED + 1;
+stwo_constraint_framework::relation!(AddCarryOutLookupElements, REL_ADD_CARRY_OUT_LOOKUP_SIZE);
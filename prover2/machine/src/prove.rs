@@ -1,45 +1,264 @@
+use std::time::{Duration, Instant};
+
 use serde::{Deserialize, Serialize};
 use stwo::{
     core::{
         channel::{Blake2sChannel, Channel},
         fields::qm31::SecureField,
-        pcs::PcsConfig,
         poly::circle::CanonicCoset,
         proof::StarkProof,
         vcs::blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher},
     },
     prover::{
         backend::simd::SimdBackend, poly::circle::PolyOps, CommitmentSchemeProver, ComponentProver,
-        ProvingError,
     },
 };
 use stwo_constraint_framework::TraceLocationAllocator;
 
-use nexus_vm::{emulator::View, trace::Trace};
+use nexus_vm::{emulator::View, riscv::BuiltinOpcode, trace::Trace};
 use nexus_vm_prover_trace::{
     component::ComponentTrace,
     eval::{ORIGINAL_TRACE_IDX, PREPROCESSED_TRACE_IDX},
+    source::{SourceLocation, SourceMap},
 };
 
 use super::BASE_COMPONENTS;
-use crate::{lookups::AllLookupElements, side_note::SideNote};
+use crate::{
+    cost::side_note_cost,
+    framework::MachineComponent,
+    lookups::AllLookupElements,
+    report::{self, ComponentReport, ExpressionBound, ProvingReport, TimingBreakdown},
+    side_note::SideNote,
+    ProvingConfig,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Proof {
     pub stark_proof: StarkProof<Blake2sMerkleHasher>,
     pub claimed_sums: Vec<SecureField>,
     pub log_sizes: Vec<u32>,
+    /// Number of executed steps, bound to the final clock by the preprocessed trace.
+    pub num_steps: u32,
+    /// Total cost weight of the executed steps, see [`crate::opcode_cost`].
+    ///
+    /// Bound by the logup sum, a verifier can charge proportionally to the proving work.
+    pub cost: u32,
+}
+
+/// Error returned when component traces can't be generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceGenError {
+    /// Component traces have more rows in total than allowed by
+    /// [`ProvingConfig::with_row_budget`].
+    RowBudgetExceeded { budget: usize, rows: usize },
+    /// A load or store accesses an address that isn't a multiple of its width.
+    MisalignedAccess {
+        pc: u32,
+        address: u32,
+        alignment: u32,
+        /// Source location of the instruction, if known from the source map.
+        location: Option<SourceLocation>,
+    },
+}
+
+impl std::fmt::Display for TraceGenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RowBudgetExceeded { budget, rows } => {
+                write!(f, "trace has {rows} rows, exceeding the budget of {budget}")
+            }
+            Self::MisalignedAccess {
+                pc,
+                address,
+                alignment,
+                location,
+            } => {
+                write!(f, "address {address:#x} is not aligned to {alignment} bytes at ")?;
+                match location {
+                    Some(location) => write!(f, "{location} (pc = {pc:#x})"),
+                    None => write!(f, "pc = {pc:#x}"),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for TraceGenError {}
+
+/// Error returned by the prover.
+#[derive(Debug)]
+pub enum ProvingError {
+    TraceGen(TraceGenError),
+    Stark(stwo::prover::ProvingError),
+}
+
+impl std::fmt::Display for ProvingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TraceGen(error) => write!(f, "{error}"),
+            Self::Stark(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ProvingError {}
+
+impl From<TraceGenError> for ProvingError {
+    fn from(error: TraceGenError) -> Self {
+        Self::TraceGen(error)
+    }
 }
 
+impl From<stwo::prover::ProvingError> for ProvingError {
+    fn from(error: stwo::prover::ProvingError) -> Self {
+        Self::Stark(error)
+    }
+}
+
+/// Proves an execution trace against the program and memory described by the view.
+///
+/// The trace doesn't have to come from nexus-vm, traces produced by an external VM can be proven
+/// by filling in the same structures:
+///
+/// - Each [`Block`](nexus_vm::trace::Block) holds the register file as it was before its first
+///   step, followed by the steps in execution order.
+/// - Each [`Step`](nexus_vm::trace::Step) carries its timestamp (the global clock starts at 1 and
+///   is incremented by every step), `pc` and `next_pc`, the raw instruction word along with its
+///   decoded form, the value written to `rd` if any, and the memory records of loads and stores.
+/// - The view provides the program memory as `(pc, instruction word)` entries together with the
+///   initial memory images and public input and output, see [`View::new`].
 pub fn prove(trace: &impl Trace, view: &View) -> Result<Proof, ProvingError> {
-    let mut prover_side_note = SideNote::new(trace, view);
-    let components = BASE_COMPONENTS;
+    prove_with_config(trace, view, ProvingConfig::default())
+}
+
+/// Generates a proof using the given security parameters.
+///
+/// The proof can only be verified with the same config, see [`crate::verify_with_config`].
+pub fn prove_with_config(
+    trace: &impl Trace,
+    view: &View,
+    config: ProvingConfig,
+) -> Result<Proof, ProvingError> {
+    prove_components(BASE_COMPONENTS, trace, view, config)
+}
 
-    let traces: Vec<ComponentTrace> = components
+/// Generates a proof, resolving instructions that can't be proven to their source location.
+///
+/// The source map only affects error messages, the proof is the same as the one produced by
+/// [`prove_with_config`].
+pub fn prove_with_source_map(
+    trace: &impl Trace,
+    view: &View,
+    config: ProvingConfig,
+    source_map: &SourceMap,
+) -> Result<Proof, ProvingError> {
+    prove_components_with_report(BASE_COMPONENTS, trace, view, config, Some(source_map))
+        .map(|(proof, _)| proof)
+}
+
+/// Proves the trace with the given set of components.
+///
+/// Component traces are generated in the order of the slice, since multiplicity components
+/// depend on side notes filled by earlier components. The proof itself commits to components
+/// sorted by [`MachineComponent::ordering_key`], so any valid ordering yields the same transcript.
+pub(crate) fn prove_components(
+    components: &[&dyn MachineComponent],
+    trace: &impl Trace,
+    view: &View,
+    config: ProvingConfig,
+) -> Result<Proof, ProvingError> {
+    prove_components_with_report(components, trace, view, config, None).map(|(proof, _)| proof)
+}
+
+/// Generates a proof along with a [`ProvingReport`] of per-component statistics and timings.
+pub fn prove_with_report(
+    trace: &impl Trace,
+    view: &View,
+    config: ProvingConfig,
+) -> Result<(Proof, ProvingReport), ProvingError> {
+    prove_components_with_report(BASE_COMPONENTS, trace, view, config, None)
+}
+
+/// Rejects loads and stores with an unaligned address, components can't represent them.
+fn check_alignment(
+    side_note: &SideNote,
+    source_map: Option<&SourceMap>,
+) -> Result<(), TraceGenError> {
+    for program_step in side_note.iter_program_steps() {
+        let (base, alignment) = match program_step.step.instruction.opcode.builtin() {
+            Some(BuiltinOpcode::SW) => (program_step.get_value_a(), 4),
+            Some(BuiltinOpcode::SH) => (program_step.get_value_a(), 2),
+            Some(BuiltinOpcode::LW) => (program_step.get_value_b(), 4),
+            Some(BuiltinOpcode::LH | BuiltinOpcode::LHU) => (program_step.get_value_b(), 2),
+            _ => continue,
+        };
+        let (offset, _) = program_step.get_value_c();
+        let address = u32::from_le_bytes(base).wrapping_add(u32::from_le_bytes(offset));
+        if !address.is_multiple_of(alignment) {
+            return Err(TraceGenError::MisalignedAccess {
+                pc: program_step.step.pc,
+                address,
+                alignment,
+                location: source_map
+                    .and_then(|source_map| program_step.source_location(source_map))
+                    .cloned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn prove_components_with_report(
+    components: &[&dyn MachineComponent],
+    trace: &impl Trace,
+    view: &View,
+    config: ProvingConfig,
+    source_map: Option<&SourceMap>,
+) -> Result<(Proof, ProvingReport), ProvingError> {
+    let start = Instant::now();
+    let mut timing = TimingBreakdown::default();
+    let mut prover_side_note =
+        SideNote::new(trace, view).with_logup_backend(config.logup_backend);
+    check_alignment(&prover_side_note, source_map)?;
+    if let Some(budget) = config.row_budget {
+        let rows: usize = components
+            .iter()
+            .map(|c| 1usize << c.main_trace_log_size(&prover_side_note))
+            .sum();
+        if rows > budget {
+            return Err(TraceGenError::RowBudgetExceeded { budget, rows }.into());
+        }
+    }
+    prover_side_note.run_step_hooks(components.iter().filter_map(|c| c.step_hook()));
+
+    let mut component_traces: Vec<(&dyn MachineComponent, ComponentTrace, Duration)> =
+        Vec::with_capacity(components.len());
+    for &c in components {
+        let trace_start = Instant::now();
+        let component_trace = c.generate_component_trace(&mut prover_side_note);
+        component_traces.push((c, component_trace, trace_start.elapsed()));
+    }
+    component_traces.sort_by_key(|(c, _, _)| c.ordering_key());
+    let mut components = Vec::with_capacity(component_traces.len());
+    let mut traces = Vec::with_capacity(component_traces.len());
+    let mut trace_times = Vec::with_capacity(component_traces.len());
+    for (c, component_trace, trace_time) in component_traces {
+        components.push(c);
+        traces.push(component_trace);
+        trace_times.push(trace_time);
+    }
+    let log_sizes: Vec<u32> = traces.iter().map(ComponentTrace::log_size).collect();
+    timing.trace_gen = start.elapsed();
+    let expression_bounds: Vec<Option<Vec<ExpressionBound>>> = components
         .iter()
-        .map(|c| c.generate_component_trace(&mut prover_side_note))
+        .zip(&traces)
+        .map(|(c, component_trace)| {
+            config
+                .expression_bounds
+                .then(|| c.expression_bounds(component_trace))
+        })
         .collect();
-    let log_sizes: Vec<u32> = traces.iter().map(ComponentTrace::log_size).collect();
+    let commitment_start = Instant::now();
 
     let max_constraint_log_degree_bound = components
         .iter()
@@ -49,7 +268,7 @@ pub fn prove(trace: &impl Trace, view: &View) -> Result<Proof, ProvingError> {
         .unwrap_or(0);
 
     // Precompute twiddles.
-    let config = PcsConfig::default();
+    let config = config.pcs_config;
     let twiddles = SimdBackend::precompute_twiddles(
         CanonicCoset::new(max_constraint_log_degree_bound + config.fri_config.log_blowup_factor)
             .circle_domain()
@@ -66,6 +285,8 @@ pub fn prove(trace: &impl Trace, view: &View) -> Result<Proof, ProvingError> {
     log_sizes.iter().for_each(|log_size| {
         prover_channel.mix_u64(*log_size as u64);
     });
+    let cost = side_note_cost(&prover_side_note);
+    prover_channel.mix_u64(cost.into());
 
     // Preprocessed trace.
     let mut tree_builder = commitment_scheme.tree_builder();
@@ -93,9 +314,14 @@ pub fn prove(trace: &impl Trace, view: &View) -> Result<Proof, ProvingError> {
     let claimed_sums: Vec<SecureField> = components
         .iter()
         .zip(traces)
-        .map(|(c, component_trace)| {
+        .zip(&mut trace_times)
+        .map(|((c, component_trace), trace_time)| {
+            let interaction_start = Instant::now();
             let (interaction_trace, claimed_sum) =
                 c.generate_interaction_trace(component_trace, &prover_side_note, &lookup_elements);
+            let interaction_time = interaction_start.elapsed();
+            *trace_time += interaction_time;
+            timing.interaction_gen += interaction_time;
             tree_builder.extend_evals(interaction_trace);
 
             claimed_sum
@@ -103,9 +329,10 @@ pub fn prove(trace: &impl Trace, view: &View) -> Result<Proof, ProvingError> {
         .collect();
     prover_channel.mix_felts(&claimed_sums);
     tree_builder.commit(prover_channel);
+    timing.commitment = commitment_start.elapsed() - timing.interaction_gen;
 
     let tree_span_provider = &mut TraceLocationAllocator::default();
-    let components: Vec<Box<dyn ComponentProver<SimdBackend>>> = components
+    let component_provers: Vec<Box<dyn ComponentProver<SimdBackend>>> = components
         .iter()
         .zip(&log_sizes)
         .zip(&claimed_sums)
@@ -119,29 +346,67 @@ pub fn prove(trace: &impl Trace, view: &View) -> Result<Proof, ProvingError> {
         })
         .collect();
     let components_ref: Vec<&dyn ComponentProver<SimdBackend>> =
-        components.iter().map(|c| &**c).collect();
+        component_provers.iter().map(|c| &**c).collect();
 
+    let fri_start = Instant::now();
     let proof = stwo::prover::prove::<SimdBackend, Blake2sMerkleChannel>(
         &components_ref,
         prover_channel,
         commitment_scheme,
     )?;
+    timing.fri = fri_start.elapsed();
+
+    let component_reports = components
+        .iter()
+        .zip(&log_sizes)
+        .zip(&claimed_sums)
+        .zip(trace_times)
+        .zip(expression_bounds)
+        .map(
+            |((((c, &log_size), &claimed_sum), trace_time), expression_bounds)| ComponentReport {
+                name: c.ordering_key().to_string(),
+                log_size,
+                n_constraints: c.n_constraints(),
+                claimed_sum,
+                trace_time,
+                expression_bounds,
+            },
+        )
+        .collect();
+    let report = ProvingReport {
+        components: component_reports,
+        total_time: start.elapsed(),
+        timing,
+        peak_memory_bytes: report::peak_memory_bytes(),
+    };
 
-    Ok(Proof {
+    let proof = Proof {
         stark_proof: proof,
         claimed_sums,
         log_sizes,
-    })
+        num_steps: prover_side_note.program.num_steps,
+        cost,
+    };
+    Ok((proof, report))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::verify;
+    use crate::{
+        framework::{sort_components, test_utils::serialize_traces},
+        verify,
+    };
+    use nexus_common::{constants::ELF_TEXT_START, cpu::Registers};
     use nexus_vm::{
-        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
-        trace::k_trace_direct,
+        cpu::RegisterFile,
+        emulator::{LinearMemoryLayout, ProgramInfo, ProgramMemoryEntry},
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode, ProgramBuilder, Register},
+        system::seeded_private_input,
+        trace::{k_trace_direct, k_trace_direct_with_private_input, Block, Step, UniformTrace},
+        SyscallCode, WORD_SIZE,
     };
+    use stwo::prover::backend::simd::m31::LOG_N_LANES;
 
     #[test]
     fn prove_verify() {
@@ -159,4 +424,510 @@ mod tests {
         let proof = prove(&program_trace, &view).unwrap();
         verify(proof, &view).unwrap();
     }
+
+    #[test]
+    fn prove_verify_dev_config() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let config = ProvingConfig::dev();
+        let proof = prove_with_config(&program_trace, &view, config).unwrap();
+        crate::verify_with_config(proof.clone(), &view, config).unwrap();
+
+        // production verifier must reject proofs generated with dev parameters
+        assert!(verify(proof, &view).is_err());
+    }
+
+    #[test]
+    fn row_budget_exceeded() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        // every component trace has at least 2^LOG_N_LANES rows
+        let budget = 1 << LOG_N_LANES;
+        let config = ProvingConfig::dev().with_row_budget(budget);
+        let err = prove_with_config(&program_trace, &view, config).unwrap_err();
+
+        // the budget is checked against the projected size of the traces, which matches the
+        // generated ones
+        let proof = prove(&program_trace, &view).unwrap();
+        let expected_rows: usize = proof
+            .log_sizes
+            .iter()
+            .map(|&log_size| 1usize << log_size)
+            .sum();
+        assert!(matches!(
+            err,
+            ProvingError::TraceGen(TraceGenError::RowBudgetExceeded { budget: b, rows })
+                if b == budget && rows == expected_rows
+        ));
+    }
+
+    #[test]
+    fn prove_verify_multiple_blocks() {
+        let addi = Opcode::from(BuiltinOpcode::ADDI);
+        let add = Opcode::from(BuiltinOpcode::ADD);
+        let basic_blocks = ProgramBuilder::new()
+            .block("entry")
+            .push(Instruction::new_ir(addi.clone(), 1, 0, 1))
+            .jal(3, "target")
+            .block("skipped")
+            .push(Instruction::new_ir(addi, 1, 0, 100))
+            .block("target")
+            .push(Instruction::new_ir(add, 2, 1, 3))
+            .build();
+        let (view, program_trace) =
+            k_trace_direct(&basic_blocks, 1).expect("error generating trace");
+
+        let proof = prove(&program_trace, &view).unwrap();
+        verify(proof, &view).unwrap();
+    }
+
+    #[test]
+    fn prove_verify_recursive_factorial() {
+        let add = Opcode::from(BuiltinOpcode::ADD);
+        let addi = Opcode::from(BuiltinOpcode::ADDI);
+        let (sp, ra, a0) = (Register::X2 as u8, Register::X1 as u8, Register::X10 as u8);
+        // there is no multiplication component, n · (n − 1)! is computed by repeated addition
+        let basic_blocks = ProgramBuilder::new()
+            .block("entry")
+            // sp = 0x82000, a0 = 5
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), sp, 0, 0x82))
+            .push(Instruction::new_ir(addi.clone(), a0, 0, 5))
+            .jal(ra, "fact")
+            .jal(0, "done")
+            .block("fact")
+            // push ra and n
+            .push(Instruction::new_ir(addi.clone(), sp, sp, 0xFF8))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), sp, ra, 4))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), sp, a0, 0))
+            .branch(BuiltinOpcode::BNE, a0, 0, "recurse")
+            .push(Instruction::new_ir(addi.clone(), a0, 0, 1))
+            .jal(0, "return")
+            .block("recurse")
+            // a0 = (n − 1)!
+            .push(Instruction::new_ir(addi.clone(), a0, a0, 0xFFF))
+            .jal(ra, "fact")
+            // x11 = n, x12 = 0
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::LW), 11, sp, 0))
+            .push(Instruction::new_ir(addi.clone(), 12, 0, 0))
+            .block("mul")
+            .push(Instruction::new_ir(add.clone(), 12, 12, a0))
+            .push(Instruction::new_ir(addi.clone(), 11, 11, 0xFFF))
+            .branch(BuiltinOpcode::BNE, 11, 0, "mul")
+            .push(Instruction::new_ir(addi.clone(), a0, 12, 0))
+            .block("return")
+            // pop ra and return
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::LW), ra, sp, 4))
+            .push(Instruction::new_ir(addi, sp, sp, 8))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::JALR), 0, ra, 0))
+            .block("done")
+            .push(Instruction::new_ir(add, 13, a0, 0))
+            .build();
+        let (view, program_trace) =
+            k_trace_direct(&basic_blocks, 1).expect("error generating trace");
+        let last_step = &program_trace.blocks.last().unwrap().steps[0];
+        assert_eq!(last_step.result, Some(120));
+
+        let proof = prove(&program_trace, &view).unwrap();
+        verify(proof, &view).unwrap();
+    }
+
+    #[test]
+    fn prove_external_trace() {
+        let instructions = [
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 7),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 2, 1),
+        ];
+        let (native_view, native_trace) =
+            k_trace_direct(&vec![BasicBlock::new(instructions.to_vec())], 1)
+                .expect("error generating trace");
+
+        // the same execution, as an external VM would report it
+        let mut regs = RegisterFile::new();
+        let mut blocks = Vec::new();
+        for (idx, (instruction, result)) in instructions.iter().zip([7, 14, 21]).enumerate() {
+            let pc = ELF_TEXT_START + (idx * WORD_SIZE) as u32;
+            blocks.push(Block {
+                regs,
+                steps: vec![Step {
+                    timestamp: idx as u32 + 1,
+                    pc,
+                    next_pc: pc + WORD_SIZE as u32,
+                    raw_instruction: instruction.encode(),
+                    instruction: instruction.clone(),
+                    result: Some(result),
+                    memory_records: Default::default(),
+                }],
+            });
+            regs.write(instruction.op_a, result);
+        }
+        let external_trace = UniformTrace {
+            memory_layout: LinearMemoryLayout::default(),
+            k: 1,
+            start: 0,
+            blocks,
+        };
+        let program = ProgramInfo {
+            initial_pc: ELF_TEXT_START,
+            program: instructions
+                .iter()
+                .enumerate()
+                .map(|(idx, instruction)| ProgramMemoryEntry {
+                    pc: ELF_TEXT_START + (idx * WORD_SIZE) as u32,
+                    instruction_word: instruction.encode(),
+                })
+                .collect(),
+        };
+        let external_view = View::new(
+            &None,
+            &vec![],
+            &program,
+            &vec![],
+            &vec![],
+            &vec![],
+            0,
+            &vec![],
+            &vec![],
+            &vec![],
+        );
+
+        let config = ProvingConfig::dev();
+        let native_proof = prove_with_config(&native_trace, &native_view, config).unwrap();
+        let external_proof = prove_with_config(&external_trace, &external_view, config).unwrap();
+        assert_eq!(format!("{native_proof:?}"), format!("{external_proof:?}"));
+
+        crate::verify_with_config(external_proof, &external_view, config).unwrap();
+    }
+
+    #[test]
+    fn prove_verify_heterogeneous_log_sizes() {
+        let add = Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 3, 3, 2);
+        let mut instructions = vec![
+            // x2 = 0x81008
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 2, 0, 0x81),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 2, 8),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), 2, 2, 0),
+        ];
+        instructions.extend(std::iter::repeat_n(add, 1000));
+        let (view, program_trace) = k_trace_direct(&vec![BasicBlock::new(instructions)], 1)
+            .expect("error generating trace");
+
+        let config = ProvingConfig::dev();
+        let proof = prove_with_config(&program_trace, &view, config).unwrap();
+
+        // each component is padded to its own power of two
+        let components = sort_components(BASE_COMPONENTS);
+        let log_size = |component: &dyn MachineComponent| {
+            let idx = components
+                .iter()
+                .position(|c| c.ordering_key() == component.ordering_key())
+                .expect("component is in the base set");
+            proof.log_sizes[idx]
+        };
+        assert_eq!(log_size(&crate::components::ADD), 10);
+        assert_eq!(log_size(&crate::components::SW), LOG_N_LANES);
+
+        crate::verify_with_config(proof, &view, config).unwrap();
+    }
+
+    #[test]
+    fn prove_verify_thread_pointer_access() {
+        // thread-local block in the heap of direct programs
+        const TP_BASE: u32 = 0x81008;
+        let tp = Register::X4 as u8;
+        let basic_block = vec![BasicBlock::new(vec![
+            // tp = TP_BASE
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), tp, 0, TP_BASE >> 12),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), tp, tp, TP_BASE & 0xFFF),
+            // store a thread-local variable at tp + 4 and read it back
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 5, 0, 42),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), tp, 5, 4),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LW), 6, tp, 4),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let load_step = &program_trace.blocks.last().unwrap().steps[0];
+        assert_eq!(load_step.result, Some(42));
+
+        let proof = prove(&program_trace, &view).unwrap();
+        verify(proof, &view).unwrap();
+    }
+
+    #[test]
+    fn prove_verify_heap_allocation() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x10 = heap break = 0x81008, x11 = 4096
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 10, 0, 0x81),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 10, 10, 8),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 11, 0, 1),
+            // allocate [x10, x10 + x11) the way the runtime allocator does
+            Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                17,
+                0,
+                SyscallCode::MemoryAdvise as u32,
+            ),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ECALL), 0, 0, 0),
+            // x12 = new heap break
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 12, 10, 11),
+            // write the last word of the new region and read it back
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 5, 0, 42),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), 12, 5, 0xFFC),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LW), 6, 12, 0xFFC),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let load_step = &program_trace.blocks.last().unwrap().steps[0];
+        assert_eq!(load_step.result, Some(42));
+
+        let store_step = &program_trace.blocks[program_trace.blocks.len() - 2].steps[0];
+        let address = store_step.memory_records.iter().next().unwrap().get_address();
+        assert_eq!(address, 0x81008 + 4096 - 4);
+
+        let proof = prove(&program_trace, &view).unwrap();
+        verify(proof, &view).unwrap();
+    }
+
+    #[test]
+    fn proving_report_covers_components() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let config = ProvingConfig::dev();
+        let (proof, report) = prove_with_report(&program_trace, &view, config).unwrap();
+
+        let components = sort_components(BASE_COMPONENTS);
+        assert_eq!(report.components.len(), components.len());
+        for ((component, component_report), log_size) in
+            components.iter().zip(&report.components).zip(&proof.log_sizes)
+        {
+            assert_eq!(component_report.name, component.ordering_key());
+            assert_eq!(component_report.log_size, *log_size);
+            assert!(component_report.trace_time > Duration::ZERO);
+        }
+        let claimed_sums: Vec<SecureField> =
+            report.components.iter().map(|c| c.claimed_sum).collect();
+        assert_eq!(claimed_sums, proof.claimed_sums);
+        let trace_time: Duration = report.components.iter().map(|c| c.trace_time).sum();
+        assert!(report.total_time >= trace_time);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["components"].as_array().unwrap().len(), components.len());
+
+        crate::verify_with_config(proof, &view, config).unwrap();
+    }
+
+    #[test]
+    fn timing_breakdown_within_total() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLL), 3, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::XOR), 4, 3, 2),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let (_, report) =
+            prove_with_report(&program_trace, &view, ProvingConfig::default()).unwrap();
+        let TimingBreakdown {
+            trace_gen,
+            interaction_gen,
+            commitment,
+            fri,
+        } = report.timing;
+        for phase in [trace_gen, interaction_gen, commitment, fri] {
+            assert!(phase > Duration::ZERO);
+        }
+        assert!(trace_gen + interaction_gen + commitment + fri <= report.total_time);
+    }
+
+    #[test]
+    fn component_order_does_not_affect_proof() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::XOR), 3, 2, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 4, 3, 2),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let config = ProvingConfig::dev();
+
+        // execution components are independent of each other, while memory components must be
+        // generated first and bitwise and range multiplicities last
+        let position = |component: &dyn MachineComponent| {
+            BASE_COMPONENTS
+                .iter()
+                .position(|c| c.ordering_key() == component.ordering_key())
+                .unwrap()
+        };
+        let execution = position(&crate::components::ADD)..=position(&crate::components::ECALL);
+        let mut reordered = BASE_COMPONENTS.to_vec();
+        reordered[execution].reverse();
+
+        let proof = prove_components(BASE_COMPONENTS, &program_trace, &view, config).unwrap();
+        let reordered_proof = prove_components(&reordered, &program_trace, &view, config).unwrap();
+        assert_eq!(format!("{proof:?}"), format!("{reordered_proof:?}"));
+
+        crate::verify_with_config(reordered_proof, &view, config).unwrap();
+    }
+
+    #[test]
+    fn golden_trace() {
+        let addi = Opcode::from(BuiltinOpcode::ADDI);
+        let basic_blocks = ProgramBuilder::new()
+            .block("entry")
+            // x2 = 0x81008
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 2, 0, 0x81))
+            .push(Instruction::new_ir(addi.clone(), 2, 2, 8))
+            .push(Instruction::new_ir(addi.clone(), 3, 0, 0x7F3))
+            .push(Instruction::new_ir(addi.clone(), 4, 0, 0xFFB))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 5, 3, 4))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SUB), 6, 4, 3))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::XOR), 7, 3, 4))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::AND), 8, 3, 4))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SLL), 9, 3, 4))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SRA), 10, 4, 3))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SLTU), 11, 3, 4))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::SW), 2, 6, 0))
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::LB), 12, 2, 1))
+            .branch(BuiltinOpcode::BLT, 4, 3, "target")
+            .block("skipped")
+            .push(Instruction::new_ir(addi, 13, 0, 1))
+            .block("target")
+            .jal(1, "exit")
+            .block("exit")
+            .push(Instruction::new_ir(Opcode::from(BuiltinOpcode::AUIPC), 14, 0, 1))
+            .build();
+        let (view, program_trace) =
+            k_trace_direct(&basic_blocks, 1).expect("error generating trace");
+
+        // side notes are rebuilt on every run, iteration-order dependencies show up as a mismatch
+        let golden = serialize_traces(&program_trace, &view);
+        assert!(golden == serialize_traces(&program_trace, &view));
+
+        // compare builds with different profiles by running the test with the same path twice,
+        // e.g. once in debug and once with `--release`, the first run writes the reference
+        if let Some(path) = std::env::var_os("NEXUS_GOLDEN_TRACE") {
+            match std::fs::read(&path) {
+                Ok(reference) => {
+                    assert!(reference == golden, "trace differs from the reference at {path:?}")
+                }
+                Err(_) => std::fs::write(&path, &golden).expect("failed to write reference trace"),
+            }
+        }
+    }
+
+    #[test]
+    fn prove_seeded_random_reads() {
+        let set_syscall = Instruction::new_ir(
+            Opcode::from(BuiltinOpcode::ADDI),
+            17,
+            0,
+            SyscallCode::ReadFromPrivateInput as u32,
+        );
+        let ecall = Instruction::new_ir(Opcode::from(BuiltinOpcode::ECALL), 0, 0, 0);
+        let basic_block = vec![BasicBlock::new(vec![
+            // x11 = random byte
+            set_syscall.clone(),
+            ecall.clone(),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 11, 10, 0),
+            // x12 = random byte
+            set_syscall,
+            ecall,
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 12, 10, 0),
+        ])];
+        let config = ProvingConfig::dev();
+
+        let prove_with_seed = |seed: u64| {
+            let private_input = seeded_private_input(seed, 2);
+            let (view, program_trace) =
+                k_trace_direct_with_private_input(&basic_block, 1, &private_input)
+                    .expect("error generating trace");
+            let proof = prove_with_config(&program_trace, &view, config).unwrap();
+            crate::verify_with_config(proof.clone(), &view, config).unwrap();
+            proof
+        };
+
+        // the seed only determines the tape, it isn't a part of the proof
+        let proof = prove_with_seed(42);
+        assert_eq!(format!("{proof:?}"), format!("{:?}", prove_with_seed(42)));
+        assert_ne!(format!("{proof:?}"), format!("{:?}", prove_with_seed(43)));
+    }
+
+    #[test]
+    fn prove_verify_debug_logs() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x2 = 0x81008
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::LUI), 2, 0, 0x81),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 2, 8),
+            // store "hi" at x2
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 3, 0, b'h' as u32),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SB), 2, 3, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 3, 0, b'i' as u32),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::SB), 2, 3, 1),
+            // write(fd = 1, buf = x2, count = 2)
+            Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                17,
+                0,
+                SyscallCode::Write as u32,
+            ),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 10, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 11, 2, 0),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 12, 0, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ECALL), 0, 0, 0),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        assert_eq!(view.view_debug_logs(), Some(vec![b"hi".to_vec()]));
+
+        let proof = prove(&program_trace, &view).unwrap();
+        verify(proof, &view).unwrap();
+    }
+
+    #[test]
+    fn prove_verify_private_hint() {
+        let basic_block = vec![BasicBlock::new(vec![
+            // x10 = hint
+            Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                17,
+                0,
+                SyscallCode::ReadFromPrivateInput as u32,
+            ),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ECALL), 0, 0, 0),
+            // x11 = 3 * hint
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 11, 10, 10),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 11, 11, 10),
+            // skip unimpl if x11 == 21
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 12, 0, 21),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::BEQ), 11, 12, 8),
+            Instruction::unimpl(),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 13, 0, 1),
+        ])];
+        assert!(k_trace_direct_with_private_input(&basic_block, 1, &[6]).is_err());
+
+        let (view, program_trace) = k_trace_direct_with_private_input(&basic_block, 1, &[7])
+            .expect("error generating trace");
+
+        let proof = prove(&program_trace, &view).unwrap();
+        verify(proof, &view).unwrap();
+    }
 }
@@ -0,0 +1,94 @@
+//! Machine-readable summary of a proving run, intended for tracking proving performance over time.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use stwo::core::fields::{m31, qm31::SecureField};
+
+/// Per-component statistics collected while proving.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ComponentReport {
+    /// Name of the component, matches its position in the proof.
+    pub name: String,
+    pub log_size: u32,
+    pub n_constraints: usize,
+    /// Contribution of the component to the total logup sum.
+    pub claimed_sum: SecureField,
+    /// Time spent generating the main and interaction traces of the component.
+    pub trace_time: Duration,
+    /// Largest magnitudes reached by the component's constraints, only collected with
+    /// [`ProvingConfig::with_expression_bounds`](crate::ProvingConfig::with_expression_bounds).
+    pub expression_bounds: Option<Vec<ExpressionBound>>,
+}
+
+/// Largest magnitude reached while evaluating a constraint over the integers.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct ExpressionBound {
+    /// Index of the constraint in the order the component adds them.
+    pub constraint_idx: usize,
+    /// Largest absolute value of the constraint or any of its subexpressions over all rows,
+    /// field elements above `P / 2` are read as negative.
+    pub max_magnitude: u64,
+}
+
+impl ExpressionBound {
+    /// Intermediates above `P / 2` can't be told apart from negative values and might wrap
+    /// around the modulus.
+    pub fn near_modulus(&self) -> bool {
+        self.max_magnitude > u64::from(m31::P / 2)
+    }
+}
+
+/// Time spent in each phase of a proving run, the phases add up to nearly all of the total time.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct TimingBreakdown {
+    /// Generation of the main traces, including preprocessed columns.
+    pub trace_gen: Duration,
+    /// Generation of the interaction traces.
+    pub interaction_gen: Duration,
+    /// Interpolation and commitment of the preprocessed, main and interaction traces.
+    pub commitment: Duration,
+    /// The rest of the STARK protocol: composition polynomial, FRI and openings.
+    pub fri: Duration,
+}
+
+/// Summary of a proving run, components are listed in the order they are committed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProvingReport {
+    pub components: Vec<ComponentReport>,
+    /// Wall-clock time of the whole proving run, including commitments and FRI.
+    pub total_time: Duration,
+    /// Split of the total time into proving phases.
+    pub timing: TimingBreakdown,
+    /// Peak resident memory of the process in bytes, if the platform reports it.
+    ///
+    /// This is a process-wide high-water mark and includes allocations made before proving.
+    pub peak_memory_bytes: Option<u64>,
+}
+
+impl ProvingReport {
+    /// Serializes the report as a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("report serialization is infallible")
+    }
+}
+
+/// Reads the resident set size high-water mark of the current process.
+#[cfg(target_os = "linux")]
+pub(crate) fn peak_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn peak_memory_bytes() -> Option<u64> {
+    None
+}
@@ -1,9 +1,34 @@
-use crate::components::BitwiseMultiplicities;
+use crate::components::{BitwiseMultiplicities, AND_LOOKUP_IDX, OR_LOOKUP_IDX, XOR_LOOKUP_IDX};
 
 /// Lookup counters used by the prover to compute final multiplicities for bitwise instructions.
+///
+/// All bitwise instructions share a single lookup table keyed by (op, b, c, a), counters are kept
+/// per op since each op has its own multiplicity column.
 #[derive(Debug, Default)]
 pub struct BitwiseAccumulators {
     pub(crate) bitwise_mults_and: BitwiseMultiplicities,
     pub(crate) bitwise_mults_or: BitwiseMultiplicities,
     pub(crate) bitwise_mults_xor: BitwiseMultiplicities,
 }
+
+impl BitwiseAccumulators {
+    /// Returns counters of the bitwise operation identified by its lookup index.
+    pub(crate) fn get(&self, lookup_idx: u32) -> &BitwiseMultiplicities {
+        match lookup_idx {
+            AND_LOOKUP_IDX => &self.bitwise_mults_and,
+            OR_LOOKUP_IDX => &self.bitwise_mults_or,
+            XOR_LOOKUP_IDX => &self.bitwise_mults_xor,
+            _ => panic!("invalid lookup idx"),
+        }
+    }
+
+    /// Returns mutable counters of the bitwise operation identified by its lookup index.
+    pub(crate) fn get_mut(&mut self, lookup_idx: u32) -> &mut BitwiseMultiplicities {
+        match lookup_idx {
+            AND_LOOKUP_IDX => &mut self.bitwise_mults_and,
+            OR_LOOKUP_IDX => &mut self.bitwise_mults_or,
+            XOR_LOOKUP_IDX => &mut self.bitwise_mults_xor,
+            _ => panic!("invalid lookup idx"),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use nexus_vm_prover_trace::program::ProgramStep;
+
+/// Hook run on every program step, in execution order, before any component trace is generated.
+///
+/// Components declare their hook with [`BuiltInComponent::STEP_HOOK`] and use it to accumulate
+/// lookup multiplicities or other per-step data into a side note of their own type.
+///
+/// [`BuiltInComponent::STEP_HOOK`]: crate::framework::BuiltInComponent::STEP_HOOK
+pub type StepHook = fn(&ProgramStep, &mut CustomSideNotes);
+
+/// Side notes of custom components, keyed by their type.
+///
+/// A side note is registered with its default value the first time a hook asks for it, so
+/// components don't need to be known to [`SideNote`](super::SideNote) in advance.
+#[derive(Default)]
+pub struct CustomSideNotes(HashMap<TypeId, Box<dyn Any + Send + Sync>>);
+
+impl CustomSideNotes {
+    /// Returns the side note of type `T`, or `None` if no hook has accumulated into it.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .map(|note| note.downcast_ref().expect("side note is keyed by its type"))
+    }
+
+    /// Returns the side note of type `T`, registering its default value on first access.
+    pub fn get_or_default<T: Any + Default + Send + Sync>(&mut self) -> &mut T {
+        self.0
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::<T>::default())
+            .downcast_mut()
+            .expect("side note is keyed by its type")
+    }
+}
+
+impl std::fmt::Debug for CustomSideNotes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomSideNotes")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
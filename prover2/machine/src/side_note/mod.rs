@@ -4,10 +4,16 @@ use nexus_vm::{
 };
 use nexus_vm_prover_trace::program::ProgramStep;
 
+use crate::lookups::LogupBackend;
+
 /// Accumulators for bitwise instructions lookups.
 pub mod bitwise;
+/// Side notes of custom components and their per-step hooks.
+pub mod custom;
 /// Memory-checking side notes.
 pub mod memory;
+/// Accumulators for byte multiplication lookups.
+pub mod product;
 /// Bytecode and the initial memory state.
 pub mod program;
 /// Range checks accumulators
@@ -16,22 +22,45 @@ pub mod range_check;
 /// Prover's side note used for tracking additional data for trace generation.
 pub struct SideNote<'a> {
     execution_trace: &'a [Block],
-    num_steps: usize,
     pub(crate) program: program::ProgramTraceRef<'a>,
     pub(crate) memory: memory::MemorySideNote,
     pub(crate) bitwise: bitwise::BitwiseAccumulators,
+    pub(crate) product: product::ProductMultiplicities,
     pub(crate) range_check: range_check::RangeCheckAccumulator,
+    pub(crate) custom: custom::CustomSideNotes,
+    /// Backend used by logup trace builders of all components.
+    pub(crate) logup_backend: LogupBackend,
 }
 
 impl<'a> SideNote<'a> {
     pub fn new<'b: 'a>(trace: &'b impl Trace, view: &'a View) -> Self {
+        let execution_trace = trace.as_blocks_slice();
+        let num_steps = u32::try_from(trace.get_num_steps()).expect("too many steps");
+        // the clock starts at 1, the last step's timestamp equals the number of executed steps
+        let final_timestamp = execution_trace
+            .last()
+            .map_or(0, |block| ProgramStep::from(block).step.timestamp);
+        assert_eq!(
+            final_timestamp, num_steps,
+            "final timestamp doesn't match the number of executed steps"
+        );
         SideNote {
-            execution_trace: trace.as_blocks_slice(),
-            num_steps: trace.get_num_steps(),
-            program: program::ProgramTraceRef::new(view),
+            execution_trace,
+            program: program::ProgramTraceRef::new(view, num_steps),
             memory: Default::default(),
             bitwise: Default::default(),
+            product: Default::default(),
             range_check: Default::default(),
+            custom: Default::default(),
+            logup_backend: LogupBackend::default(),
+        }
+    }
+
+    /// Generates interaction traces using the given logup backend.
+    pub fn with_logup_backend(self, logup_backend: LogupBackend) -> Self {
+        Self {
+            logup_backend,
+            ..self
         }
     }
 
@@ -40,6 +69,22 @@ impl<'a> SideNote<'a> {
     }
 
     pub fn num_program_steps(&self) -> usize {
-        self.num_steps
+        self.program.num_steps as usize
+    }
+
+    /// Runs each hook on every program step, all hooks see a step before any of them sees the
+    /// next one.
+    ///
+    /// Must be called once, before traces are generated.
+    pub(crate) fn run_step_hooks(&mut self, hooks: impl IntoIterator<Item = custom::StepHook>) {
+        let hooks: Vec<custom::StepHook> = hooks.into_iter().collect();
+        if hooks.is_empty() {
+            return;
+        }
+        for program_step in self.execution_trace.iter().map(ProgramStep::from) {
+            for hook in &hooks {
+                hook(&program_step, &mut self.custom);
+            }
+        }
     }
 }
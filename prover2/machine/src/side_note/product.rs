@@ -0,0 +1,37 @@
+use std::{collections::BTreeMap, ops::Deref};
+
+use stwo::core::fields::m31;
+
+/// Lookup counters used by the prover to compute final multiplicities of the byte multiplication
+/// table.
+#[derive(Debug, Default, Clone)]
+pub struct ProductMultiplicities {
+    // looked up pairs of bytes (b, c) -> mult
+    multiplicities: BTreeMap<(u8, u8), u32>,
+}
+
+impl ProductMultiplicities {
+    pub fn add_product(&mut self, b: u8, c: u8) {
+        let mult = self.multiplicities.entry((b, c)).or_default();
+
+        assert!(*mult < m31::P - 1);
+        *mult += 1;
+    }
+
+    pub fn append(&mut self, mults: Self) {
+        for (val, mult) in mults.multiplicities {
+            let curr = self.multiplicities.entry(val).or_default();
+            assert!(*curr + mult < m31::P);
+
+            *curr += mult;
+        }
+    }
+}
+
+impl Deref for ProductMultiplicities {
+    type Target = BTreeMap<(u8, u8), u32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.multiplicities
+    }
+}
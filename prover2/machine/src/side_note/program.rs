@@ -22,17 +22,13 @@ pub struct ProgramTraceRef<'a> {
     pub private_memory_start: u32,
     /// End of the private read-write memory.
     pub private_memory_end: u32,
+    /// Number of executed steps, public as it determines the final value of the clock.
+    pub num_steps: u32,
 }
 
 impl<'a> ProgramTraceRef<'a> {
-    pub fn new(view: &'a View) -> Self {
-        let (memory_start, memory_end) = if let Some(layout) = view.view_memory_layout() {
-            (layout.heap_start(), layout.stack_top())
-        } else if cfg!(test) {
-            (0, u32::MAX)
-        } else {
-            panic!("memory layout must be present")
-        };
+    pub fn new(view: &'a View, num_steps: u32) -> Self {
+        let (memory_start, memory_end) = private_memory_bounds(view);
         Self {
             program_memory: view.get_program_memory(),
             ro_memory: view.get_ro_initial_memory(),
@@ -42,6 +38,18 @@ impl<'a> ProgramTraceRef<'a> {
             public_output: view.get_public_output(),
             private_memory_start: memory_start,
             private_memory_end: memory_end,
+            num_steps,
         }
     }
 }
+
+/// Returns the start and the end of the private read-write memory.
+pub fn private_memory_bounds(view: &View) -> (u32, u32) {
+    if let Some(layout) = view.view_memory_layout() {
+        (layout.heap_start(), layout.stack_top())
+    } else if cfg!(test) {
+        (0, u32::MAX)
+    } else {
+        panic!("memory layout must be present")
+    }
+}
@@ -1,16 +1,25 @@
 use num_traits::Zero;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use stwo::{
     core::{
         air::Component,
         channel::{Blake2sChannel, Channel},
-        fields::qm31::SecureField,
-        pcs::{CommitmentSchemeVerifier, PcsConfig, TreeVec},
+        circle::M31_CIRCLE_LOG_ORDER,
+        fields::{m31, qm31::SecureField},
+        pcs::{CommitmentSchemeVerifier, TreeVec},
         poly::circle::CanonicCoset,
         proof::StarkProof,
-        vcs::blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher},
+        vcs::{
+            blake2_hash::Blake2sHash,
+            blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher},
+        },
         verifier::VerificationError,
     },
-    prover::{backend::simd::SimdBackend, poly::circle::PolyOps, CommitmentSchemeProver},
+    prover::{
+        backend::simd::{m31::LOG_N_LANES, SimdBackend},
+        poly::circle::PolyOps,
+        CommitmentSchemeProver,
+    },
 };
 use stwo_constraint_framework::TraceLocationAllocator;
 
@@ -21,16 +30,30 @@ use nexus_vm_prover_trace::eval::{
 
 use super::{Proof, BASE_COMPONENTS};
 use crate::{
-    components::PrivateMemoryBoundary, lookups::AllLookupElements,
+    components::{PrivateMemoryBoundary, ProgramMemoryBoundary},
+    framework::sort_components,
+    lookups::AllLookupElements,
     side_note::program::ProgramTraceRef,
+    ProvingConfig,
 };
 
 pub fn verify(proof: Proof, view: &View) -> Result<(), VerificationError> {
-    let components = BASE_COMPONENTS;
+    verify_with_config(proof, view, ProvingConfig::default())
+}
+
+/// Verifies a proof generated with the given security parameters.
+pub fn verify_with_config(
+    proof: Proof,
+    view: &View,
+    config: ProvingConfig,
+) -> Result<(), VerificationError> {
+    let components = sort_components(BASE_COMPONENTS);
     let Proof {
         stark_proof: proof,
         claimed_sums,
         log_sizes: claimed_log_sizes,
+        num_steps,
+        cost,
     } = proof;
 
     if claimed_sums.len() != components.len() {
@@ -38,13 +61,9 @@ pub fn verify(proof: Proof, view: &View) -> Result<(), VerificationError> {
             "claimed sums len mismatch".to_string(),
         ));
     }
-    if claimed_log_sizes.len() != components.len() {
-        return Err(VerificationError::InvalidStructure(
-            "log sizes len mismatch".to_string(),
-        ));
-    }
+    check_log_sizes(&claimed_log_sizes, config)?;
 
-    let config = PcsConfig::default();
+    let pcs_config = config.pcs_config;
     let verifier_channel = &mut Blake2sChannel::default();
     for &byte in view.view_associated_data().as_deref().unwrap_or_default() {
         verifier_channel.mix_u64(byte.into());
@@ -52,10 +71,16 @@ pub fn verify(proof: Proof, view: &View) -> Result<(), VerificationError> {
     claimed_log_sizes.iter().for_each(|log_size| {
         verifier_channel.mix_u64(*log_size as u64);
     });
+    if cost >= m31::P {
+        return Err(VerificationError::InvalidStructure(
+            "cost doesn't fit into a field element".to_string(),
+        ));
+    }
+    verifier_channel.mix_u64(cost.into());
 
-    verify_preprocessed_trace(&proof, view, verifier_channel, &claimed_log_sizes)?;
+    verify_preprocessed_trace(&proof, view, num_steps, &claimed_log_sizes, config)?;
 
-    let commitment_scheme = &mut CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(config);
+    let commitment_scheme = &mut CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(pcs_config);
     let sizes: Vec<TreeVec<Vec<u32>>> = components
         .iter()
         .zip(&claimed_log_sizes)
@@ -77,7 +102,9 @@ pub fn verify(proof: Proof, view: &View) -> Result<(), VerificationError> {
         .iter()
         .for_each(|c| c.draw_lookup_elements(&mut lookup_elements, verifier_channel));
 
-    verify_logup_sum(&claimed_sums, view, &lookup_elements)?;
+    // the verifier consumes the cost provided by the program memory boundary
+    let cost_sum = ProgramMemoryBoundary::cost_logup_sum(cost, lookup_elements.as_ref());
+    verify_logup_sum(&[claimed_sums.as_slice(), &[-cost_sum]].concat(), view, &lookup_elements)?;
 
     let tree_span_provider = &mut TraceLocationAllocator::default();
     let verifier_components: Vec<Box<dyn Component>> = components
@@ -100,15 +127,129 @@ pub fn verify(proof: Proof, view: &View) -> Result<(), VerificationError> {
     stwo::core::verifier::verify(&components_ref, verifier_channel, commitment_scheme, proof)
 }
 
+/// Error returned by [`verify_batch`], identifies the first proof that failed verification.
+#[derive(Debug)]
+pub struct BatchVerificationError {
+    pub index: usize,
+    pub error: VerificationError,
+}
+
+impl std::fmt::Display for BatchVerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "proof {} failed verification: {}", self.index, self.error)
+    }
+}
+
+impl std::error::Error for BatchVerificationError {}
+
+/// Verifies multiple independent proofs in parallel, each against its own view and the
+/// security parameters it was generated with.
+///
+/// Proofs don't share commitments, so each one is checked in full; the error reports
+/// the index of the first invalid proof.
+pub fn verify_batch(
+    proofs: Vec<(Proof, &View, ProvingConfig)>,
+) -> Result<(), BatchVerificationError> {
+    match proofs
+        .into_par_iter()
+        .enumerate()
+        .find_map_first(|(index, (proof, view, config))| {
+            verify_with_config(proof, view, config)
+                .err()
+                .map(|error| BatchVerificationError { index, error })
+        }) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Verifies a proof of a program that halted within `max_steps` steps.
+///
+/// The number of executed steps fixes the final clock in the preprocessed trace, so a proof can't
+/// claim fewer steps than the program executed.
+pub fn verify_with_step_bound(
+    proof: Proof,
+    view: &View,
+    max_steps: u32,
+) -> Result<(), VerificationError> {
+    verify_with_step_bound_with_config(proof, view, max_steps, ProvingConfig::default())
+}
+
+/// Verifies a proof generated with the given security parameters of a program that halted within
+/// `max_steps` steps, see [`verify_with_step_bound`].
+pub fn verify_with_step_bound_with_config(
+    proof: Proof,
+    view: &View,
+    max_steps: u32,
+    config: ProvingConfig,
+) -> Result<(), VerificationError> {
+    if proof.num_steps > max_steps {
+        return Err(VerificationError::InvalidStructure(format!(
+            "program executed {} steps, exceeding the bound of {max_steps}",
+            proof.num_steps
+        )));
+    }
+    verify_with_config(proof, view, config)
+}
+
 pub fn verify_preprocessed_trace(
     proof: &StarkProof<Blake2sMerkleHasher>,
     view: &View,
-    verifier_channel: &Blake2sChannel,
+    num_steps: u32,
+    log_sizes: &[u32],
+    config: ProvingConfig,
+) -> Result<(), VerificationError> {
+    let preprocessed_expected = preprocessed_commitment(view, num_steps, log_sizes, config);
+    let preprocessed = proof.commitments[PREPROCESSED_TRACE_IDX];
+    if preprocessed_expected != preprocessed {
+        Err(VerificationError::InvalidStructure(format!("invalid commitment to preprocessed trace: \
+                                                         expected {preprocessed_expected}, got {preprocessed}")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that claimed log sizes of component traces can be used to rebuild the preprocessed
+/// trace: there is one per component, every trace has at least `2^LOG_N_LANES` rows, and the
+/// evaluation domain of the constraints fits into the circle group.
+pub(crate) fn check_log_sizes(
     log_sizes: &[u32],
+    config: ProvingConfig,
 ) -> Result<(), VerificationError> {
-    let program = ProgramTraceRef::new(view);
+    let components = sort_components(BASE_COMPONENTS);
+    if log_sizes.len() != components.len() {
+        return Err(VerificationError::InvalidStructure(
+            "log sizes len mismatch".to_string(),
+        ));
+    }
+    let log_blowup_factor = config.pcs_config.fri_config.log_blowup_factor;
+    for (c, &log_size) in components.iter().zip(log_sizes) {
+        // bound the log size first, the domain size is computed by adding to it
+        let in_bounds = (LOG_N_LANES..M31_CIRCLE_LOG_ORDER).contains(&log_size) && {
+            let domain_log_size = c.max_constraint_log_degree_bound(log_size) + log_blowup_factor;
+            domain_log_size < M31_CIRCLE_LOG_ORDER
+        };
+        if !in_bounds {
+            return Err(VerificationError::InvalidStructure(format!(
+                "log size {log_size} of {} is out of bounds",
+                c.ordering_key()
+            )));
+        }
+    }
+    Ok(())
+}
 
-    let components = BASE_COMPONENTS;
+/// Computes the commitment to the preprocessed trace, which only depends on the program and the
+/// number of executed steps.
+pub(crate) fn preprocessed_commitment(
+    view: &View,
+    num_steps: u32,
+    log_sizes: &[u32],
+    config: ProvingConfig,
+) -> Blake2sHash {
+    let program = ProgramTraceRef::new(view, num_steps);
+
+    let components = sort_components(BASE_COMPONENTS);
     let max_constraint_log_degree_bound = components
         .iter()
         .zip(log_sizes)
@@ -116,8 +257,8 @@ pub fn verify_preprocessed_trace(
         .max()
         .unwrap_or(0);
 
-    let config = PcsConfig::default();
-    let verifier_channel = &mut verifier_channel.clone();
+    let config = config.pcs_config;
+    let channel = &mut Blake2sChannel::default();
     let twiddles = SimdBackend::precompute_twiddles(
         CanonicCoset::new(max_constraint_log_degree_bound + config.fri_config.log_blowup_factor)
             .circle_domain()
@@ -130,16 +271,9 @@ pub fn verify_preprocessed_trace(
     for (c, log_size) in components.iter().zip(log_sizes) {
         tree_builder.extend_evals(c.generate_preprocessed_trace(*log_size, &program));
     }
-    tree_builder.commit(verifier_channel);
+    tree_builder.commit(channel);
 
-    let preprocessed_expected = commitment_scheme.roots()[PREPROCESSED_TRACE_IDX];
-    let preprocessed = proof.commitments[PREPROCESSED_TRACE_IDX];
-    if preprocessed_expected != preprocessed {
-        Err(VerificationError::InvalidStructure(format!("invalid commitment to preprocessed trace: \
-                                                         expected {preprocessed_expected}, got {preprocessed}")))
-    } else {
-        Ok(())
-    }
+    commitment_scheme.roots()[PREPROCESSED_TRACE_IDX]
 }
 
 pub fn verify_logup_sum(
@@ -147,10 +281,8 @@ pub fn verify_logup_sum(
     view: &View,
     lookup_elements: &AllLookupElements,
 ) -> Result<(), VerificationError> {
-    let program = ProgramTraceRef::new(view);
-
     let memory_boundary =
-        PrivateMemoryBoundary::expected_logup_sum(&program, lookup_elements.as_ref());
+        PrivateMemoryBoundary::expected_logup_sum(view, lookup_elements.as_ref());
     if claimed_sums.iter().sum::<SecureField>() - memory_boundary != SecureField::zero() {
         return Err(VerificationError::InvalidStructure(
             "claimed logup sum is not zero".to_string(),
@@ -158,3 +290,95 @@ pub fn verify_logup_sum(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prove, prove_with_config};
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::k_trace_direct,
+    };
+    use num_traits::One;
+
+    #[test]
+    fn verify_batch_reports_invalid_index() {
+        let runs: Vec<_> = (1..=4)
+            .map(|imm| {
+                let basic_block = vec![BasicBlock::new(vec![
+                    Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, imm),
+                    Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+                ])];
+                let (view, program_trace) =
+                    k_trace_direct(&basic_block, 1).expect("error generating trace");
+                let proof = prove(&program_trace, &view).unwrap();
+                (proof, view)
+            })
+            .collect();
+
+        let config = ProvingConfig::default();
+        let mut batch: Vec<_> = runs[..3]
+            .iter()
+            .map(|(proof, view)| (proof.clone(), view, config))
+            .collect();
+
+        // each proof is checked with its own parameters
+        let (dev_view, dev_trace) = k_trace_direct(
+            &vec![BasicBlock::new(vec![Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                1,
+                0,
+                5,
+            )])],
+            1,
+        )
+        .expect("error generating trace");
+        let dev_proof = prove_with_config(&dev_trace, &dev_view, ProvingConfig::dev()).unwrap();
+        batch.push((dev_proof.clone(), &dev_view, ProvingConfig::dev()));
+        verify_batch(batch).unwrap();
+
+        let err = verify_batch(vec![(dev_proof, &dev_view, config)]).unwrap_err();
+        assert_eq!(err.index, 0);
+
+        // the last two proofs are invalid, the first one by index is reported
+        let batch = runs
+            .iter()
+            .enumerate()
+            .map(|(i, (proof, view))| {
+                let mut proof = proof.clone();
+                if i >= 2 {
+                    proof.claimed_sums[0] += SecureField::one();
+                }
+                (proof, view, config)
+            })
+            .collect();
+        let err = verify_batch(batch).unwrap_err();
+        assert_eq!(err.index, 2);
+    }
+
+    #[test]
+    fn verify_step_bound() {
+        let basic_block = vec![BasicBlock::new(
+            (1..=10)
+                .map(|imm| Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 1, imm))
+                .collect(),
+        )];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+        let proof = prove(&program_trace, &view).unwrap();
+        assert_eq!(proof.num_steps, 10);
+
+        verify_with_step_bound(proof.clone(), &view, 16).unwrap();
+        assert!(verify_with_step_bound(proof.clone(), &view, 8).is_err());
+
+        // the step count is bound to the proof and can't be lowered to meet the bound
+        let mut proof = proof;
+        proof.num_steps = 8;
+        assert!(verify_with_step_bound(proof, &view, 8).is_err());
+
+        let config = ProvingConfig::dev();
+        let proof = prove_with_config(&program_trace, &view, config).unwrap();
+        verify_with_step_bound_with_config(proof.clone(), &view, 16, config).unwrap();
+        assert!(verify_with_step_bound_with_config(proof, &view, 8, config).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Debug proving with an invariant over the register file that must hold after every step.
+//!
+//! The invariant is checked on the host before proving and is not part of the proof: a proof
+//! returned by [`prove_with_watchpoint`] is an ordinary proof of the trace.
+
+use nexus_common::cpu::Registers;
+use nexus_vm::{cpu::RegisterFile, emulator::View, trace::UniformTrace};
+use nexus_vm_prover_trace::program::ProgramStep;
+
+use crate::{prove, Proof, ProvingError};
+
+/// Error returned by [`prove_with_watchpoint`].
+#[derive(Debug)]
+pub enum WatchpointError {
+    /// The invariant doesn't hold after the step with the given index.
+    Violated { step: usize },
+    Proving(ProvingError),
+}
+
+impl std::fmt::Display for WatchpointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Violated { step } => write!(f, "invariant violated after step {step}"),
+            Self::Proving(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for WatchpointError {}
+
+impl From<ProvingError> for WatchpointError {
+    fn from(error: ProvingError) -> Self {
+        Self::Proving(error)
+    }
+}
+
+/// Returns the index of the first step after which `invariant` doesn't hold, or `None` if it holds
+/// throughout the trace.
+pub fn first_violation(
+    trace: &UniformTrace,
+    invariant: impl Fn(&RegisterFile) -> bool,
+) -> Option<usize> {
+    assert_eq!(trace.k, 1, "k must be equal to 1");
+
+    // each block starts with the registers left by the previous step, apply the last step manually
+    let final_regs = trace.blocks.last().map(|block| {
+        let mut regs = block.regs;
+        let program_step = ProgramStep {
+            regs: &block.regs,
+            step: &block.steps[0],
+        };
+        if let Some(result) = program_step.step.result {
+            regs.write(program_step.get_op_a(), result);
+        }
+        regs
+    });
+    trace
+        .blocks
+        .iter()
+        .skip(1)
+        .map(|block| block.regs)
+        .chain(final_regs)
+        .position(|regs| !invariant(&regs))
+}
+
+/// Checks that `invariant` holds after every step of the trace and proves it.
+pub fn prove_with_watchpoint(
+    trace: &UniformTrace,
+    view: &View,
+    invariant: impl Fn(&RegisterFile) -> bool,
+) -> Result<Proof, WatchpointError> {
+    if let Some(step) = first_violation(trace, invariant) {
+        return Err(WatchpointError::Violated { step });
+    }
+    Ok(prove(trace, view)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode, Register},
+        trace::k_trace_direct,
+    };
+
+    use crate::verify;
+
+    fn x1_is_even(regs: &RegisterFile) -> bool {
+        regs.read(Register::X1) % 2 == 0
+    }
+
+    #[test]
+    fn watchpoint_holds() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 1, 1, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 1, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let proof = prove_with_watchpoint(&program_trace, &view, x1_is_even).unwrap();
+        verify(proof, &view).unwrap();
+    }
+
+    #[test]
+    fn watchpoint_reports_violating_step() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 2, 1, 1),
+            // x1 = 3
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 1, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 1, 1, 1),
+        ])];
+        let (view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        assert!(matches!(
+            prove_with_watchpoint(&program_trace, &view, x1_is_even),
+            Err(WatchpointError::Violated { step: 2 })
+        ));
+    }
+
+    #[test]
+    fn watchpoint_checks_last_step() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 2),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+        ])];
+        let (_, program_trace) = k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        assert_eq!(first_violation(&program_trace, x1_is_even), Some(1));
+    }
+}
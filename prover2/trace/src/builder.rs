@@ -3,12 +3,19 @@ use std::marker::PhantomData;
 use num_traits::Zero;
 use stwo::{
     core::fields::m31::BaseField,
-    prover::backend::simd::{column::BaseColumn, m31::LOG_N_LANES},
+    prover::backend::{
+        simd::{column::BaseColumn, m31::LOG_N_LANES},
+        Column as _,
+    },
 };
 
+use nexus_vm::WORD_SIZE;
 use nexus_vm_prover_air_column::AirColumn;
 
-use super::utils::{self, IntoBaseFields};
+use super::{
+    program::WordHex,
+    utils::{self, IntoBaseFields},
+};
 
 /// Wrapper struct for generating and indexing AIR traces.
 ///
@@ -141,4 +148,137 @@ impl FinalizedTrace {
 
         Self { cols, log_size }
     }
+
+    /// Recombines the limbs of `col` at `row` into a native integer.
+    ///
+    /// Limbs are little-endian, either four bytes or two 16-bit halves. Rows are indexed in the
+    /// order the trace was finalized with, which is bit-reversed for
+    /// [`TraceBuilder::finalize_bit_reversed`].
+    pub fn read_word<C: AirColumn>(&self, row: usize, col: C) -> u32 {
+        let size = col.size();
+        assert!(size == WORD_SIZE || size == WORD_SIZE / 2, "{col:?} is not a word column");
+        let limb_bits = u32::BITS / size as u32;
+
+        let offset = col.offset();
+        self.cols[offset..offset + size]
+            .iter()
+            .rev()
+            .fold(0, |word, limb| (word << limb_bits) | limb.at(row).0)
+    }
+
+    /// Formats the columns of `C` at `row` as `name: value` lines, intended for debugging.
+    ///
+    /// Columns listed in `word_columns` are recombined with [`Self::read_word`] and printed as hex
+    /// words, other columns as their field values. Columns past the ones described by `C`, e.g.
+    /// decoding columns appended to a component's trace, are not printed.
+    pub fn dump_row<C: AirColumn>(&self, row: usize, word_columns: &[C]) -> String {
+        let mut dump = String::new();
+        for &col in C::ALL_VARIANTS {
+            let offset = col.offset();
+            let is_word = word_columns.iter().any(|word| word.offset() == offset);
+            let value = if is_word {
+                WordHex(self.read_word(row, col).to_le_bytes()).to_string()
+            } else {
+                let values: Vec<u32> = self.cols[offset..offset + col.size()]
+                    .iter()
+                    .map(|column| column.at(row).0)
+                    .collect();
+                match values.as_slice() {
+                    [value] => value.to_string(),
+                    values => format!("{values:?}"),
+                }
+            };
+            dump += &format!("{col:?}: {value}\n");
+        }
+        dump
+    }
+
+    /// Computes a fingerprint of the trace for detecting corrupted or mismatched traces.
+    ///
+    /// The fingerprint is a polynomial hash of all cells in column-major order, it is not
+    /// collision-resistant and must not be used as a commitment.
+    pub fn fingerprint(&self) -> BaseField {
+        const BASE: u32 = 0x3A4C_5B1F;
+        let base = BaseField::from(BASE);
+
+        let mut acc = BaseField::from(self.log_size);
+        for col in &self.cols {
+            for row in 0..col.len() {
+                acc = acc * base + col.at(row);
+            }
+            // separate columns so that moving values across columns changes the result
+            acc = acc * base + BaseField::from(col.len() as u32);
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone, AirColumn)]
+    enum Column {
+        #[size = 1]
+        A,
+        #[size = 2]
+        B,
+    }
+
+    #[derive(Debug, Copy, Clone, AirColumn)]
+    enum WordColumn {
+        #[size = 1]
+        Flag,
+        #[size = 4]
+        Val,
+        #[size = 2]
+        Halves,
+    }
+
+    #[test]
+    fn word_hex_format() {
+        let word = 0xDEAD_BEEFu32.to_le_bytes();
+        assert_eq!(WordHex(word).to_string(), "0xDEADBEEF");
+        assert_eq!(format!("{:?}", WordHex([1, 0, 0, 0])), "0x00000001");
+    }
+
+    #[test]
+    fn dump_row_names_columns() {
+        let mut builder = TraceBuilder::<WordColumn>::new(LOG_N_LANES);
+        builder.fill_columns(1, true, WordColumn::Flag);
+        builder.fill_columns(1, 0xDEAD_BEEFu32, WordColumn::Val);
+        builder.fill_columns(1, [0x1234u16, 7], WordColumn::Halves);
+        let trace = builder.finalize();
+
+        assert_eq!(
+            trace.dump_row(1, &[WordColumn::Val]),
+            "Flag: 1\nVal: 0xDEADBEEF\nHalves: [4660, 7]\n"
+        );
+        assert_eq!(
+            trace.dump_row(1, &[WordColumn::Halves]),
+            "Flag: 1\nVal: [239, 190, 173, 222]\nHalves: 0x00071234\n"
+        );
+    }
+
+    #[test]
+    fn fingerprint_detects_mutation() {
+        let log_size = LOG_N_LANES;
+        let mut builder = TraceBuilder::<Column>::new(log_size);
+        for row in 0..builder.num_rows() {
+            builder.fill_columns(row, row as u8, Column::A);
+            builder.fill_columns(row, [row as u8 * 3, row as u8 + 7], Column::B);
+        }
+        let trace = builder.finalize();
+        let fingerprint = trace.fingerprint();
+        assert_eq!(trace.clone().fingerprint(), fingerprint);
+
+        for col_idx in 0..trace.cols.len() {
+            for row in 0..1 << log_size {
+                let mut mutated = trace.clone();
+                let value = mutated.cols[col_idx].at(row);
+                mutated.cols[col_idx].set(row, value + BaseField::from(1u32));
+                assert_ne!(mutated.fingerprint(), fingerprint);
+            }
+        }
+    }
 }
@@ -0,0 +1,147 @@
+use num_traits::{One, Zero};
+use stwo::core::fields::m31::BaseField;
+use stwo_constraint_framework::EvalAtRow;
+
+use nexus_vm_prover_air_column::{AirColumn, PreprocessedAirColumn};
+
+use crate::{builder::TraceBuilder, eval::TraceEval};
+
+/// Helper struct for decomposing a value into `W` little-endian bits, e.g. a shift amount or
+/// an immediate. Constraining the decomposition also range checks the value to `[0, 2^W)`.
+pub struct BitDecomposition<C> {
+    /// The decomposed value
+    pub value: C,
+    /// `W` boolean columns, least significant bit first
+    pub bits: C,
+}
+
+impl<C: AirColumn> BitDecomposition<C> {
+    /// Fills the value column and its bits, the value must fit into `W` bits.
+    pub fn fill<const W: usize>(&self, trace: &mut TraceBuilder<C>, row_idx: usize, value: u32) {
+        assert!(W < 31, "{W}-bit values can wrap around the field modulus");
+        assert!(value >> W == 0, "{value:#x} doesn't fit into {W} bits");
+        let bits: [bool; W] = std::array::from_fn(|i| (value >> i) & 1 == 1);
+
+        trace.fill_columns(row_idx, BaseField::from(value), self.value);
+        trace.fill_columns(row_idx, bits, self.bits);
+    }
+
+    /// Constrains the bits to be boolean and to recombine into the value, returns the bits.
+    pub fn decompose_bits<const W: usize, E: EvalAtRow, P: PreprocessedAirColumn>(
+        &self,
+        eval: &mut E,
+        trace_eval: &TraceEval<P, C, E>,
+    ) -> [E::F; W] {
+        assert!(W < 31, "{W}-bit values can wrap around the field modulus");
+        let [value] = trace_eval.column_eval(self.value);
+        let bits: [E::F; W] = trace_eval.column_eval(self.bits);
+
+        // bit(i) · (1 − bit(i)) = 0 for i = 0, ..., W − 1
+        for bit in &bits {
+            eval.add_constraint(bit.clone() * (E::F::one() - bit.clone()));
+        }
+        // value − Σ bit(i) · 2^i = 0
+        let recombined = bits.iter().enumerate().fold(E::F::zero(), |acc, (i, bit)| {
+            acc + bit.clone() * BaseField::from(1u32 << i)
+        });
+        eval.add_constraint(value - recombined);
+
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stwo::{
+        core::{fields::qm31::SecureField, pcs::TreeVec, poly::circle::CanonicCoset},
+        prover::{
+            backend::simd::{m31::LOG_N_LANES, SimdBackend},
+            poly::{circle::CircleEvaluation, BitReversedOrder},
+        },
+    };
+    use stwo_constraint_framework::assert_constraints_on_polys;
+
+    use nexus_vm_prover_air_column::empty::EmptyPreprocessedColumn;
+
+    #[derive(Debug, Copy, Clone, AirColumn)]
+    enum Column {
+        #[size = 1]
+        ShiftAmount,
+        #[size = 5]
+        ShiftAmountBits,
+        #[size = 1]
+        Imm,
+        #[size = 12]
+        ImmBits,
+    }
+
+    const SHIFT_AMOUNT: BitDecomposition<Column> = BitDecomposition {
+        value: Column::ShiftAmount,
+        bits: Column::ShiftAmountBits,
+    };
+    const IMM: BitDecomposition<Column> = BitDecomposition {
+        value: Column::Imm,
+        bits: Column::ImmBits,
+    };
+
+    fn assert_decompositions(trace: TraceBuilder<Column>) {
+        let log_size = trace.log_size();
+        let domain = CanonicCoset::new(log_size).circle_domain();
+        let polys = trace
+            .finalize()
+            .cols
+            .into_iter()
+            .map(|col| {
+                CircleEvaluation::<SimdBackend, BaseField, BitReversedOrder>::new(domain, col)
+                    .interpolate()
+            })
+            .collect();
+        let trace_polys = TreeVec::new(vec![vec![], polys, vec![]]);
+
+        assert_constraints_on_polys(
+            &trace_polys,
+            CanonicCoset::new(log_size),
+            |mut eval| {
+                let trace_eval = TraceEval::<EmptyPreprocessedColumn, Column, _>::new(&mut eval);
+                SHIFT_AMOUNT.decompose_bits::<5, _, _>(&mut eval, &trace_eval);
+                IMM.decompose_bits::<12, _, _>(&mut eval, &trace_eval);
+            },
+            SecureField::zero(),
+        );
+    }
+
+    #[test]
+    fn decompose_boundary_values() {
+        let shift_amounts = [0, 1, 15, 16, 30, 31];
+        let imms = [0, 1, 0x7FF, 0x800, 0xFFE, 0xFFF];
+
+        let mut trace = TraceBuilder::new(LOG_N_LANES);
+        for (row_idx, (shift_amount, imm)) in shift_amounts.into_iter().zip(imms).enumerate() {
+            SHIFT_AMOUNT.fill::<5>(&mut trace, row_idx, shift_amount);
+            IMM.fill::<12>(&mut trace, row_idx, imm);
+        }
+        assert_eq!(trace.column::<5>(5, Column::ShiftAmountBits), [BaseField::one(); 5]);
+        assert_eq!(trace.column::<12>(3, Column::ImmBits)[11], BaseField::one());
+
+        assert_decompositions(trace);
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit into 5 bits")]
+    fn fill_rejects_wide_value() {
+        let mut trace = TraceBuilder::new(LOG_N_LANES);
+        SHIFT_AMOUNT.fill::<5>(&mut trace, 0, 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "row: ")]
+    fn decompose_rejects_out_of_range_value() {
+        let mut trace = TraceBuilder::new(LOG_N_LANES);
+        IMM.fill::<12>(&mut trace, 0, 0xFFF);
+        // 2^12 cannot be recombined from 12 boolean columns
+        trace.fill_columns(0, BaseField::from(1 << 12), Column::Imm);
+
+        assert_decompositions(trace);
+    }
+}
@@ -1,7 +1,9 @@
 pub mod builder;
 pub mod component;
+pub mod decomposition;
 pub mod eval;
 pub mod program;
+pub mod source;
 pub mod utils;
 pub mod virtual_column;
 
@@ -34,9 +34,25 @@ pub type Word = [u8; WORD_SIZE];
 pub type BoolWord = [bool; WORD_SIZE];
 
 /// Represents a 32-bit word as 4 8-bit limbs in little-endian order,
-/// along with the count of effective bits.
+/// along with the count of effective bits, i.e. the bit width of the encoded operand.
 pub type WordWithEffectiveBits = (Word, usize);
 
+/// Formats a [`Word`] as a hexadecimal integer, e.g. `0xDEADBEEF`, for both `Display` and `Debug`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct WordHex(pub Word);
+
+impl std::fmt::Display for WordHex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#010X}", u32::from_le_bytes(self.0))
+    }
+}
+
+impl std::fmt::Debug for WordHex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
 impl ProgramStep<'_> {
     /// Returns the value of the first operand (rd or rs1) as bytes.
     /// Always a register value in range u32.
@@ -95,6 +111,13 @@ impl ProgramStep<'_> {
 
     /// Returns the value of the third operand (rs2 or immediate) as bytes.
     /// The size of effective bits varies based on the instruction type.
+    ///
+    /// Immediates are returned as decoded by the VM, that is already sign-extended to 32 bits where
+    /// the ISA requires it. The number of effective bits is the width of the immediate field in the
+    /// instruction encoding, it doesn't carry the sign and can be ignored by unsigned comparisons.
+    ///
+    /// Register-sourced values (R-type rs2) are the only ones with all 32 effective bits, which
+    /// distinguishes them from immediates.
     pub fn get_value_c(&self) -> WordWithEffectiveBits {
         let instruction = &self.step.instruction;
         let (value, effective_bits) = match instruction.ins_type {
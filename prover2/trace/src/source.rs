@@ -0,0 +1,116 @@
+//! Optional mapping from program counters to source locations, used for debugging.
+//!
+//! The map is expected to be populated from the line table of the guest ELF debug info, it is not
+//! committed to and never affects the proof.
+
+use std::{collections::BTreeMap, fmt};
+
+use super::program::ProgramStep;
+
+/// Location of an instruction in the guest source code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+/// Line table mapping instruction addresses to source locations.
+///
+/// Each entry covers all addresses starting at its pc up to the next entry, matching
+/// the layout of DWARF line programs.
+#[derive(Debug, Default, Clone)]
+pub struct SourceMap(BTreeMap<u32, SourceLocation>);
+
+impl SourceMap {
+    /// Adds a line table row starting at `pc`.
+    pub fn insert(&mut self, pc: u32, location: SourceLocation) {
+        self.0.insert(pc, location);
+    }
+
+    /// Returns the source location of the instruction at `pc`, if known.
+    pub fn lookup(&self, pc: u32) -> Option<&SourceLocation> {
+        self.0
+            .range(..=pc)
+            .next_back()
+            .map(|(_, location)| location)
+    }
+
+    /// Formats `pc` for error messages, preferring the source location when available.
+    pub fn describe(&self, pc: u32) -> String {
+        match self.lookup(pc) {
+            Some(location) => format!("{location} (pc = {pc:#x})"),
+            None => format!("pc = {pc:#x}"),
+        }
+    }
+}
+
+impl FromIterator<(u32, SourceLocation)> for SourceMap {
+    fn from_iter<I: IntoIterator<Item = (u32, SourceLocation)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl ProgramStep<'_> {
+    /// Returns the source location of the executed instruction.
+    pub fn source_location<'m>(&self, source_map: &'m SourceMap) -> Option<&'m SourceLocation> {
+        source_map.lookup(self.step.pc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nexus_vm::{
+        riscv::{BasicBlock, BuiltinOpcode, Instruction, Opcode},
+        trace::{k_trace_direct, Trace},
+    };
+
+    #[test]
+    fn step_reports_source_line() {
+        let basic_block = vec![BasicBlock::new(vec![
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADDI), 1, 0, 1),
+            Instruction::new_ir(Opcode::from(BuiltinOpcode::ADD), 2, 1, 1),
+        ])];
+        let (_view, program_trace) =
+            k_trace_direct(&basic_block, 1).expect("error generating trace");
+
+        let first_pc = program_trace.get_blocks_iter().next().unwrap().steps[0].pc;
+        let source_map: SourceMap = [
+            (first_pc, location("main.rs", 3)),
+            (first_pc + 4, location("main.rs", 4)),
+        ]
+        .into_iter()
+        .collect();
+
+        let add_step = program_trace
+            .get_blocks_iter()
+            .map(ProgramStep::from)
+            .find(|step| step.step.instruction.opcode.builtin() == Some(BuiltinOpcode::ADD))
+            .expect("ADD step is present");
+        assert_eq!(
+            add_step.source_location(&source_map),
+            Some(&location("main.rs", 4))
+        );
+        assert_eq!(
+            source_map.describe(add_step.step.pc),
+            format!("main.rs:4 (pc = {:#x})", first_pc + 4)
+        );
+        assert_eq!(
+            SourceMap::default().describe(first_pc),
+            format!("pc = {first_pc:#x}")
+        );
+    }
+
+    fn location(file: &str, line: u32) -> SourceLocation {
+        SourceLocation {
+            file: file.to_string(),
+            line,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use nexus_sdk::{stwo::seq::Stwo, Local, Prover, Verifiable};
+
+const EXAMPLE_NAME: &str = "double";
+
+const TARGET_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../target/riscv32im-unknown-none-elf/release"
+);
+
+fn main() {
+    let path = std::path::Path::new(TARGET_PATH).join(EXAMPLE_NAME);
+    if path.try_exists().is_err() {
+        panic!(
+            "{}{} was not found, make sure to compile the program \
+             with `cd examples && cargo build --release --bin {}`",
+            "target/riscv32im-unknown-none-elf/release/", EXAMPLE_NAME, EXAMPLE_NAME,
+        );
+    }
+
+    let prover: Stwo<Local> = Stwo::new_from_file(&path).expect("failed to load program");
+    let elf = prover.elf;
+
+    // arguments and return value are passed in their postcard encoding
+    let args = postcard::to_stdvec(&21u32).expect("failed to encode arguments");
+    let expected = postcard::to_stdvec(&42u32).expect("failed to encode return value");
+
+    print!("Proving f(21) == 42... ");
+    let (_view, proof) =
+        Stwo::<Local>::prove_function(&elf, &args, &expected).expect("failed to prove function");
+    println!("  Succeeded!");
+
+    print!("Verifying execution...");
+
+    #[rustfmt::skip]
+    proof
+        .verify_expected::<u32, u32>(
+            &21,  // x = 21
+            nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+            &42,  // f(x) = 42
+            &elf, // expected elf (program binary)
+            &[],  // no associated data,
+        )
+        .expect("failed to verify proof");
+
+    println!("  Succeeded!");
+
+    print!("Checking f(21) != 43... ");
+    let unexpected = postcard::to_stdvec(&43u32).expect("failed to encode return value");
+    assert!(Stwo::<Local>::prove_function(&elf, &args, &unexpected).is_err());
+
+    println!("  Succeeded!");
+}
@@ -1,4 +1,7 @@
-use nexus_sdk::{stwo::seq::Stwo, Local, Prover, Verifiable, Viewable};
+use nexus_sdk::{
+    stwo::seq::{Proof, Stwo},
+    Local, Prover, Verifiable, Viewable,
+};
 
 const EXAMPLE_NAME: &str = "input_output";
 
@@ -42,8 +45,11 @@ fn main() {
         view.logs().expect("failed to retrieve debug logs").join("")
     );
 
-    // Normally the prover communicates the seralized proof to the verifier who deserializes it.
-    //
+    // The prover communicates the serialized proof to the verifier who deserializes it, no
+    // parameters have to be detached or set up again on the verifier side.
+    let proof_bytes = proof.to_bytes().expect("failed to serialize proof");
+    let proof = Proof::from_bytes(&proof_bytes).expect("failed to deserialize proof");
+
     // The verifier must also possess the program binary and the public i/o. Usually, either
     // the verifier will rebuild the elf in a reproducible way (e.g., within a container) or
     // the prover will communicate it to the verifier who will then check that it is a valid
@@ -0,0 +1,59 @@
+use nexus_sdk::{
+    stwo::seq::{Proof, Stwo},
+    Local, Prover, Verifiable,
+};
+
+const EXAMPLE_NAME: &str = "double";
+
+const TARGET_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../target/riscv32im-unknown-none-elf/release"
+);
+
+fn main() {
+    let path = std::path::Path::new(TARGET_PATH).join(EXAMPLE_NAME);
+    if path.try_exists().is_err() {
+        panic!(
+            "{}{} was not found, make sure to compile the program \
+             with `cd examples && cargo build --release --bin {}`",
+            "target/riscv32im-unknown-none-elf/release/", EXAMPLE_NAME, EXAMPLE_NAME,
+        );
+    }
+
+    let prover: Stwo<Local> = Stwo::new_from_file(&path).expect("failed to load program");
+
+    let elf = prover.elf.clone(); // save elf for use with verification
+
+    print!("Proving execution of vm... ");
+    let (_view, proof) = prover
+        .prove_with_input::<(), u32>(&(), &21)
+        .expect("failed to prove program");
+    println!("  Succeeded!");
+
+    // The prover writes into one end of the pipe while the verifier reads from the other, the
+    // write end is closed when the writer thread drops it.
+    let (reader, writer) = std::io::pipe().expect("failed to create pipe");
+    let writer_thread = std::thread::spawn(move || {
+        proof.write_to(writer).expect("failed to write proof");
+    });
+
+    print!("Reading proof from pipe... ");
+    let proof = Proof::read_from(reader).expect("failed to read proof");
+    writer_thread.join().expect("writer thread panicked");
+    println!("  Succeeded!");
+
+    print!("Verifying execution...");
+
+    #[rustfmt::skip]
+    proof
+        .verify_expected::<u32, u32>(
+            &21,  // x = 21
+            nexus_sdk::KnownExitCodes::ExitSuccess as u32,
+            &42,  // f(x) = 42
+            &elf, // expected elf (program binary)
+            &[],  // no associated data,
+        )
+        .expect("failed to verify proof");
+
+    println!("  Succeeded!");
+}
@@ -1,7 +1,7 @@
 use crate::compile::Compile;
 use crate::traits::*;
 
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{de::DeserializeOwned, ser::SerializeTuple, Deserialize, Serialize};
 use std::marker::PhantomData;
 use thiserror::Error;
 
@@ -45,6 +45,14 @@ pub enum Error {
     /// An error occured configuring the prover.
     #[error(transparent)]
     ConfigurationError(#[from] ConfigurationError),
+
+    /// The guest program did not exit successfully with the expected return value.
+    #[error("guest program did not return the expected value")]
+    UnexpectedReturnError,
+
+    /// An error occurred serializing or deserializing a proof.
+    #[error("proof serialization error: {0}")]
+    ProofSerializationError(#[from] postcard::Error),
 }
 
 /// Prover for the Nexus zkVM, when using Stwo.
@@ -148,6 +156,93 @@ impl Prover for Stwo<Local> {
     }
 }
 
+impl Stwo<Local> {
+    /// Prove the guest program as a pure function of `args`, checking that it exits successfully
+    /// and returns `expected_return`.
+    ///
+    /// `args` and `expected_return` are the postcard encodings of the arguments and the return
+    /// value of the guest `main`. They are framed the way the `nexus_rt::main` entry point reads
+    /// its public input and writes its public output, so the proof is bound to both. The RV32
+    /// argument registers are not used: the initial register file is fixed to zero.
+    ///
+    /// The execution is checked before proving, a mismatching return value is reported without
+    /// spending any time on the proof.
+    pub fn prove_function(
+        elf: &nexus_core::nvm::ElfFile,
+        args: &[u8],
+        expected_return: &[u8],
+    ) -> Result<(nexus_core::nvm::View, Proof), Error> {
+        let public_encoded =
+            <Self as Prover>::encode_input(&RawBytes(args)).map_err(Error::GuestIOError)?;
+        let expected_output = <Self as Prover>::encode_input(&RawBytes(expected_return))
+            .map_err(Error::GuestIOError)?;
+
+        let (view, trace) =
+            nexus_core::nvm::k_trace(elf.clone(), &[], public_encoded.as_slice(), &[], 1)?;
+
+        if view.exit_code()? != crate::KnownExitCodes::ExitSuccess as u32
+            || view.view_public_output() != Some(expected_output)
+        {
+            return Err(Error::UnexpectedReturnError);
+        }
+
+        let proof = nexus_core::stwo::prove(&trace, &view)?;
+        Ok((
+            view,
+            Proof {
+                proof,
+                memory_layout: trace.memory_layout,
+            },
+        ))
+    }
+}
+
+/// Bytes that serialize as themselves: postcard doesn't prefix tuples with their length.
+struct RawBytes<'a>(&'a [u8]);
+
+impl Serialize for RawBytes<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(self.0.len())?;
+        for byte in self.0 {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+}
+
+impl Proof {
+    /// Serialize the proof directly into `writer`, without first collecting the bytes in memory.
+    ///
+    /// The writer is returned so that the caller decides when it is flushed or closed.
+    pub fn write_to<W: std::io::Write>(&self, writer: W) -> Result<W, Error> {
+        Ok(postcard::to_io(self, writer)?)
+    }
+
+    /// Read a proof written by [`Proof::write_to`], deserializing it as bytes arrive.
+    ///
+    /// Only the bytes of the proof are consumed from `reader`.
+    pub fn read_from<R: std::io::Read>(reader: R) -> Result<Self, Error> {
+        // scratch space for borrowed byte strings, the proof only holds owned values
+        let mut scratch = [0u8; 1024];
+        let (proof, _) = postcard::from_io((reader, &mut scratch))?;
+
+        Ok(proof)
+    }
+
+    /// Serialize the proof to bytes.
+    ///
+    /// The proof is self-contained: Stwo has no setup, so the bytes together with the program
+    /// and its public i/o are all a verifier needs.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(postcard::to_stdvec(self)?)
+    }
+
+    /// Deserialize a proof produced by [`Proof::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(postcard::from_bytes(bytes)?)
+    }
+}
+
 impl Verifiable for Proof {
     type View = nexus_core::nvm::View;
     type Error = Error;
@@ -11,9 +11,13 @@ mod test {
     use nexus_vm_prover::{
         extensions::ExtensionComponent,
         machine::{BaseComponent, Machine},
-        prove, verify,
+        prove, prove_equivalence, verify, verify_equivalence, EquivalenceError, EquivalenceProof,
     };
-    use postcard::to_allocvec_cobs;
+    use nexus_sdk::{
+        stwo::seq::{Error as StwoError, Stwo},
+        Local, Verifiable,
+    };
+    use postcard::{to_allocvec, to_allocvec_cobs};
     use serial_test::serial;
     const K: usize = 1;
 
@@ -527,6 +531,68 @@ mod test {
         verify(proof, &view).unwrap();
     }
 
+    #[test]
+    #[serial]
+    fn test_prove_mul_equivalence() {
+        let trace_with_input = |name: &str, a: u32, b: u32| {
+            let elfs = compile_multi(name, &["-C opt-level=3"], &HOME_PATH);
+            let mut public_input_bytes = to_allocvec_cobs(&mut (a, b)).unwrap();
+            public_input_bytes.resize(public_input_bytes.len().word_align(), 0);
+            k_trace(elfs[0].clone(), &[], &public_input_bytes, &[], K)
+                .expect("error generating trace")
+        };
+
+        let (shift_add_view, shift_add_trace) =
+            trace_with_input("examples/src/bin/mul_shift_add", 1234, 5678);
+        let (mul_view, mul_trace) = trace_with_input("examples/src/bin/mul_direct", 1234, 5678);
+
+        let proof = prove_equivalence(
+            (&shift_add_trace, &shift_add_view),
+            (&mul_trace, &mul_view),
+        )
+        .unwrap();
+        verify_equivalence(proof.clone(), &shift_add_view, &mul_view).unwrap();
+
+        // same programs on a different input produce a different output
+        let (other_view, other_trace) = trace_with_input("examples/src/bin/mul_direct", 1234, 5679);
+        assert!(matches!(
+            prove_equivalence((&shift_add_trace, &shift_add_view), (&other_trace, &other_view)),
+            Err(EquivalenceError::OutputMismatch)
+        ));
+        assert!(verify_equivalence(proof, &shift_add_view, &other_view).is_err());
+
+        // equal outputs on different inputs don't make the programs equivalent
+        let (left_view, left_trace) = trace_with_input("examples/src/bin/mul_shift_add", 2, 3);
+        let (right_view, right_trace) = trace_with_input("examples/src/bin/mul_direct", 1, 6);
+        assert!(matches!(
+            prove_equivalence((&left_trace, &left_view), (&right_trace, &right_view)),
+            Err(EquivalenceError::InputMismatch)
+        ));
+        let proof = EquivalenceProof {
+            left: prove(&left_trace, &left_view).unwrap(),
+            right: prove(&right_trace, &right_view).unwrap(),
+        };
+        assert!(verify_equivalence(proof, &left_view, &right_view).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_prove_function() {
+        let elfs = compile_multi("examples/src/bin/double", &["-C opt-level=3"], &HOME_PATH);
+        let args = to_allocvec(&21u32).unwrap();
+
+        let (view, proof) =
+            Stwo::<Local>::prove_function(&elfs[0], &args, &to_allocvec(&42u32).unwrap())
+                .expect("f(21) == 42 must be proven");
+        proof.verify(&view).unwrap();
+
+        // the return value is checked before proving
+        assert!(matches!(
+            Stwo::<Local>::prove_function(&elfs[0], &args, &to_allocvec(&43u32).unwrap()),
+            Err(StwoError::UnexpectedReturnError)
+        ));
+    }
+
     #[test]
     #[serial]
     fn test_emulate_fail() {
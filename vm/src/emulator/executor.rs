@@ -207,8 +207,8 @@ impl Executor {
         self.private_input_tape = VecDeque::<u8>::from(private_input.to_vec());
     }
 
-    /// Set whether to capture logs or print out.
-    pub(crate) fn capture_logs(&mut self, capture: bool) {
+    /// Set whether to capture logs into the view or print them to stdout.
+    pub fn capture_logs(&mut self, capture: bool) {
         if capture && self.logs.is_none() {
             self.logs = Some(Vec::new());
         }
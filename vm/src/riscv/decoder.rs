@@ -234,9 +234,53 @@ pub fn decode_until_end_of_a_block(u32_instructions: &[u32]) -> BasicBlock {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::elf::ElfFile;
+    use crate::{elf::ElfFile, riscv::BuiltinOpcode};
     use nexus_common::constants::WORD_SIZE;
 
+    /// Tests that assembler pseudo-instructions decode into their base expansions
+    ///
+    /// Pseudo-instructions are expanded by the assembler before encoding, so no normalization is
+    /// needed at decoding time: the binary form already maps onto the base instruction set.
+    #[test]
+    fn test_decode_pseudo_instructions() {
+        let ir = |opcode: BuiltinOpcode, op_a: u8, op_b: u8, op_c: i32| {
+            Instruction::new_ir(Opcode::from(opcode), op_a, op_b, op_c as u32)
+        };
+        let (ra, a0, a1) = (Register::X1 as u8, Register::X10 as u8, Register::X11 as u8);
+        let test_cases: [(&str, &[u32], Vec<Instruction>); 10] = [
+            ("mv a0, a1", &[0x00058513], vec![ir(BuiltinOpcode::ADDI, a0, a1, 0)]),
+            ("li a0, -42", &[0xfd600513], vec![ir(BuiltinOpcode::ADDI, a0, 0, -42)]),
+            (
+                "li a0, 0x12345678",
+                &[0x12345537, 0x67850513],
+                vec![
+                    ir(BuiltinOpcode::LUI, a0, 0, 0x12345),
+                    ir(BuiltinOpcode::ADDI, a0, a0, 0x678),
+                ],
+            ),
+            ("not a0, a1", &[0xfff5c513], vec![ir(BuiltinOpcode::XORI, a0, a1, -1)]),
+            ("neg a0, a1", &[0x40b00533], vec![ir(BuiltinOpcode::SUB, a0, 0, a1 as i32)]),
+            ("seqz a0, a1", &[0x0015b513], vec![ir(BuiltinOpcode::SLTIU, a0, a1, 1)]),
+            ("snez a0, a1", &[0x00b03533], vec![ir(BuiltinOpcode::SLTU, a0, 0, a1 as i32)]),
+            ("j -8", &[0xff9ff06f], vec![ir(BuiltinOpcode::JAL, 0, 0, -8)]),
+            ("ret", &[0x00008067], vec![ir(BuiltinOpcode::JALR, 0, ra, 0)]),
+            (
+                "call 0x1234",
+                &[0x00001097, 0x234080e7],
+                vec![
+                    ir(BuiltinOpcode::AUIPC, ra, 0, 1),
+                    ir(BuiltinOpcode::JALR, ra, ra, 0x234),
+                ],
+            ),
+        ];
+
+        for (asm, encoded, expected) in test_cases {
+            let decoded: Vec<Instruction> =
+                encoded.iter().map(|&word| decode_instruction(word)).collect();
+            assert_eq!(decoded, expected, "{asm}");
+        }
+    }
+
     /// Tests the decoding of instructions from an ELF file
     ///
     /// This test function does the following:
@@ -1,8 +1,10 @@
 mod basic_block;
 mod instruction;
 mod macros;
+mod program_builder;
 
 pub use basic_block::{BasicBlock, BasicBlockProgram};
 pub use instruction::InstructionDecoder;
 pub use nexus_common::riscv::instruction::{Instruction, InstructionType};
 pub use nexus_common::riscv::opcode::{BuiltinOpcode, Opcode};
+pub use program_builder::ProgramBuilder;
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use nexus_common::constants::WORD_SIZE;
+
+use super::{BasicBlock, BuiltinOpcode, Instruction, InstructionType, Opcode};
+
+enum PendingInstruction {
+    Resolved(Instruction),
+    /// Jump or branch whose pc-relative offset is resolved from the target block label.
+    Jump {
+        opcode: BuiltinOpcode,
+        op_a: u8,
+        op_b: u8,
+        target: &'static str,
+    },
+}
+
+/// Builder for programs composed of multiple labeled basic blocks.
+///
+/// Blocks are laid out contiguously in insertion order, jumps and branches refer to blocks by label
+/// and their offsets are computed once the layout is known.
+#[derive(Default)]
+pub struct ProgramBuilder {
+    blocks: Vec<(&'static str, Vec<PendingInstruction>)>,
+}
+
+impl ProgramBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new basic block with the given label.
+    pub fn block(mut self, label: &'static str) -> Self {
+        assert!(
+            self.blocks.iter().all(|(l, _)| *l != label),
+            "duplicate block label {label}"
+        );
+        self.blocks.push((label, Vec::new()));
+        self
+    }
+
+    /// Appends an instruction to the current block.
+    pub fn push(self, instruction: Instruction) -> Self {
+        self.push_pending(PendingInstruction::Resolved(instruction))
+    }
+
+    /// Appends `JAL rd, target` to the current block.
+    pub fn jal(self, rd: u8, target: &'static str) -> Self {
+        self.push_pending(PendingInstruction::Jump {
+            opcode: BuiltinOpcode::JAL,
+            op_a: rd,
+            op_b: 0,
+            target,
+        })
+    }
+
+    /// Appends a conditional branch `opcode rs1, rs2, target` to the current block.
+    pub fn branch(self, opcode: BuiltinOpcode, rs1: u8, rs2: u8, target: &'static str) -> Self {
+        assert_eq!(
+            Opcode::from(opcode).ins_type(),
+            InstructionType::BType,
+            "{opcode} is not a branch"
+        );
+        self.push_pending(PendingInstruction::Jump {
+            opcode,
+            op_a: rs1,
+            op_b: rs2,
+            target,
+        })
+    }
+
+    /// Returns the offset of the block entry point relative to the start of the program.
+    pub fn entry_point(&self, label: &str) -> Option<u32> {
+        self.block_offsets().get(label).copied()
+    }
+
+    /// Lays out the blocks and resolves jump targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a jump refers to an unknown label.
+    pub fn build(self) -> Vec<BasicBlock> {
+        let offsets = self.block_offsets();
+        let mut pc = 0u32;
+        self.blocks
+            .into_iter()
+            .map(|(_, instructions)| {
+                let instructions = instructions
+                    .into_iter()
+                    .map(|pending| {
+                        let instruction = match pending {
+                            PendingInstruction::Resolved(instruction) => instruction,
+                            PendingInstruction::Jump {
+                                opcode,
+                                op_a,
+                                op_b,
+                                target,
+                            } => {
+                                let target_pc = *offsets
+                                    .get(target)
+                                    .unwrap_or_else(|| panic!("unknown block label {target}"));
+                                let offset = target_pc.wrapping_sub(pc);
+                                Instruction::new_ir(Opcode::from(opcode), op_a, op_b, offset)
+                            }
+                        };
+                        pc += WORD_SIZE as u32;
+                        instruction
+                    })
+                    .collect();
+                BasicBlock::new(instructions)
+            })
+            .collect()
+    }
+
+    fn push_pending(mut self, instruction: PendingInstruction) -> Self {
+        let (_, block) = self
+            .blocks
+            .last_mut()
+            .expect("no block was started, call `block` first");
+        block.push(instruction);
+        self
+    }
+
+    fn block_offsets(&self) -> HashMap<&'static str, u32> {
+        let mut offset = 0;
+        self.blocks
+            .iter()
+            .map(|(label, instructions)| {
+                let entry = offset;
+                offset += (instructions.len() * WORD_SIZE) as u32;
+                (*label, entry)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::{k_trace_direct, Trace};
+    use nexus_common::constants::ELF_TEXT_START;
+
+    #[test]
+    fn test_jump_between_blocks() {
+        let basic_blocks = ProgramBuilder::new()
+            .block("entry")
+            .push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                1,
+                0,
+                1,
+            ))
+            .jal(0, "target")
+            .block("skipped")
+            .push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADDI),
+                1,
+                0,
+                100,
+            ))
+            .block("target")
+            .push(Instruction::new_ir(
+                Opcode::from(BuiltinOpcode::ADD),
+                2,
+                1,
+                1,
+            ))
+            .build();
+        assert_eq!(basic_blocks.len(), 3);
+        assert_eq!(basic_blocks[0][1].op_c, 8);
+
+        let (_, trace) = k_trace_direct(&basic_blocks, 1).expect("error generating trace");
+        let pcs: Vec<u32> = trace
+            .get_blocks_iter()
+            .map(|block| block.steps[0].pc - ELF_TEXT_START)
+            .collect();
+        assert_eq!(pcs, vec![0, 4, 12]);
+    }
+}
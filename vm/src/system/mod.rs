@@ -1,3 +1,5 @@
+mod prng;
 mod syscall;
 
+pub use prng::{seeded_private_input, SeededRng};
 pub use syscall::{SyscallCode, SyscallInstruction};
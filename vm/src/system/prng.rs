@@ -0,0 +1,60 @@
+//! Deterministic randomness for guest programs.
+//!
+//! There is no dedicated randomness syscall: "random" values are read from the private input tape
+//! (syscall 0x400), filled by a PRNG keyed by a proving-time seed. The same seed yields the same
+//! tape, execution trace and proof.
+//!
+//! This is host-side reproducibility only. Generated values are unconstrained witness data and the
+//! seed is not a part of the proof, the verifier doesn't learn which randomness was used.
+
+/// SplitMix64 generator.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next `len` bytes of the stream.
+    pub fn next_bytes(&mut self, len: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(len.next_multiple_of(8));
+        while bytes.len() < len {
+            bytes.extend(self.next_u64().to_le_bytes());
+        }
+        bytes.truncate(len);
+        bytes
+    }
+}
+
+/// Returns a private input tape of `len` pseudo-random bytes derived from the seed.
+pub fn seeded_private_input(seed: u64, len: usize) -> Vec<u8> {
+    SeededRng::new(seed).next_bytes(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_rng() {
+        let mut rng = SeededRng::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+
+        let tape = seeded_private_input(42, 12);
+        assert_eq!(tape.len(), 12);
+        assert_eq!(tape, seeded_private_input(42, 12));
+        assert_eq!(tape[..8], SeededRng::new(42).next_u64().to_le_bytes());
+        assert_ne!(tape, seeded_private_input(43, 12));
+    }
+}
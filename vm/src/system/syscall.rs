@@ -319,15 +319,20 @@ impl SyscallInstruction {
         let second_pass = memory_layout.is_some() || force_second_pass;
         match self.code {
             SyscallCode::Write => {
-                // No-op on second pass.
+                let fd = self.args[0];
+                let buf = self.args[1];
+                let count = self.args[2];
+
+                // No-op on second pass, unless logs are captured by an emulator that skips the
+                // first pass (e.g. direct traces); the result is never written back.
                 if second_pass {
+                    if let (1, Some(logger)) = (fd, &mut executor.logs) {
+                        logger.push(memory.read_bytes(buf, count as _)?);
+                    }
                     self.result = None;
                     return Ok(());
                 }
 
-                let fd = self.args[0];
-                let buf = self.args[1];
-                let count = self.args[2];
                 self.execute_write(&mut executor.logs, memory, fd, buf, count)
             }
 
@@ -354,7 +354,18 @@ pub fn k_trace(
 
 /// Similar to `k_trace`, but uses HarvardEmulator and supports Intermediate Representation (IR) as input instead of an ELF file.
 pub fn k_trace_direct(basic_blocks: &Vec<BasicBlock>, k: usize) -> Result<(View, UniformTrace)> {
+    k_trace_direct_with_private_input(basic_blocks, k, &[])
+}
+
+/// Similar to `k_trace_direct`, but the program can read prover-supplied bytes from the private input tape.
+pub fn k_trace_direct_with_private_input(
+    basic_blocks: &Vec<BasicBlock>,
+    k: usize,
+    private_input: &[u8],
+) -> Result<(View, UniformTrace)> {
     let mut harvard = HarvardEmulator::from_basic_blocks(basic_blocks);
+    harvard.set_private_input(private_input);
+    harvard.get_executor_mut().capture_logs(true);
 
     let mut trace = UniformTrace {
         memory_layout: LinearMemoryLayout::default(), // dummy